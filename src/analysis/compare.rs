@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use crate::models::{TestCase, TestStatus};
+use crate::report::StatusSummary;
+
+/// Tipo de diferencia de un caso entre dos ejecuciones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Pasó a rechazado o bloqueado desde un estado que no era fallo
+    NewFailure,
+    /// Pasó de rechazado o bloqueado a validado
+    Fixed,
+    /// Cualquier otro cambio de estado
+    StatusChanged,
+    /// Solo existe en la segunda ejecución
+    Added,
+    /// Solo existe en la primera ejecución
+    Removed,
+}
+
+impl ChangeKind {
+    /// Título de la sección en los informes
+    pub fn title(&self) -> &'static str {
+        match self {
+            ChangeKind::NewFailure => "Nuevos fallos",
+            ChangeKind::Fixed => "Corregidos",
+            ChangeKind::StatusChanged => "Otros cambios de estado",
+            ChangeKind::Added => "Casos añadidos",
+            ChangeKind::Removed => "Casos eliminados",
+        }
+    }
+
+    /// Orden en que se muestran las secciones
+    pub const ALL: [ChangeKind; 5] = [
+        ChangeKind::NewFailure,
+        ChangeKind::Fixed,
+        ChangeKind::StatusChanged,
+        ChangeKind::Added,
+        ChangeKind::Removed,
+    ];
+}
+
+/// Diferencia de un caso de prueba entre dos ejecuciones
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaseChange {
+    pub id: String,
+    pub description: String,
+    pub before: Option<TestStatus>,
+    pub after: Option<TestStatus>,
+    pub kind: ChangeKind,
+}
+
+/// Resultado de comparar dos ejecuciones de la misma definición
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub base_name: String,
+    pub target_name: String,
+    pub base_summary: StatusSummary,
+    pub target_summary: StatusSummary,
+    pub changes: Vec<CaseChange>,
+    /// Casos presentes en ambas ejecuciones con el mismo estado
+    pub unchanged: usize,
+}
+
+impl Comparison {
+    /// Cambios de un tipo concreto
+    pub fn changes_of(&self, kind: ChangeKind) -> impl Iterator<Item = &CaseChange> {
+        self.changes.iter().filter(move |c| c.kind == kind)
+    }
+
+    /// Indica si hay casos que empezaron a fallar
+    pub fn has_regressions(&self) -> bool {
+        self.changes_of(ChangeKind::NewFailure).next().is_some()
+    }
+}
+
+/// Compara dos ejecuciones emparejando los casos por `id`
+///
+/// Los cambios se devuelven en el orden de la ejecución `target`, seguidos de
+/// los casos eliminados en el orden de `base`.
+pub fn compare_executions(
+    base_name: &str,
+    base: &[TestCase],
+    target_name: &str,
+    target: &[TestCase],
+) -> Comparison {
+    let base_by_id: HashMap<&str, &TestCase> = base.iter().map(|tc| (tc.id.as_str(), tc)).collect();
+    let target_by_id: HashMap<&str, &TestCase> =
+        target.iter().map(|tc| (tc.id.as_str(), tc)).collect();
+
+    let mut changes = Vec::new();
+    let mut unchanged = 0;
+
+    for after in target {
+        let Some(before) = base_by_id.get(after.id.as_str()) else {
+            changes.push(CaseChange {
+                id: after.id.clone(),
+                description: after.description.clone(),
+                before: None,
+                after: Some(after.status),
+                kind: ChangeKind::Added,
+            });
+            continue;
+        };
+
+        if before.status == after.status {
+            unchanged += 1;
+            continue;
+        }
+
+        let kind = if after.status.is_failure() && !before.status.is_failure() {
+            ChangeKind::NewFailure
+        } else if before.status.is_failure() && after.status == TestStatus::Validated {
+            ChangeKind::Fixed
+        } else {
+            ChangeKind::StatusChanged
+        };
+
+        changes.push(CaseChange {
+            id: after.id.clone(),
+            description: after.description.clone(),
+            before: Some(before.status),
+            after: Some(after.status),
+            kind,
+        });
+    }
+
+    for before in base {
+        if !target_by_id.contains_key(before.id.as_str()) {
+            changes.push(CaseChange {
+                id: before.id.clone(),
+                description: before.description.clone(),
+                before: Some(before.status),
+                after: None,
+                kind: ChangeKind::Removed,
+            });
+        }
+    }
+
+    Comparison {
+        base_name: base_name.to_string(),
+        target_name: target_name.to_string(),
+        base_summary: StatusSummary::from_cases(base),
+        target_summary: StatusSummary::from_cases(target),
+        changes,
+        unchanged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TestStatus::*;
+
+    fn cases(statuses: &[(&str, TestStatus)]) -> Vec<TestCase> {
+        statuses
+            .iter()
+            .map(|(id, status)| {
+                let mut test_case = TestCase::new(&format!("Caso {}", id), "", "");
+                test_case.id = id.to_string();
+                test_case.status = *status;
+                test_case
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classifies_status_changes() {
+        // (antes, después, tipo de cambio esperado; None si no cambia)
+        let table = [
+            (Validated, Rejected, Some(ChangeKind::NewFailure)),
+            (Pending, Blocked, Some(ChangeKind::NewFailure)),
+            (Skipped, Rejected, Some(ChangeKind::NewFailure)),
+            (Rejected, Validated, Some(ChangeKind::Fixed)),
+            (Blocked, Validated, Some(ChangeKind::Fixed)),
+            (Rejected, Blocked, Some(ChangeKind::StatusChanged)),
+            (Blocked, Pending, Some(ChangeKind::StatusChanged)),
+            (Validated, Skipped, Some(ChangeKind::StatusChanged)),
+            (Pending, Validated, Some(ChangeKind::StatusChanged)),
+            (Validated, Validated, None),
+            (Rejected, Rejected, None),
+        ];
+
+        for (before, after, expected) in table {
+            let comparison = compare_executions(
+                "base",
+                &cases(&[("TC-1", before)]),
+                "target",
+                &cases(&[("TC-1", after)]),
+            );
+
+            let kind = comparison.changes.first().map(|c| c.kind);
+            assert_eq!(kind, expected, "{:?} -> {:?}", before, after);
+            assert_eq!(comparison.unchanged, usize::from(expected.is_none()));
+            assert_eq!(
+                comparison.has_regressions(),
+                expected == Some(ChangeKind::NewFailure)
+            );
+        }
+    }
+
+    #[test]
+    fn reports_added_and_removed_cases_in_order() {
+        let base = cases(&[
+            ("TC-1", Validated),
+            ("TC-2", Rejected),
+            ("TC-3", Pending),
+            ("TC-4", Validated),
+        ]);
+        let target = cases(&[
+            ("TC-5", Pending),
+            ("TC-2", Validated),
+            ("TC-1", Validated),
+            ("TC-6", Rejected),
+        ]);
+
+        let comparison = compare_executions("base", &base, "target", &target);
+
+        let changes: Vec<(&str, ChangeKind, Option<TestStatus>, Option<TestStatus>)> = comparison
+            .changes
+            .iter()
+            .map(|c| (c.id.as_str(), c.kind, c.before, c.after))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("TC-5", ChangeKind::Added, None, Some(Pending)),
+                ("TC-2", ChangeKind::Fixed, Some(Rejected), Some(Validated)),
+                ("TC-6", ChangeKind::Added, None, Some(Rejected)),
+                ("TC-3", ChangeKind::Removed, Some(Pending), None),
+                ("TC-4", ChangeKind::Removed, Some(Validated), None),
+            ]
+        );
+        assert_eq!(comparison.unchanged, 1);
+        // Un caso nuevo que falla no es una regresión
+        assert!(!comparison.has_regressions());
+        assert_eq!(comparison.changes_of(ChangeKind::Added).count(), 2);
+        assert_eq!(comparison.base_summary.rejected, 1);
+        assert_eq!(comparison.target_summary.total, 4);
+        assert_eq!(comparison.target_summary.validated, 2);
+    }
+}
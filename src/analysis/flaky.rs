@@ -0,0 +1,266 @@
+use crate::analysis::History;
+use crate::config::FlakyConfig;
+use crate::models::TestStatus;
+
+/// Cantidad mínima de cambios entre ✅ y ❌ para marcar un caso como inestable;
+/// un único cambio es una regresión o una corrección
+pub const MIN_FLIPS: usize = 2;
+
+/// Inestabilidad de un caso de prueba en las últimas ejecuciones
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlakyScore {
+    pub id: String,
+    pub description: String,
+    /// Ejecuciones de la ventana con resultado validado, rechazado o bloqueado
+    pub decided_runs: usize,
+    /// Veces que el resultado cambió entre validado y fallido
+    pub flips: usize,
+    /// Cambios sobre el máximo posible (`decided_runs - 1`), entre 0 y 1
+    pub score: f64,
+    /// Resultados de la ventana, de la ejecución más antigua a la más reciente
+    pub timeline: Vec<Option<TestStatus>>,
+}
+
+impl FlakyScore {
+    /// Indica si el caso supera el umbral de inestabilidad
+    pub fn is_flaky(&self, config: &FlakyConfig) -> bool {
+        self.flips >= MIN_FLIPS && self.score >= config.threshold
+    }
+}
+
+/// Puntúa cada caso según la frecuencia con que alterna entre validado y fallido
+/// en las últimas `config.window` ejecuciones
+///
+/// Los estados pendiente y omitido no cuentan como resultado. Los casos se
+/// devuelven de mayor a menor puntuación.
+pub fn flaky_scores(history: &History, config: &FlakyConfig) -> Vec<FlakyScore> {
+    let skip = history.runs.len().saturating_sub(config.window.max(1));
+
+    let mut scores: Vec<FlakyScore> = history
+        .cases()
+        .into_iter()
+        .map(|(id, description)| {
+            let timeline: Vec<Option<TestStatus>> =
+                history.timeline(&id).into_iter().skip(skip).collect();
+
+            let outcomes: Vec<bool> = timeline
+                .iter()
+                .flatten()
+                .filter(|s| **s == TestStatus::Validated || s.is_failure())
+                .map(|s| s.is_failure())
+                .collect();
+
+            let flips = outcomes.windows(2).filter(|w| w[0] != w[1]).count();
+            let score = if outcomes.len() < 2 {
+                0.0
+            } else {
+                flips as f64 / (outcomes.len() - 1) as f64
+            };
+
+            FlakyScore {
+                id,
+                description,
+                decided_runs: outcomes.len(),
+                flips,
+                score,
+                timeline,
+            }
+        })
+        .collect();
+
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score).then(b.flips.cmp(&a.flips)));
+    scores
+}
+
+/// Casos que superan el umbral de inestabilidad
+pub fn flaky_cases(history: &History, config: &FlakyConfig) -> Vec<FlakyScore> {
+    flaky_scores(history, config)
+        .into_iter()
+        .filter(|score| score.is_flaky(config))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::Run;
+    use crate::models::TestCase;
+    use crate::report::StatusSummary;
+    use TestStatus::*;
+
+    /// Historial con una ejecución por columna; `None` indica que el caso no
+    /// estaba en esa ejecución
+    fn history(cases: &[(&str, &[Option<TestStatus>])]) -> History {
+        let run_count = cases.iter().map(|(_, s)| s.len()).max().unwrap_or(0);
+        let runs = (0..run_count)
+            .map(|i| {
+                let test_cases: Vec<TestCase> = cases
+                    .iter()
+                    .filter_map(|(id, statuses)| {
+                        statuses[i].map(|status| {
+                            let mut test_case = TestCase::new(&format!("Caso {}", id), "", "");
+                            test_case.id = id.to_string();
+                            test_case.status = status;
+                            test_case
+                        })
+                    })
+                    .collect();
+                Run {
+                    reference: format!("executions/login-{}", i),
+                    name: format!("login-{}", i),
+                    executed_at: None,
+                    summary: StatusSummary::from_cases(&test_cases),
+                    test_cases,
+                }
+            })
+            .collect();
+
+        History {
+            definition: "login".to_string(),
+            runs,
+        }
+    }
+
+    fn config(window: usize, threshold: f64) -> FlakyConfig {
+        FlakyConfig { window, threshold }
+    }
+
+    fn score<'a>(scores: &'a [FlakyScore], id: &str) -> &'a FlakyScore {
+        scores.iter().find(|s| s.id == id).unwrap()
+    }
+
+    #[test]
+    fn scores_flips_between_validated_and_failed() {
+        let history = history(&[
+            (
+                "stable",
+                &[
+                    Some(Validated),
+                    Some(Validated),
+                    Some(Validated),
+                    Some(Validated),
+                ],
+            ),
+            (
+                "flaky",
+                &[
+                    Some(Validated),
+                    Some(Rejected),
+                    Some(Validated),
+                    Some(Blocked),
+                ],
+            ),
+            (
+                "regressed",
+                &[
+                    Some(Validated),
+                    Some(Validated),
+                    Some(Rejected),
+                    Some(Rejected),
+                ],
+            ),
+        ]);
+        let config = config(10, 0.3);
+
+        let scores = flaky_scores(&history, &config);
+
+        let ids: Vec<&str> = scores.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["flaky", "regressed", "stable"]);
+
+        let flaky = score(&scores, "flaky");
+        assert_eq!((flaky.decided_runs, flaky.flips), (4, 3));
+        assert_eq!(flaky.score, 1.0);
+        assert!(flaky.is_flaky(&config));
+
+        let stable = score(&scores, "stable");
+        assert_eq!((stable.flips, stable.score), (0, 0.0));
+        assert!(!stable.is_flaky(&config));
+
+        // Un solo cambio supera el umbral de puntuación pero no MIN_FLIPS
+        let regressed = score(&scores, "regressed");
+        assert_eq!(regressed.flips, MIN_FLIPS - 1);
+        assert!(regressed.score >= config.threshold);
+        assert!(!regressed.is_flaky(&config));
+
+        let flaky_ids: Vec<String> = flaky_cases(&history, &config)
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(flaky_ids, vec!["flaky"]);
+    }
+
+    #[test]
+    fn ignores_pending_skipped_and_missing_runs() {
+        let history = history(&[
+            (
+                "TC-1",
+                &[
+                    Some(Validated),
+                    Some(Pending),
+                    Some(Rejected),
+                    Some(Skipped),
+                    None,
+                    Some(Validated),
+                ],
+            ),
+            (
+                "TC-2",
+                &[
+                    Some(Pending),
+                    Some(Skipped),
+                    Some(Validated),
+                    None,
+                    Some(Pending),
+                    None,
+                ],
+            ),
+        ]);
+
+        let scores = flaky_scores(&history, &config(10, 0.5));
+
+        let first = score(&scores, "TC-1");
+        assert_eq!((first.decided_runs, first.flips, first.score), (3, 2, 1.0));
+        assert_eq!(first.timeline.len(), 6);
+        assert_eq!(first.timeline[4], None);
+
+        // Menos de dos resultados no dan puntuación
+        let second = score(&scores, "TC-2");
+        assert_eq!(
+            (second.decided_runs, second.flips, second.score),
+            (1, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn only_counts_runs_inside_the_window() {
+        let history = history(&[(
+            "TC-1",
+            &[
+                Some(Rejected),
+                Some(Validated),
+                Some(Rejected),
+                Some(Validated),
+                Some(Validated),
+                Some(Validated),
+            ],
+        )]);
+
+        let all = flaky_scores(&history, &config(6, 0.3));
+        assert_eq!(all[0].flips, 3);
+        assert!(all[0].is_flaky(&config(6, 0.3)));
+
+        // Las tres últimas ejecuciones no tienen ningún cambio
+        let recent = flaky_scores(&history, &config(3, 0.3));
+        assert_eq!(recent[0].timeline.len(), 3);
+        assert_eq!((recent[0].decided_runs, recent[0].flips), (3, 0));
+
+        // La ventana de cuatro incluye un único cambio, por debajo de MIN_FLIPS
+        let window = config(4, 0.3);
+        let last_four = flaky_scores(&history, &window);
+        assert_eq!(last_four[0].flips, 1);
+        assert!(!last_four[0].is_flaky(&window));
+
+        // Una ventana de 0 se trata como 1
+        assert_eq!(flaky_scores(&history, &config(0, 0.3))[0].timeline.len(), 1);
+    }
+}
@@ -0,0 +1,294 @@
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+use std::io;
+
+use crate::engine::{base_name_of, executed_at, file_stem};
+use crate::models::{TestCase, TestStatus};
+use crate::report::StatusSummary;
+use crate::storage::Storage;
+
+/// Ejecución de una definición dentro de su historial
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// Referencia de la ejecución en el almacenamiento
+    pub reference: String,
+    /// Nombre de la ejecución: `<definición>-<timestamp>`
+    pub name: String,
+    pub executed_at: Option<NaiveDateTime>,
+    pub summary: StatusSummary,
+    pub test_cases: Vec<TestCase>,
+}
+
+impl Run {
+    fn new(reference: String, test_cases: Vec<TestCase>) -> Self {
+        Run {
+            name: file_stem(&reference),
+            executed_at: executed_at(&reference),
+            summary: StatusSummary::from_cases(&test_cases),
+            reference,
+            test_cases,
+        }
+    }
+
+    /// Porcentaje de casos validados sobre el total
+    pub fn pass_rate(&self) -> f64 {
+        if self.summary.total == 0 {
+            0.0
+        } else {
+            self.summary.validated as f64 * 100.0 / self.summary.total as f64
+        }
+    }
+
+    /// Etiqueta corta para tablas y gráficos: la fecha, o el nombre si no la tiene
+    pub fn label(&self) -> String {
+        match self.executed_at {
+            Some(date) => date.format("%Y-%m-%d %H:%M").to_string(),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Estado de un caso en esta ejecución, si existe en ella
+    pub fn status_of(&self, id: &str) -> Option<TestStatus> {
+        self.test_cases
+            .iter()
+            .find(|tc| tc.id == id)
+            .map(|tc| tc.status)
+    }
+}
+
+/// Todas las ejecuciones de una definición, de la más antigua a la más reciente
+#[derive(Debug, Clone)]
+pub struct History {
+    /// Nombre base de la definición
+    pub definition: String,
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Reúne las ejecuciones derivadas de una definición, incluidas las antiguas de `tests/`
+    ///
+    /// La definición se puede indicar por su referencia o por su nombre base.
+    pub fn load(storage: &dyn Storage, definition: &str) -> io::Result<Self> {
+        let mut history = History::new(definition);
+        history.refresh(storage)?;
+
+        Ok(history)
+    }
+
+    /// Historial vacío de una definición, que se completa con [`History::refresh`]
+    pub fn new(definition: &str) -> Self {
+        History {
+            definition: file_stem(definition),
+            runs: Vec::new(),
+        }
+    }
+
+    /// Añade las ejecuciones nuevas de la definición y quita las que ya no existen
+    ///
+    /// Las ejecuciones que ya estaban en el historial no se vuelven a leer; los
+    /// cambios hechos a través de este proceso se registran con [`History::record`].
+    pub fn refresh(&mut self, storage: &dyn Storage) -> io::Result<()> {
+        let references: Vec<String> = storage
+            .list_executions()?
+            .into_iter()
+            .filter(|reference| base_name_of(reference) == self.definition)
+            .collect();
+
+        self.runs.retain(|run| references.contains(&run.reference));
+
+        for reference in references {
+            if self.runs.iter().all(|run| run.reference != reference) {
+                let test_cases = storage.load(&reference)?;
+                self.runs.push(Run::new(reference, test_cases));
+            }
+        }
+
+        self.sort_runs();
+        Ok(())
+    }
+
+    /// Registra el contenido actual de una ejecución de la definición
+    pub fn record(&mut self, reference: &str, test_cases: &[TestCase]) {
+        let run = Run::new(reference.to_string(), test_cases.to_vec());
+
+        match self.runs.iter_mut().find(|r| r.reference == reference) {
+            Some(existing) => *existing = run,
+            None => {
+                self.runs.push(run);
+                self.sort_runs();
+            }
+        }
+    }
+
+    fn sort_runs(&mut self) {
+        self.runs.sort_by(|a, b| {
+            a.executed_at
+                .cmp(&b.executed_at)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+    }
+
+    /// Casos que aparecen en alguna ejecución, con la descripción más reciente,
+    /// en el orden en que aparecieron por primera vez
+    pub fn cases(&self) -> Vec<(String, String)> {
+        let mut cases: Vec<(String, String)> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for run in &self.runs {
+            for test_case in &run.test_cases {
+                match positions.get(&test_case.id) {
+                    Some(&i) => cases[i].1 = test_case.description.clone(),
+                    None => {
+                        positions.insert(test_case.id.clone(), cases.len());
+                        cases.push((test_case.id.clone(), test_case.description.clone()));
+                    }
+                }
+            }
+        }
+
+        cases
+    }
+
+    /// Estados de un caso en cada ejecución, en orden cronológico
+    pub fn timeline(&self, id: &str) -> Vec<Option<TestStatus>> {
+        self.runs.iter().map(|run| run.status_of(id)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{ensure_directories, CsvStorage};
+    use crate::test_support::TempWorkingDir;
+    use TestStatus::*;
+
+    fn case(id: &str, description: &str, status: TestStatus) -> TestCase {
+        let mut test_case = TestCase::new(description, "", "");
+        test_case.id = id.to_string();
+        test_case.status = status;
+        test_case
+    }
+
+    fn save(reference: &str, test_cases: &[TestCase]) {
+        CsvStorage.save(reference, test_cases).unwrap();
+    }
+
+    #[test]
+    fn groups_executions_by_definition_in_chronological_order() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+
+        save(
+            "executions/login-20250301_090000.csv",
+            &[
+                case("TC-1", "Entrar", Rejected),
+                case("TC-2", "Salir", Validated),
+            ],
+        );
+        save(
+            "tests/login-20250101_090000.csv",
+            &[case("TC-1", "Entrar", Validated)],
+        );
+        save(
+            "executions/login-20250301_090000_2.csv",
+            &[
+                case("TC-1", "Entrar con correo", Validated),
+                case("TC-3", "Recordar", Pending),
+            ],
+        );
+        // Otras definiciones cuyo nombre empieza igual
+        save(
+            "executions/login-form-20250201_090000.csv",
+            &[case("TC-9", "Formulario", Validated)],
+        );
+        save(
+            "executions/login-manual.csv",
+            &[case("TC-8", "Manual", Validated)],
+        );
+
+        let history = History::load(&CsvStorage, "definitions/login.csv").unwrap();
+
+        assert_eq!(history.definition, "login");
+        let names: Vec<&str> = history.runs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "login-20250101_090000",
+                "login-20250301_090000",
+                "login-20250301_090000_2",
+            ]
+        );
+        assert_eq!(history.runs[1].summary.rejected, 1);
+        assert_eq!(history.runs[1].pass_rate(), 50.0);
+        assert_eq!(history.runs[0].label(), "2025-01-01 09:00");
+
+        assert_eq!(
+            history.cases(),
+            vec![
+                ("TC-1".to_string(), "Entrar con correo".to_string()),
+                ("TC-2".to_string(), "Salir".to_string()),
+                ("TC-3".to_string(), "Recordar".to_string()),
+            ]
+        );
+        assert_eq!(
+            history.timeline("TC-1"),
+            vec![Some(Validated), Some(Rejected), Some(Validated)]
+        );
+        assert_eq!(history.timeline("TC-3"), vec![None, None, Some(Pending)]);
+        assert_eq!(history.timeline("TC-7"), vec![None, None, None]);
+
+        let form = History::load(&CsvStorage, "login-form").unwrap();
+        assert_eq!(form.runs.len(), 1);
+        assert_eq!(form.cases()[0].0, "TC-9");
+
+        let manual = History::load(&CsvStorage, "login-manual").unwrap();
+        assert_eq!(manual.runs.len(), 1);
+        assert_eq!(manual.runs[0].executed_at, None);
+        assert_eq!(manual.runs[0].label(), "login-manual");
+    }
+
+    #[test]
+    fn refresh_reads_only_new_executions_and_record_updates_a_run() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+        save(
+            "executions/login-20250301_090000.csv",
+            &[case("TC-1", "Entrar", Rejected)],
+        );
+        let mut history = History::load(&CsvStorage, "login").unwrap();
+
+        // Un cambio en un archivo ya leído solo se ve si se registra
+        save(
+            "executions/login-20250301_090000.csv",
+            &[case("TC-1", "Entrar", Blocked)],
+        );
+        save(
+            "executions/login-20250101_090000.csv",
+            &[case("TC-1", "Entrar", Validated)],
+        );
+        history.refresh(&CsvStorage).unwrap();
+        assert_eq!(
+            history.timeline("TC-1"),
+            vec![Some(Validated), Some(Rejected)]
+        );
+
+        history.record(
+            "executions/login-20250301_090000.csv",
+            &[case("TC-1", "Entrar", Skipped)],
+        );
+        history.record(
+            "executions/login-20250201_090000.csv",
+            &[case("TC-1", "Entrar", Pending)],
+        );
+        assert_eq!(
+            history.timeline("TC-1"),
+            vec![Some(Validated), Some(Pending), Some(Skipped)]
+        );
+        assert_eq!(history.runs[2].summary.skipped, 1);
+
+        // Las ejecuciones que ya no existen se quitan
+        std::fs::remove_file("executions/login-20250101_090000.csv").unwrap();
+        history.refresh(&CsvStorage).unwrap();
+        assert_eq!(history.timeline("TC-1"), vec![Some(Skipped)]);
+    }
+}
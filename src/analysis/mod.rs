@@ -0,0 +1,7 @@
+pub mod compare;
+pub mod flaky;
+pub mod history;
+
+pub use compare::{compare_executions, CaseChange, ChangeKind, Comparison};
+pub use flaky::{flaky_cases, flaky_scores, FlakyScore, MIN_FLIPS};
+pub use history::{History, Run};
@@ -0,0 +1,102 @@
+use colored::*;
+use std::io;
+
+use crate::analysis::{flaky_scores, History};
+use crate::config::Config;
+use crate::report::save_flaky_report;
+use crate::storage::open_storage;
+
+/// Analiza las ejecuciones de una definición y muestra los casos inestables
+///
+/// La ventana y el umbral indicados reemplazan los de la sección `[flaky]` de
+/// la configuración.
+pub fn analyze_flaky(
+    definition: &str,
+    window: Option<usize>,
+    threshold: Option<f64>,
+    output: Option<&str>,
+) -> io::Result<()> {
+    let mut config = Config::load()?.flaky;
+    if let Some(window) = window {
+        config.window = window;
+    }
+    if let Some(threshold) = threshold {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Umbral inválido {}. Debe estar entre 0 y 1", threshold),
+            ));
+        }
+        config.threshold = threshold;
+    }
+
+    let storage = open_storage()?;
+    let history = History::load(storage.as_ref(), definition)?;
+
+    if history.runs.len() < 2 {
+        println!(
+            "{}",
+            format!(
+                "Se necesitan al menos dos ejecuciones de {} para detectar casos inestables.",
+                history.definition
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+
+    let scores = flaky_scores(&history, &config);
+    let analyzed_runs = history.runs.len().min(config.window);
+
+    println!(
+        "{}",
+        format!(
+            "Analizando las últimas {} ejecuciones de {} (umbral {:.0}%)",
+            analyzed_runs,
+            history.definition,
+            config.threshold * 100.0
+        )
+        .blue()
+    );
+
+    let changed: Vec<_> = scores.iter().filter(|s| s.flips > 0).collect();
+    if changed.is_empty() {
+        println!("{}", "Ningún caso alternó entre ✅ y ❌.".green());
+    }
+
+    for score in &changed {
+        let timeline: Vec<&str> = score
+            .timeline
+            .iter()
+            .map(|status| status.map_or("—", |s| s.emoji()))
+            .collect();
+
+        let line = format!(
+            "  {:>4.0}%  {} cambio(s)  {}  {} {}",
+            score.score * 100.0,
+            score.flips,
+            timeline.join(" "),
+            score.id,
+            score.description
+        );
+
+        if score.is_flaky(&config) {
+            println!("{} {}", line.red(), "[inestable]".red().bold());
+        } else {
+            println!("{}", line.yellow());
+        }
+    }
+
+    let flaky_count = scores.iter().filter(|s| s.is_flaky(&config)).count();
+    println!("\nCasos inestables: {}", flaky_count);
+
+    if let Some(output) = output {
+        save_flaky_report(output, &history.definition, &scores, &config)?;
+        println!(
+            "{}",
+            format!("Informe de inestabilidad guardado en {}", output).green()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use colored::*;
+use std::io;
+
+use crate::analysis::{compare_executions, CaseChange, ChangeKind};
+use crate::commands::load_test_cases;
+use crate::engine::file_stem;
+use crate::report::save_comparison_to_markdown;
+use crate::storage::open_storage;
+
+/// Compara dos ejecuciones y muestra los cambios de estado de sus casos
+///
+/// Si se indica una ruta de salida, guarda además un informe Markdown.
+pub fn compare_execution_files(
+    base_path: &str,
+    target_path: &str,
+    output: Option<&str>,
+) -> io::Result<()> {
+    let storage = open_storage()?;
+    let base = load_test_cases(storage.as_ref(), base_path)?;
+    let target = load_test_cases(storage.as_ref(), target_path)?;
+
+    let comparison = compare_executions(
+        &file_stem(base_path),
+        &base,
+        &file_stem(target_path),
+        &target,
+    );
+
+    println!(
+        "{}",
+        format!(
+            "Comparando {} ({} casos) con {} ({} casos)",
+            comparison.base_name,
+            comparison.base_summary.total,
+            comparison.target_name,
+            comparison.target_summary.total
+        )
+        .blue()
+    );
+
+    for kind in ChangeKind::ALL {
+        let changes: Vec<&CaseChange> = comparison.changes_of(kind).collect();
+        if changes.is_empty() {
+            continue;
+        }
+
+        println!(
+            "\n{}",
+            colorize(kind, &format!("{} ({}):", kind.title(), changes.len())).bold()
+        );
+        for change in changes {
+            println!("{}", colorize(kind, &format_change(change)));
+        }
+    }
+
+    if comparison.changes.is_empty() {
+        println!("{}", "No hay diferencias entre las ejecuciones.".green());
+    } else {
+        println!("\nSin cambios: {}", comparison.unchanged);
+    }
+
+    if let Some(output) = output {
+        save_comparison_to_markdown(output, &comparison)?;
+        println!(
+            "{}",
+            format!("Informe de comparación guardado en {}", output).green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Línea de la terminal que describe el cambio de un caso
+fn format_change(change: &CaseChange) -> String {
+    let status = match (change.before, change.after) {
+        (Some(before), Some(after)) => format!("{} → {}", before, after),
+        (None, Some(after)) => after.to_string(),
+        (Some(before), None) => before.to_string(),
+        (None, None) => String::new(),
+    };
+
+    format!("  {} {} [{}]", change.id, change.description, status)
+}
+
+/// Color con el que se muestra cada tipo de cambio
+fn colorize(kind: ChangeKind, text: &str) -> ColoredString {
+    match kind {
+        ChangeKind::NewFailure => text.red(),
+        ChangeKind::Fixed => text.green(),
+        ChangeKind::StatusChanged => text.yellow(),
+        ChangeKind::Added | ChangeKind::Removed => text.cyan(),
+    }
+}
@@ -1,17 +1,20 @@
-use std::io;
-use std::path::Path;
 use colored::*;
 use inquire::{Select, Text};
-use uuid::Uuid;
+use std::io;
+
+use crate::commands::execute::{execute_test_cases_from_definition, ExecuteOptions};
+use crate::commands::spelling::{correct_fields, prompt_spelling_backend, SpellingField};
+use crate::config::Config;
+use crate::models::{parse_tags, Priority, TestCase, TestStep};
+use crate::storage::{open_storage, Storage};
 
-use crate::models::{TestCase, TestStatus};
-use crate::utils::{correct_spelling, save_to_csv, save_to_markdown};
-use crate::commands::execute::execute_test_cases_from_definition;
+/// Tipos de prueba admitidos al crear una definición
+pub const TEST_TYPES: [&str; 3] = ["smoke", "regression", "functional"];
 
 /// Crea nuevos casos de prueba
 pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()> {
     // Validar tipo de prueba
-    if test_type != "smoke" && test_type != "regression" && test_type != "functional" {
+    if !TEST_TYPES.contains(&test_type) {
         println!(
             "{}",
             "Tipo de prueba inválido. Use 'smoke', 'regression' o 'functional'.".red()
@@ -32,29 +35,17 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
     // Generar nombre de archivo base (sin fecha ni hora)
     let base_name = match name {
         Some(n) if !n.is_empty() => format!("{}-{}", test_type, n),
-        _ => format!("{}", test_type),
+        _ => test_type.to_string(),
     };
 
-    // Rutas para archivos base (definiciones)
-    let base_csv_path = format!("definitions/{}.csv", base_name);
+    // Referencia de la definición en el almacenamiento configurado
+    let storage = open_storage()?;
+    let base_csv_path = storage.definition_ref(&base_name);
 
     // Verificar si ya existe un archivo con ese nombre
-    if Path::new(&base_csv_path).exists() {
-        let options = vec!["Sí", "No"];
-        let selection = Select::new(
-            format!(
-                "Ya existe un archivo con el nombre '{}'. ¿Deseas sobrescribirlo?",
-                base_name
-            )
-            .as_str(),
-            options,
-        )
-        .prompt();
-
-        if let Ok("No") = selection {
-            println!("{}", "Operación cancelada.".yellow());
-            return Ok(());
-        }
+    if !confirm_overwrite(storage.as_ref(), &base_csv_path, &base_name)? {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
     }
 
     // Crear casos de prueba
@@ -69,13 +60,6 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
     loop {
         println!("{}", format!("Caso de prueba #{}", i).blue());
 
-        let id = Uuid::new_v4()
-            .to_string()
-            .split('-')
-            .next()
-            .unwrap_or("TC")
-            .to_string();
-
         let description = Text::new("Descripción (o escribe 'FIN' para terminar):")
             .prompt()
             .unwrap_or_else(|_| format!("Caso de prueba {}", i));
@@ -85,15 +69,29 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
             break;
         }
 
-        let test_case = TestCase {
-            id,
-            description,
-            status: TestStatus::Pending,
-            observations: String::new(),
-            evidence: String::new(),
-            version: version.clone(),
-            ticket_numbers: ticket_numbers.clone(),
-        };
+        let mut test_case = TestCase::new(&description, &version, &ticket_numbers);
+
+        test_case.preconditions = Text::new("Precondiciones (opcional):")
+            .prompt()
+            .unwrap_or_default();
+
+        test_case.test_data = Text::new("Datos de prueba (opcional):")
+            .prompt()
+            .unwrap_or_default();
+
+        test_case.priority = prompt_priority(None);
+
+        test_case.component = Text::new("Componente (opcional):")
+            .prompt()
+            .unwrap_or_default();
+
+        test_case.tags = parse_tags(
+            &Text::new("Etiquetas separadas por comas (opcional):")
+                .prompt()
+                .unwrap_or_default(),
+        );
+
+        test_case.steps = prompt_steps();
 
         test_cases.push(test_case);
         i += 1;
@@ -105,52 +103,21 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
         return Ok(());
     }
 
-    // Preguntar si desea corregir la ortografía
-    let options = vec!["Sí", "No"];
-    let selection = Select::new(
+    // Preguntar si desea corregir la ortografía y con qué corrector
+    let spelling = Config::load()?.spelling;
+    if let Some(backend) = prompt_spelling_backend(
         "¿Deseas corregir la ortografía de las descripciones?",
-        options,
-    )
-    .prompt();
-
-    // Aplicar corrección ortográfica si se seleccionó "Sí"
-    let final_test_cases = if let Ok("Sí") = selection {
-        println!("{}", "Corrigiendo ortografía...".blue());
-
-        let mut corrected_cases = Vec::new();
-
-        for mut test_case in test_cases {
-            println!(
-                "{}",
-                format!("Corrigiendo: {}", test_case.description).blue()
-            );
-
-            // Corregir ortografía
-            let corrected_description = correct_spelling(&test_case.description);
-
-            // Verificar si hubo cambios
-            if corrected_description != test_case.description {
-                println!(
-                    "{}",
-                    format!(
-                        "Descripción corregida: {} -> {}",
-                        test_case.description, corrected_description
-                    )
-                    .green()
-                );
-                test_case.description = corrected_description;
-            }
-
-            corrected_cases.push(test_case);
+        &spelling,
+    ) {
+        let mut fields = SpellingField::descriptions(&mut test_cases);
+        if !correct_fields(backend, &spelling, &mut fields)? {
+            println!("{}", "Operación cancelada.".yellow());
+            return Ok(());
         }
-
-        corrected_cases
-    } else {
-        test_cases
-    };
+    }
 
     // Guardar el archivo base (definición) en CSV
-    save_to_csv(&base_csv_path, &final_test_cases)?;
+    storage.save(&base_csv_path, &test_cases)?;
 
     // Preguntar si desea ejecutar los casos de prueba ahora
     let options = vec!["Sí", "No"];
@@ -158,7 +125,7 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
 
     if let Ok("Sí") = selection {
         // Ejecutar los casos de prueba recién creados
-        execute_test_cases_from_definition(&base_csv_path)?;
+        execute_test_cases_from_definition(&base_csv_path, &ExecuteOptions::default())?;
     } else {
         println!(
             "{}",
@@ -173,3 +140,74 @@ pub fn create_test_cases(test_type: &str, name: Option<String>) -> io::Result<()
 
     Ok(())
 }
+
+/// Solicita la prioridad de un caso de prueba, o ninguna si se cancela
+pub(crate) fn prompt_priority(current: Option<Priority>) -> Option<Priority> {
+    let mut options = vec!["Sin prioridad"];
+    options.extend(Priority::ALL.iter().map(|p| p.label()));
+
+    let cursor = current
+        .and_then(|c| Priority::ALL.iter().position(|p| *p == c))
+        .map_or(0, |i| i + 1);
+
+    match Select::new("Prioridad:", options)
+        .with_starting_cursor(cursor)
+        .raw_prompt()
+    {
+        Ok(selected) if selected.index > 0 => Some(Priority::ALL[selected.index - 1]),
+        Ok(_) => None,
+        Err(_) => current,
+    }
+}
+
+/// Pregunta si se sobrescribe la definición cuando ya existe
+pub(crate) fn confirm_overwrite(
+    storage: &dyn Storage,
+    reference: &str,
+    base_name: &str,
+) -> io::Result<bool> {
+    if !storage.exists(reference)? {
+        return Ok(true);
+    }
+
+    let options = vec!["Sí", "No"];
+    let selection = Select::new(
+        format!(
+            "Ya existe un archivo con el nombre '{}'. ¿Deseas sobrescribirlo?",
+            base_name
+        )
+        .as_str(),
+        options,
+    )
+    .prompt();
+
+    Ok(!matches!(selection, Ok("No")))
+}
+
+/// Solicita los pasos de un caso de prueba hasta que se deje la acción vacía
+pub(crate) fn prompt_steps() -> Vec<TestStep> {
+    let mut steps = Vec::new();
+
+    println!(
+        "{}",
+        "Ingresa los pasos del caso. Deja la acción vacía para terminar.".blue()
+    );
+
+    loop {
+        let action = Text::new(&format!("Paso #{} - Acción:", steps.len() + 1))
+            .prompt()
+            .unwrap_or_default();
+
+        if action.trim().is_empty() {
+            break;
+        }
+
+        let expected = Text::new("Resultado esperado:")
+            .prompt()
+            .unwrap_or_default();
+
+        steps.push(TestStep::new(action.trim(), expected.trim()));
+    }
+
+    steps
+}
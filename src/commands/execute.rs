@@ -1,209 +1,745 @@
-use std::io;
-use std::path::Path;
-use chrono::Local;
 use colored::*;
-use inquire::{Select, Text};
+use inquire::error::InquireResult;
+use inquire::{Confirm, Select, Text};
+use std::io;
 
-use crate::models::{TestCase, TestStatus};
-use crate::utils::{get_definition_files, load_from_csv, save_to_csv, save_to_markdown};
+use crate::commands::load_test_cases;
+use crate::config::Config;
+use crate::engine::{
+    base_name_of, get_drafts, is_definition, load_results, parse_set, CaseFilter, Draft, Execution,
+    PlanProgress, TestPlan,
+};
+use crate::integrations::{JiraClient, TicketCache};
+use crate::models::{suggested_status, TestCase, TestStatus};
+use crate::report::{save_plan_report, save_to_html, save_to_junit};
+use crate::storage::{open_storage, Storage};
+use crate::tui;
+
+/// Opciones de ejecución comunes a los modos interactivo y no interactivo
+#[derive(Debug, Clone, Default)]
+pub struct ExecuteOptions {
+    /// Ruta donde guardar además un informe JUnit XML
+    pub junit_path: Option<String>,
+    /// Ruta donde guardar además un informe HTML
+    pub html_path: Option<String>,
+    /// Casos a ejecutar; los demás se omiten de la ejecución
+    pub filter: CaseFilter,
+    /// Responder los casos en la interfaz a pantalla completa en lugar de con preguntas
+    pub tui: bool,
+}
 
 /// Ejecuta casos de prueba
-pub fn execute_test_cases(file_path: &str) -> io::Result<()> {
+pub fn execute_test_cases(file_path: &str, options: &ExecuteOptions) -> io::Result<()> {
+    let storage = open_storage()?;
+
     // Verificar si el archivo es una definición o una ejecución anterior
-    if file_path.starts_with("definitions/") {
+    if is_definition(file_path) {
         // Si es una definición, ejecutar a partir de ella
-        execute_test_cases_from_definition(file_path)
-    } else {
-        // Si es una ejecución anterior, mostrar mensaje y preguntar
-        println!("{}", "NOTA: Estás ejecutando a partir de un archivo de ejecución anterior, no de una definición base.".yellow());
-
-        let options = vec![
-            "Continuar con este archivo",
-            "Seleccionar una definición base",
-        ];
-        let selection = Select::new("¿Qué deseas hacer?", options).prompt();
-
-        match selection {
-            Ok("Seleccionar una definición base") => {
-                // Listar archivos de definición
-                let definitions = get_definition_files()?;
-
-                if definitions.is_empty() {
-                    println!("{}", "No hay archivos de definición disponibles.".yellow());
-                    return Ok(());
-                }
+        return execute_test_cases_from_definition(file_path, options);
+    }
 
-                let selection =
-                    Select::new("Selecciona un archivo de definición:", definitions).prompt();
+    // Si es una ejecución anterior, mostrar mensaje y preguntar
+    println!("{}", "NOTA: Estás ejecutando a partir de un archivo de ejecución anterior, no de una definición base.".yellow());
 
-                match selection {
-                    Ok(definition_path) => execute_test_cases_from_definition(&definition_path),
-                    Err(_) => {
-                        println!("{}", "Operación cancelada.".yellow());
-                        Ok(())
-                    }
-                }
+    let choices = vec![
+        "Continuar con este archivo",
+        "Seleccionar una definición base",
+    ];
+    let selection = Select::new("¿Qué deseas hacer?", choices).prompt();
+
+    match selection {
+        Ok("Seleccionar una definición base") => {
+            // Listar archivos de definición
+            let definitions = storage.list_definitions()?;
+
+            if definitions.is_empty() {
+                println!("{}", "No hay archivos de definición disponibles.".yellow());
+                return Ok(());
             }
-            _ => {
-                // Ejecutar a partir del archivo seleccionado (ejecución anterior)
-                let mut test_cases = load_from_csv(file_path)?;
 
-                if test_cases.is_empty() {
-                    println!("{}", "No hay casos de prueba para ejecutar.".yellow());
-                    return Ok(());
+            let selection =
+                Select::new("Selecciona un archivo de definición:", definitions).prompt();
+
+            match selection {
+                Ok(definition_path) => {
+                    execute_test_cases_from_definition(&definition_path, options)
+                }
+                Err(_) => {
+                    println!("{}", "Operación cancelada.".yellow());
+                    Ok(())
                 }
+            }
+        }
+        _ => {
+            // Ejecutar a partir del archivo seleccionado (ejecución anterior)
+            let test_cases = load_test_cases(storage.as_ref(), file_path)?;
+            let execution = Execution::from_loaded(storage.as_ref(), file_path, test_cases)?;
 
-                // Generar nombre para el nuevo archivo de ejecución
-                let timestamp = Local::now().format("%Y%m%d_%H%M%S");
+            println!(
+                "{}",
+                format!("Ejecutando casos de prueba a partir de {}", file_path).blue()
+            );
 
-                // Obtener el nombre base del archivo de ejecución anterior
-                let base_name = Path::new(file_path)
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("test_cases");
+            execute_test_cases_impl(storage.as_ref(), options, file_path, execution)
+        }
+    }
+}
 
-                // Extraer el nombre base sin el timestamp anterior
-                let name_parts: Vec<&str> = base_name.split('-').collect();
-                let clean_base_name = if name_parts.len() > 1 {
-                    name_parts[0..name_parts.len() - 1].join("-")
-                } else {
-                    base_name.to_string()
-                };
+/// Ejecuta casos de prueba a partir de un archivo de definición
+pub fn execute_test_cases_from_definition(
+    definition_path: &str,
+    options: &ExecuteOptions,
+) -> io::Result<()> {
+    let storage = open_storage()?;
 
-                // Crear rutas para los nuevos archivos de ejecución
-                let execution_name = format!("{}-{}", clean_base_name, timestamp);
-                let execution_csv_path = format!("executions/{}.csv", execution_name);
-                let execution_md_path = format!("executions/{}.md", execution_name);
+    // Cargar los casos de prueba desde el archivo de definición
+    let test_cases = load_test_cases(storage.as_ref(), definition_path)?;
+    let execution = Execution::from_loaded(storage.as_ref(), definition_path, test_cases)?;
 
-                println!(
-                    "{}",
+    println!(
+        "{}",
+        format!(
+            "Ejecutando casos de prueba de la definición {}",
+            definition_path
+        )
+        .blue()
+    );
+
+    execute_test_cases_impl(storage.as_ref(), options, definition_path, execution)
+}
+
+/// Reanuda una ejecución interrumpida desde el primer caso sin responder
+///
+/// Si se indica un archivo, solo se ofrecen los borradores iniciados a partir de él.
+/// Los borradores de un plan continúan con el resto de sus definiciones.
+pub fn resume_execution(file_path: Option<&str>, options: &ExecuteOptions) -> io::Result<()> {
+    let drafts: Vec<Draft> = get_drafts()?
+        .into_iter()
+        .filter(|d| file_path.is_none_or(|path| d.source == path))
+        .collect();
+
+    resume_draft(drafts, options)
+}
+
+/// Reanuda un plan de pruebas interrumpido desde el primer caso sin responder
+pub fn resume_plan(plan: &str, options: &ExecuteOptions) -> io::Result<()> {
+    let name = TestPlan::load(plan)?.name;
+    let drafts: Vec<Draft> = get_drafts()?
+        .into_iter()
+        .filter(|d| d.plan.as_ref().is_some_and(|p| p.name == name))
+        .collect();
+
+    resume_draft(drafts, options)
+}
+
+/// Pregunta qué borrador reanudar si hay más de uno y lo reanuda
+fn resume_draft(mut drafts: Vec<Draft>, options: &ExecuteOptions) -> io::Result<()> {
+    let draft = match drafts.len() {
+        0 => {
+            println!(
+                "{}",
+                "No hay ejecuciones interrumpidas para reanudar.".yellow()
+            );
+            return Ok(());
+        }
+        1 => drafts.remove(0),
+        _ => {
+            let options: Vec<String> = drafts
+                .iter()
+                .map(|d| {
+                    let plan = d
+                        .plan
+                        .as_ref()
+                        .map(|p| format!("plan {}, ", p.name))
+                        .unwrap_or_default();
                     format!(
-                        "Continuando ejecución a partir de {}",
-                        file_path
+                        "{} ({}{}/{} casos)",
+                        d.execution.name,
+                        plan,
+                        d.answered,
+                        d.execution.test_cases.len()
                     )
-                    .blue()
-                );
-                println!(
-                    "{}",
-                    format!("Los resultados se guardarán en {}", execution_csv_path).blue()
-                );
+                })
+                .collect();
 
-                // Ejecutar los casos de prueba
-                execute_test_cases_impl(&mut test_cases, &execution_csv_path, &execution_md_path, &execution_name)
+            match Select::new("Selecciona la ejecución a reanudar:", options).raw_prompt() {
+                Ok(selected) => drafts.remove(selected.index),
+                Err(_) => {
+                    println!("{}", "Operación cancelada.".yellow());
+                    return Ok(());
+                }
             }
         }
-    }
+    };
+
+    println!(
+        "{}",
+        format!(
+            "Reanudando {} desde el caso #{}",
+            draft.execution.name,
+            draft.answered + 1
+        )
+        .blue()
+    );
+
+    let storage = open_storage()?;
+    let mut draft = draft;
+    let Some(plan) = draft.plan.take() else {
+        return run_draft(storage.as_ref(), options, draft);
+    };
+
+    let completed = plan
+        .completed
+        .iter()
+        .map(|reference| Execution::load(storage.as_ref(), reference))
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut queue = vec![draft];
+    queue.extend(plan.remaining);
+
+    run_plan(storage.as_ref(), options, &plan.name, completed, queue)
 }
 
-/// Ejecuta casos de prueba a partir de un archivo de definición
-pub fn execute_test_cases_from_definition(definition_path: &str) -> io::Result<()> {
-    // Cargar los casos de prueba desde el archivo de definición
-    let mut test_cases = load_from_csv(definition_path)?;
+/// Ejecuta casos de prueba sin preguntas, aplicando resultados de un archivo
+/// y de valores `ID=estado[:observación]`
+pub fn execute_test_cases_with_results(
+    file_path: &str,
+    results_path: Option<&str>,
+    set_values: &[String],
+    options: &ExecuteOptions,
+) -> io::Result<()> {
+    let storage = open_storage()?;
+    let test_cases = load_test_cases(storage.as_ref(), file_path)?;
+    let mut execution = Execution::from_loaded(storage.as_ref(), file_path, test_cases)?;
+
+    // Los valores de --set se aplican después del archivo para poder sobrescribirlo
+    let mut results = match results_path {
+        Some(path) => load_results(path)?,
+        None => Vec::new(),
+    };
+    for value in set_values {
+        results.push(parse_set(value)?);
+    }
+
+    // Los IDs se validan contra todos los casos, antes de aplicar el filtro
+    execution.apply_results(&results)?;
+    execution.retain_matching(&options.filter);
+
+    let (results, filtered_out): (Vec<_>, Vec<_>) = results
+        .into_iter()
+        .partition(|r| execution.test_cases.iter().any(|tc| tc.id == r.id));
+    if !filtered_out.is_empty() {
+        let mut ids: Vec<&str> = filtered_out.iter().map(|r| r.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        println!(
+            "{}",
+            format!(
+                "Se ignoran los resultados de casos excluidos por el filtro: {}",
+                ids.join(", ")
+            )
+            .yellow()
+        );
+    }
 
-    if test_cases.is_empty() {
+    if execution.test_cases.is_empty() {
         println!("{}", "No hay casos de prueba para ejecutar.".yellow());
         return Ok(());
     }
 
-    // Generar nombre para el archivo de ejecución
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-
-    // Obtener el nombre base del archivo de definición
-    let base_name = Path::new(definition_path)
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("test_cases");
-
-    // Crear rutas para los archivos de ejecución
-    let execution_name = format!("{}-{}", base_name, timestamp);
-    let execution_csv_path = format!("executions/{}.csv", execution_name);
-    let execution_md_path = format!("executions/{}.md", execution_name);
+    execution.save(storage.as_ref())?;
+    save_extra_reports(&execution, options)?;
 
     println!(
         "{}",
         format!(
-            "Ejecutando casos de prueba de la definición {}",
-            definition_path
+            "Se registraron {} resultado(s). Ejecución guardada en {} y {}",
+            results.len(),
+            execution.reference(storage.as_ref()),
+            execution.md_path()
         )
-        .blue()
+        .green()
     );
+
+    Ok(())
+}
+
+/// Ejecuta todas las definiciones de un plan de pruebas en una sola sesión
+///
+/// Cada definición se guarda como una ejecución propia y, al terminar, se
+/// genera un informe combinado `executions/plan-<nombre>-<timestamp>.md`.
+pub fn execute_plan(plan: &str, options: &ExecuteOptions) -> io::Result<()> {
+    let storage = open_storage()?;
+    let test_plan = TestPlan::load(plan)?;
+    let mut executions = test_plan.executions(storage.as_ref())?;
+    for execution in executions.iter_mut() {
+        execution.retain_matching(&options.filter);
+    }
+
+    let total_cases: usize = executions.iter().map(|e| e.test_cases.len()).sum();
+    if total_cases == 0 {
+        println!("{}", "No hay casos de prueba para ejecutar.".yellow());
+        return Ok(());
+    }
+
     println!(
         "{}",
-        format!("Los resultados se guardarán en {}", execution_csv_path).blue()
+        format!(
+            "Ejecutando el plan {} ({} definiciones, {} casos)",
+            test_plan.name,
+            executions.len(),
+            total_cases
+        )
+        .blue()
     );
 
-    // Ejecutar los casos de prueba
-    execute_test_cases_impl(&mut test_cases, &execution_csv_path, &execution_md_path, &execution_name)
+    let queue: Vec<Draft> = test_plan
+        .definitions
+        .iter()
+        .zip(executions)
+        .map(|(entry, execution)| Draft::new(&entry.reference(storage.as_ref()), execution))
+        .collect();
+
+    run_plan(
+        storage.as_ref(),
+        options,
+        &test_plan.name,
+        Vec::new(),
+        queue,
+    )
 }
 
-/// Implementación de la ejecución de casos de prueba
-fn execute_test_cases_impl(
-    test_cases: &mut [TestCase],
-    execution_csv_path: &str,
-    execution_md_path: &str,
-    execution_name: &str,
+/// Responde las definiciones pendientes de un plan y genera el informe combinado
+///
+/// Cada borrador lleva el avance del plan, para que `execute --resume` pueda
+/// continuarlo si se interrumpe.
+fn run_plan(
+    storage: &dyn Storage,
+    options: &ExecuteOptions,
+    plan_name: &str,
+    mut completed: Vec<Execution>,
+    queue: Vec<Draft>,
 ) -> io::Result<()> {
-    for i in 0..test_cases.len() {
+    let total_definitions = completed.len() + queue.len();
+    let mut skipped = 0;
+    let mut queue = queue.into_iter();
+
+    while let Some(mut draft) = queue.next() {
         println!(
             "{}",
-            format!("Caso de prueba #{}: {}", i + 1, test_cases[i].description.clone()).blue()
+            format!(
+                "\nDefinición {}/{}: {}",
+                completed.len() + skipped + 1,
+                total_definitions,
+                base_name_of(&draft.execution.name)
+            )
+            .bold()
         );
 
-        // Mostrar estado actual
-        println!("Estado actual: {}", test_cases[i].status);
-
-        // Seleccionar nuevo estado
-        let status_options = vec![
-            "⏳ Pendiente",
-            "✅ Validado",
-            "❌ Rechazado",
-            "⏭️ Omitido",
-            "🚫 Bloqueado",
-        ];
-
-        let new_status = Select::new("Selecciona el resultado de la ejecución:", status_options)
-            .prompt()
-            .unwrap_or("⏳ Pendiente");
-
-        test_cases[i].status = match new_status {
-            "✅ Validado" => TestStatus::Validated,
-            "❌ Rechazado" => TestStatus::Rejected,
-            "⏭️ Omitido" => TestStatus::Skipped,
-            "🚫 Bloqueado" => TestStatus::Blocked,
-            _ => TestStatus::Pending,
-        };
+        if draft.execution.test_cases.is_empty() {
+            println!("{}", "No hay casos de prueba para ejecutar.".yellow());
+            skipped += 1;
+            continue;
+        }
+
+        draft.plan = Some(PlanProgress {
+            name: plan_name.to_string(),
+            completed: completed.iter().map(|e| e.reference(storage)).collect(),
+            remaining: queue.as_slice().to_vec(),
+        });
 
-        // Agregar observaciones
-        let current_observations = test_cases[i].observations.clone();
-        let observations = Text::new("Observaciones (opcional):")
-            .with_initial_value(&current_observations)
-            .prompt()
-            .unwrap_or_else(|_| current_observations);
+        match answer_draft(storage, options, draft)? {
+            Some(execution) => completed.push(execution),
+            None => {
+                println!(
+                    "{}",
+                    format!(
+                        "Plan interrumpido: {} de {} definiciones completadas. El informe combinado se generará al reanudarlo.",
+                        completed.len() + skipped,
+                        total_definitions
+                    )
+                    .yellow()
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    // La ejecución combinada solo se usa para los informes; cada definición ya se guardó
+    let mut combined = Execution::new(
+        &format!("plan-{}", plan_name),
+        completed
+            .iter()
+            .flat_map(|e| e.test_cases.iter().cloned())
+            .collect(),
+    );
+    combined.ensure_unique_name(storage)?;
+
+    save_plan_report(&combined.md_path(), plan_name, &completed)?;
+    save_extra_reports(&combined, options)?;
+
+    println!(
+        "{}",
+        format!(
+            "Plan {} completado. Informe combinado guardado en {}",
+            plan_name,
+            combined.md_path()
+        )
+        .green()
+    );
+
+    Ok(())
+}
 
-        test_cases[i].observations = observations;
+/// Implementación de la ejecución interactiva de casos de prueba
+fn execute_test_cases_impl(
+    storage: &dyn Storage,
+    options: &ExecuteOptions,
+    source: &str,
+    mut execution: Execution,
+) -> io::Result<()> {
+    execution.retain_matching(&options.filter);
 
-        // Agregar evidencia
-        let current_evidence = test_cases[i].evidence.clone();
-        let evidence = Text::new("Evidencia (ruta o URL, opcional):")
-            .with_initial_value(&current_evidence)
-            .prompt()
-            .unwrap_or_else(|_| current_evidence);
+    if execution.test_cases.is_empty() {
+        println!("{}", "No hay casos de prueba para ejecutar.".yellow());
+        return Ok(());
+    }
 
-        test_cases[i].evidence = evidence;
+    if !options.filter.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "Se ejecutarán {} casos que cumplen el filtro.",
+                execution.test_cases.len()
+            )
+            .blue()
+        );
     }
 
-    // Guardar resultados en los archivos de ejecución
-    save_to_csv(execution_csv_path, test_cases)?;
-    save_to_markdown(execution_md_path, test_cases, execution_name)?;
+    run_draft(storage, options, Draft::new(source, execution))
+}
+
+/// Pregunta los casos pendientes de un borrador y guarda la ejecución con sus informes
+fn run_draft(storage: &dyn Storage, options: &ExecuteOptions, draft: Draft) -> io::Result<()> {
+    let Some(execution) = answer_draft(storage, options, draft)? else {
+        return Ok(());
+    };
+
+    save_extra_reports(&execution, options)?;
 
     println!(
         "{}",
         format!(
             "Ejecución de casos de prueba completada y guardada en {} y {}",
-            execution_csv_path, execution_md_path
+            execution.reference(storage),
+            execution.md_path()
         )
         .green()
     );
 
     Ok(())
 }
+
+/// Responde los casos pendientes de un borrador con preguntas o a pantalla
+/// completa, guardándolo después de cada respuesta
+///
+/// Devuelve la ejecución guardada, o `None` si se interrumpió y quedó el borrador.
+fn answer_draft(
+    storage: &dyn Storage,
+    options: &ExecuteOptions,
+    mut draft: Draft,
+) -> io::Result<Option<Execution>> {
+    println!(
+        "{}",
+        format!(
+            "Los resultados se guardarán en {}",
+            draft.execution.reference(storage)
+        )
+        .blue()
+    );
+
+    let jira = JiraClient::from_config(&Config::load()?.jira)?;
+
+    let completed = if options.tui {
+        let completed = tui::run_draft(&mut draft)?;
+        // La pantalla completa no admite preguntas; las incidencias se ofrecen al salir
+        if let (true, Some(jira)) = (completed, &jira) {
+            for test_case in draft.execution.test_cases.iter_mut() {
+                if needs_issue(test_case) {
+                    println!(
+                        "{}",
+                        format!("Caso rechazado {}: {}", test_case.id, test_case.description)
+                            .blue()
+                    );
+                    offer_jira_issue(jira, test_case);
+                }
+            }
+        }
+        completed
+    } else {
+        let tickets = TicketCache::load().unwrap_or_default();
+        prompt_draft(&mut draft, jira.as_ref(), &tickets)?
+    };
+
+    if !completed {
+        // Guardar lo respondido hasta ahora en lugar de completar con estados por defecto
+        draft.save()?;
+        println!(
+            "{}",
+            format!(
+                "Ejecución interrumpida. Progreso guardado en {} ({}/{} casos).",
+                draft.path(),
+                draft.answered,
+                draft.execution.test_cases.len()
+            )
+            .yellow()
+        );
+        println!(
+            "{}",
+            "Puedes continuar más tarde con 'execute --resume'.".blue()
+        );
+        return Ok(None);
+    }
+
+    // Guardar resultados en los archivos de ejecución
+    draft.execution.save(storage)?;
+    draft.remove()?;
+
+    Ok(Some(draft.execution))
+}
+
+/// Pregunta uno a uno los casos pendientes de un borrador
+///
+/// Se muestra el estado de los tickets sincronizados de cada caso y, si Jira
+/// está configurado, se ofrece crear una incidencia para cada caso rechazado.
+/// Devuelve `false` si se canceló una pregunta.
+fn prompt_draft(
+    draft: &mut Draft,
+    jira: Option<&JiraClient>,
+    tickets: &TicketCache,
+) -> io::Result<bool> {
+    while !draft.is_complete() {
+        let i = draft.answered;
+        let test_case = &mut draft.execution.test_cases[i];
+
+        println!(
+            "{}",
+            format!("Caso de prueba #{}: {}", i + 1, test_case.description).blue()
+        );
+        print_case_tickets(test_case, tickets);
+
+        if prompt_case_result(test_case).is_err() {
+            return Ok(false);
+        }
+
+        if let Some(jira) = jira {
+            if needs_issue(test_case) {
+                offer_jira_issue(jira, test_case);
+            }
+        }
+
+        draft.answered += 1;
+        draft.save()?;
+    }
+
+    Ok(true)
+}
+
+/// Muestra el estado de los tickets sincronizados de un caso
+fn print_case_tickets(test_case: &TestCase, tickets: &TicketCache) {
+    for ticket in tickets.tickets_of(test_case) {
+        println!(
+            "Ticket {}: {} - {}",
+            ticket.key,
+            ticket.status_label(),
+            ticket.title
+        );
+    }
+
+    if !tickets.closed_blockers(test_case).is_empty() {
+        println!(
+            "{}",
+            "⚠️ El caso estaba bloqueado por un ticket que ya está cerrado: vuelve a ejecutarlo."
+                .yellow()
+        );
+    }
+}
+
+/// Indica si un caso está rechazado y todavía no tiene incidencia
+fn needs_issue(test_case: &TestCase) -> bool {
+    test_case.status == TestStatus::Rejected && test_case.issue_key.is_empty()
+}
+
+/// Ofrece crear una incidencia en Jira para un caso rechazado y guarda su clave
+///
+/// Un error de Jira no interrumpe la ejecución: se informa y el caso queda sin clave.
+fn offer_jira_issue(jira: &JiraClient, test_case: &mut TestCase) {
+    let confirmed = Confirm::new("¿Crear una incidencia en Jira para este caso?")
+        .with_default(true)
+        .prompt()
+        .unwrap_or(false);
+
+    if !confirmed {
+        return;
+    }
+
+    match jira.create_issue(test_case) {
+        Ok(issue_key) => {
+            println!(
+                "{}",
+                format!(
+                    "Incidencia {} creada: {}",
+                    issue_key,
+                    jira.browse_url(&issue_key)
+                )
+                .green()
+            );
+            test_case.issue_key = issue_key;
+        }
+        Err(e) => println!(
+            "{}",
+            format!("No se pudo crear la incidencia en Jira: {}", e).red()
+        ),
+    }
+}
+
+/// Guarda los informes adicionales pedidos en las opciones
+fn save_extra_reports(execution: &Execution, options: &ExecuteOptions) -> io::Result<()> {
+    if let Some(junit_path) = &options.junit_path {
+        save_to_junit(junit_path, &execution.test_cases, &execution.name)?;
+        println!(
+            "{}",
+            format!("Informe JUnit guardado en {}", junit_path).green()
+        );
+    }
+
+    if let Some(html_path) = &options.html_path {
+        save_to_html(html_path, &execution.test_cases, &execution.name)?;
+        println!(
+            "{}",
+            format!("Informe HTML guardado en {}", html_path).green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Solicita el resultado de los pasos, el estado, las observaciones y la
+/// evidencia de un caso de prueba
+///
+/// El caso solo se modifica si se responden todas las preguntas.
+fn prompt_case_result(test_case: &mut TestCase) -> InquireResult<()> {
+    if !test_case.preconditions.is_empty() {
+        println!("Precondiciones: {}", test_case.preconditions);
+    }
+    if !test_case.test_data.is_empty() {
+        println!("Datos de prueba: {}", test_case.test_data);
+    }
+
+    // Marcar cada paso antes del resultado general del caso
+    let mut steps = test_case.steps.clone();
+    let total_steps = steps.len();
+    for (i, step) in steps.iter_mut().enumerate() {
+        println!(
+            "{}",
+            format!("Paso {}/{}: {}", i + 1, total_steps, step.action).cyan()
+        );
+        if !step.expected.is_empty() {
+            println!("Resultado esperado: {}", step.expected);
+        }
+
+        step.status = Select::new("Resultado del paso:", TestStatus::ALL.to_vec())
+            .with_starting_cursor(status_index(step.status))
+            .prompt()?;
+    }
+
+    // Mostrar estado actual
+    println!("Estado actual: {}", test_case.status);
+
+    let suggested = suggested_status(&steps).unwrap_or(test_case.status);
+    let status = Select::new(
+        "Selecciona el resultado de la ejecución:",
+        TestStatus::ALL.to_vec(),
+    )
+    .with_starting_cursor(status_index(suggested))
+    .prompt()?;
+
+    // Agregar observaciones
+    let observations = Text::new("Observaciones (opcional):")
+        .with_initial_value(&test_case.observations)
+        .prompt()?;
+
+    // Agregar evidencia
+    let evidence = Text::new("Evidencia (ruta o URL, opcional):")
+        .with_initial_value(&test_case.evidence)
+        .prompt()?;
+
+    test_case.steps = steps;
+    test_case.status = status;
+    test_case.observations = observations;
+    test_case.evidence = evidence;
+
+    Ok(())
+}
+
+/// Posición de un estado en `TestStatus::ALL`
+fn status_index(status: TestStatus) -> usize {
+    TestStatus::ALL
+        .iter()
+        .position(|s| *s == status)
+        .unwrap_or(0)
+}
+
+/// Solicita un estado de prueba, o `Pendiente` si se cancela
+pub(crate) fn prompt_status(message: &str) -> TestStatus {
+    Select::new(message, TestStatus::ALL.to_vec())
+        .prompt()
+        .unwrap_or(TestStatus::Pending)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::CaseFilter;
+    use crate::storage::{ensure_directories, CsvStorage};
+    use crate::test_support::TempWorkingDir;
+
+    #[test]
+    fn results_for_cases_excluded_by_the_filter_are_ignored() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+        let mut smoke = TestCase::new("Caso smoke", "", "");
+        smoke.id = "TC-1".to_string();
+        smoke.tags = vec!["smoke".to_string()];
+        let mut other = TestCase::new("Otro caso", "", "");
+        other.id = "TC-2".to_string();
+        let definition = CsvStorage.definition_ref("login");
+        CsvStorage.save(&definition, &[smoke, other]).unwrap();
+
+        let options = ExecuteOptions {
+            filter: CaseFilter {
+                tags: vec!["smoke".to_string()],
+                ..CaseFilter::default()
+            },
+            ..ExecuteOptions::default()
+        };
+        let set_values = ["TC-1=Validated".to_string(), "TC-2=Rejected".to_string()];
+        execute_test_cases_with_results(&definition, None, &set_values, &options).unwrap();
+
+        let executions = CsvStorage.list_executions().unwrap();
+        assert_eq!(executions.len(), 1);
+        let saved = CsvStorage.load(&executions[0]).unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].id, "TC-1");
+        assert_eq!(saved[0].status, TestStatus::Validated);
+
+        // Un ID que no existe en la definición sigue siendo un error
+        let error = execute_test_cases_with_results(
+            &definition,
+            None,
+            &["TC-9=Validated".to_string()],
+            &options,
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,34 @@
+use colored::*;
+use std::io;
+
+use crate::commands::load_test_cases;
+use crate::engine::file_stem;
+use crate::report::ReportFormat;
+use crate::storage::{open_storage, report_path};
+
+/// Exporta una definición o ejecución a otro formato de informe
+///
+/// Sin ruta de salida, el informe se guarda junto a la ejecución con la
+/// extensión del formato.
+pub fn export_test_cases(
+    file_path: &str,
+    format: ReportFormat,
+    output: Option<&str>,
+) -> io::Result<()> {
+    let storage = open_storage()?;
+    let test_cases = load_test_cases(storage.as_ref(), file_path)?;
+
+    let output = match output {
+        Some(output) => output.to_string(),
+        None => report_path(file_path, format.extension()),
+    };
+
+    format.save(&output, &test_cases, &file_stem(file_path))?;
+
+    println!(
+        "{}",
+        format!("{} casos exportados a {}", test_cases.len(), output).green()
+    );
+
+    Ok(())
+}
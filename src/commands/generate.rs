@@ -0,0 +1,216 @@
+use colored::*;
+use inquire::{MultiSelect, Select, Text};
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+
+use crate::commands::create::{confirm_overwrite, TEST_TYPES};
+use crate::commands::execute::{execute_test_cases_from_definition, ExecuteOptions};
+use crate::commands::modify::{edit_test_case_field, DEFINITION_FIELDS};
+use crate::models::TestCase;
+use crate::storage::open_storage;
+use crate::utils::{propose_test_cases, LlmProvider};
+
+/// Opción para terminar la edición de las propuestas
+const SAVE_SELECTED: &str = "Ninguno, guardar los casos";
+
+/// Propone casos de prueba con el proveedor LLM a partir de un requisito o
+/// historia de usuario y guarda los elegidos como definición
+///
+/// El requisito se lee del archivo indicado, o de la entrada estándar si no se
+/// indica ninguno o se indica `-`. La versión y los tickets se preguntan si no
+/// se reciben.
+pub fn generate_test_cases(
+    test_type: &str,
+    name: Option<String>,
+    input: Option<&str>,
+    version: Option<String>,
+    ticket_numbers: Option<String>,
+) -> io::Result<()> {
+    // Validar tipo de prueba
+    if !TEST_TYPES.contains(&test_type) {
+        println!(
+            "{}",
+            "Tipo de prueba inválido. Use 'smoke', 'regression' o 'functional'.".red()
+        );
+        return Ok(());
+    }
+
+    let requirement = read_requirement(input)?;
+    if requirement.trim().is_empty() {
+        println!("{}", "El requisito está vacío.".yellow());
+        return Ok(());
+    }
+
+    let version = version.unwrap_or_else(|| {
+        Text::new("Versión de prueba:")
+            .prompt()
+            .unwrap_or_else(|_| String::from("1.0.0"))
+    });
+    let ticket_numbers = ticket_numbers.unwrap_or_else(|| {
+        Text::new("Número(s) de ticket (opcional):")
+            .prompt()
+            .unwrap_or_default()
+    });
+
+    let base_name = match name {
+        Some(n) if !n.is_empty() => format!("{}-{}", test_type, n),
+        _ => test_type.to_string(),
+    };
+
+    let storage = open_storage()?;
+    let base_csv_path = storage.definition_ref(&base_name);
+
+    if !confirm_overwrite(storage.as_ref(), &base_csv_path, &base_name)? {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
+    }
+
+    let provider = LlmProvider::load()?;
+    println!(
+        "{}",
+        format!("Generando casos de prueba con {}...", provider.describe()).blue()
+    );
+
+    let proposals = propose_test_cases(&provider, &requirement, &version, &ticket_numbers)?;
+    if proposals.is_empty() {
+        println!("{}", "El modelo no propuso ningún caso de prueba.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Casos propuestos ({}):", proposals.len()).blue()
+    );
+    for (i, test_case) in proposals.iter().enumerate() {
+        print_proposal(i + 1, test_case);
+    }
+
+    let Some(mut test_cases) = select_proposals(proposals) else {
+        println!("{}", "No se seleccionó ningún caso de prueba.".yellow());
+        return Ok(());
+    };
+
+    review_proposals(&mut test_cases);
+
+    storage.save(&base_csv_path, &test_cases)?;
+
+    // Preguntar si desea ejecutar los casos de prueba ahora
+    let options = vec!["Sí", "No"];
+    let selection = Select::new("¿Deseas ejecutar estos casos de prueba ahora?", options).prompt();
+
+    if let Ok("Sí") = selection {
+        execute_test_cases_from_definition(&base_csv_path, &ExecuteOptions::default())?;
+    } else {
+        println!(
+            "{}",
+            format!(
+                "{} casos de prueba guardados en {}",
+                test_cases.len(),
+                base_csv_path
+            )
+            .green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Lee el requisito de un archivo o de la entrada estándar
+fn read_requirement(input: Option<&str>) -> io::Result<String> {
+    match input {
+        Some(path) if path != "-" => fs::read_to_string(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("No se pudo leer el requisito de {}: {}", path, e),
+            )
+        }),
+        _ => {
+            if io::stdin().is_terminal() {
+                println!(
+                    "{}",
+                    "Escribe o pega el requisito y termina con Ctrl+D:".blue()
+                );
+            }
+            let mut requirement = String::new();
+            io::stdin().read_to_string(&mut requirement)?;
+            Ok(requirement)
+        }
+    }
+}
+
+/// Muestra una propuesta con sus precondiciones y pasos
+fn print_proposal(number: usize, test_case: &TestCase) {
+    let priority = test_case
+        .priority
+        .map(|p| format!("[{}] ", p))
+        .unwrap_or_default();
+    println!(
+        "{}",
+        format!("{}. {}{}", number, priority, test_case.description).bold()
+    );
+
+    if !test_case.preconditions.is_empty() {
+        println!("   Precondiciones: {}", test_case.preconditions);
+    }
+    if !test_case.test_data.is_empty() {
+        println!("   Datos de prueba: {}", test_case.test_data);
+    }
+    if !test_case.component.is_empty() {
+        println!("   Componente: {}", test_case.component);
+    }
+    if !test_case.tags.is_empty() {
+        println!("   Etiquetas: {}", test_case.tags.join(", "));
+    }
+    for (i, step) in test_case.steps.iter().enumerate() {
+        println!("   {}. {} -> {}", i + 1, step.action, step.expected);
+    }
+}
+
+/// Pregunta qué propuestas se conservan; todas vienen marcadas
+///
+/// Devuelve `None` si no se elige ninguna o se cancela.
+fn select_proposals(proposals: Vec<TestCase>) -> Option<Vec<TestCase>> {
+    let options: Vec<String> = proposals
+        .iter()
+        .enumerate()
+        .map(|(i, tc)| format!("{}. {}", i + 1, tc.description))
+        .collect();
+
+    let all: Vec<usize> = (0..options.len()).collect();
+    let selected = MultiSelect::new("Selecciona los casos a guardar:", options)
+        .with_default(&all)
+        .raw_prompt()
+        .ok()?;
+
+    let indexes: Vec<usize> = selected.iter().map(|option| option.index).collect();
+    let test_cases: Vec<TestCase> = proposals
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| indexes.contains(i))
+        .map(|(_, tc)| tc)
+        .collect();
+
+    (!test_cases.is_empty()).then_some(test_cases)
+}
+
+/// Permite editar los casos elegidos hasta que el usuario decide guardarlos
+fn review_proposals(test_cases: &mut [TestCase]) {
+    loop {
+        let mut options: Vec<String> = test_cases
+            .iter()
+            .enumerate()
+            .map(|(i, tc)| format!("{}. {}", i + 1, tc.description))
+            .collect();
+        options.push(String::from(SAVE_SELECTED));
+
+        let index = match Select::new("¿Deseas editar algún caso antes de guardar?", options)
+            .raw_prompt()
+        {
+            Ok(selected) if selected.index < test_cases.len() => selected.index,
+            _ => return,
+        };
+
+        edit_test_case_field(&mut test_cases[index], &DEFINITION_FIELDS);
+        print_proposal(index + 1, &test_cases[index]);
+    }
+}
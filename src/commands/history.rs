@@ -0,0 +1,80 @@
+use colored::*;
+use std::io;
+
+use crate::analysis::History;
+use crate::report::save_history_to_markdown;
+use crate::storage::{open_storage, EXECUTIONS_DIR};
+
+/// Muestra el historial de ejecuciones de una definición y guarda su informe de tendencia
+///
+/// Sin ruta de salida, el informe se guarda en `executions/<definición>-history.md`.
+pub fn show_history(definition: &str, output: Option<&str>) -> io::Result<()> {
+    let storage = open_storage()?;
+    let history = History::load(storage.as_ref(), definition)?;
+
+    if history.runs.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "No hay ejecuciones de la definición {}.",
+                history.definition
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Historial de {} ({} ejecuciones)",
+            history.definition,
+            history.runs.len()
+        )
+        .blue()
+    );
+
+    for (i, run) in history.runs.iter().enumerate() {
+        let rate = format!("{:5.1}%", run.pass_rate());
+        let rate = if run.summary.rejected + run.summary.blocked > 0 {
+            rate.red()
+        } else if run.summary.validated == run.summary.total {
+            rate.green()
+        } else {
+            rate.yellow()
+        };
+
+        println!(
+            "  {:>3}. {}  {}  ({}/{} validados)",
+            i + 1,
+            run.label(),
+            rate,
+            run.summary.validated,
+            run.summary.total
+        );
+    }
+
+    println!("\n{}", "Evolución por caso:".bold());
+    for (id, description) in history.cases() {
+        let timeline: Vec<&str> = history
+            .timeline(&id)
+            .into_iter()
+            .map(|status| status.map_or("—", |s| s.emoji()))
+            .collect();
+
+        println!("  {}  {}  {}", id, timeline.join(" "), description);
+    }
+
+    let output = match output {
+        Some(output) => output.to_string(),
+        None => format!("{}/{}-history.md", EXECUTIONS_DIR, history.definition),
+    };
+
+    save_history_to_markdown(&output, &history)?;
+    println!(
+        "{}",
+        format!("Informe de tendencia guardado en {}", output).green()
+    );
+
+    Ok(())
+}
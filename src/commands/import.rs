@@ -0,0 +1,314 @@
+use colored::*;
+use inquire::Select;
+use std::io;
+use std::path::Path;
+
+use crate::commands::create::confirm_overwrite;
+use crate::config::Config;
+use crate::engine::file_stem;
+use crate::models::TestCase;
+use crate::report::{
+    load_from_feature, map_external_rows, CaseField, ColumnMapping, ExternalCsv, ExternalTool,
+};
+use crate::storage::{
+    csv_files_in, open_storage, read_csv_document, MigrationDefaults, SqliteStorage, Storage,
+    DEFINITIONS_DIR, EXECUTIONS_DIR, LEGACY_TESTS_DIR,
+};
+
+/// Importa a la base SQLite las definiciones y ejecuciones CSV de un directorio
+///
+/// Se leen `definitions/`, `executions/` y `tests/` dentro de `root_dir`. Las
+/// entradas que ya existen en la base de datos se reemplazan.
+pub fn import_csv_tree(root_dir: &str, database: Option<&str>) -> io::Result<()> {
+    let database = match database {
+        Some(database) => database.to_string(),
+        None => Config::load()?.storage.database,
+    };
+
+    let storage = SqliteStorage::open(&database)?;
+
+    println!(
+        "{}",
+        format!("Importando archivos CSV de {} en {}", root_dir, database).blue()
+    );
+
+    let root = Path::new(root_dir);
+    let mut imported = 0;
+    let mut failed = 0;
+
+    let sources = [
+        (DEFINITIONS_DIR, DEFINITIONS_DIR),
+        (EXECUTIONS_DIR, EXECUTIONS_DIR),
+        // Las ejecuciones antiguas de tests/ se importan como ejecuciones
+        (LEGACY_TESTS_DIR, EXECUTIONS_DIR),
+    ];
+
+    for (source_dir, target_dir) in sources {
+        let dir = root.join(source_dir);
+        let Some(dir) = dir.to_str() else {
+            continue;
+        };
+
+        for file in csv_files_in(dir)? {
+            let reference = format!("{}/{}", target_dir, file_stem(&file));
+
+            match read_csv_document(&file).and_then(|document| {
+                storage.save(&reference, &document.test_cases)?;
+                Ok(document)
+            }) {
+                Ok(document) => {
+                    imported += 1;
+                    println!(
+                        "{} -> {} ({} casos)",
+                        file,
+                        reference,
+                        document.test_cases.len()
+                    );
+                    for warning in &document.warnings {
+                        println!("{}", format!("  {}", warning).yellow());
+                    }
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("{}", format!("{}: {}", file, e).red());
+                }
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Importación terminada: {} archivo(s) importado(s), {} con errores.",
+            imported, failed
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+/// Importa los escenarios de un archivo Gherkin `.feature` como una definición
+///
+/// Cada escenario, y cada fila de ejemplos de un esquema de escenario, es un
+/// caso. La definición se llama como el archivo si no se indica otro nombre, y
+/// la versión y los tickets indicados se usan en los escenarios que no los
+/// tienen en sus etiquetas.
+pub fn import_feature(
+    file_path: &str,
+    name: Option<&str>,
+    defaults: &MigrationDefaults,
+) -> io::Result<()> {
+    let mut test_cases = load_from_feature(file_path)?;
+
+    if test_cases.is_empty() {
+        println!(
+            "{}",
+            format!("{} no contiene escenarios.", file_path).yellow()
+        );
+        return Ok(());
+    }
+
+    defaults.apply(&mut test_cases);
+
+    let base_name = match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => file_stem(file_path),
+    };
+
+    let storage = open_storage()?;
+    let reference = storage.definition_ref(&base_name);
+
+    if !confirm_overwrite(storage.as_ref(), &reference, &base_name)? {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
+    }
+
+    storage.save(&reference, &test_cases)?;
+
+    println!(
+        "{}",
+        format!(
+            "{} escenarios de {} importados en {}",
+            test_cases.len(),
+            file_path,
+            reference
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+/// Opciones de la vista previa de la importación
+const IMPORT: &str = "Importar";
+const CHANGE_COLUMN: &str = "Cambiar la asignación de una columna";
+const CANCEL: &str = "Cancelar";
+const IGNORE_COLUMN: &str = "(ignorar la columna)";
+
+/// Importa un CSV exportado de TestRail o Xray como una definición
+///
+/// Antes de guardar se muestra a qué campo se asigna cada columna, con un valor
+/// de ejemplo, y cuántos casos resultan; la asignación se puede corregir y las
+/// filas que no se pueden interpretar se listan en lugar de omitirse en silencio.
+pub fn import_external_csv(
+    tool: ExternalTool,
+    file_path: &str,
+    name: Option<&str>,
+    defaults: &MigrationDefaults,
+) -> io::Result<()> {
+    let csv = ExternalCsv::load(file_path)?;
+
+    if csv.rows.is_empty() {
+        println!("{}", format!("{} no contiene filas.", file_path).yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Importando el CSV de {} {}", tool, file_path).blue()
+    );
+
+    let mut mapping = ColumnMapping::detect(&csv.headers);
+
+    let mut mapped = loop {
+        print_mapping(&csv, &mapping);
+        let mapped = map_external_rows(&csv, &mapping);
+        print_mapped_summary(&mapped.test_cases, &mapped.warnings);
+
+        let mut options = vec![CHANGE_COLUMN, CANCEL];
+        if !mapped.test_cases.is_empty() {
+            options.insert(0, IMPORT);
+        }
+
+        match Select::new("¿Qué deseas hacer?", options).prompt() {
+            Ok(IMPORT) => break mapped,
+            Ok(CHANGE_COLUMN) => change_column(&csv, &mut mapping),
+            _ => {
+                println!("{}", "Operación cancelada.".yellow());
+                return Ok(());
+            }
+        }
+    };
+
+    defaults.apply(&mut mapped.test_cases);
+
+    let base_name = match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => file_stem(file_path),
+    };
+
+    let storage = open_storage()?;
+    let reference = storage.definition_ref(&base_name);
+
+    if !confirm_overwrite(storage.as_ref(), &reference, &base_name)? {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
+    }
+
+    storage.save(&reference, &mapped.test_cases)?;
+
+    println!(
+        "{}",
+        format!(
+            "{} casos de {} importados en {}",
+            mapped.test_cases.len(),
+            file_path,
+            reference
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+/// Muestra la asignación de cada columna con un valor de ejemplo
+fn print_mapping(csv: &ExternalCsv, mapping: &ColumnMapping) {
+    println!("{}", "Asignación de columnas:".blue());
+
+    for (column, header) in csv.headers.iter().enumerate() {
+        let sample = csv
+            .sample(column)
+            .map(|s| format!(" (ej.: {})", preview(s)))
+            .unwrap_or_default();
+
+        match mapping.fields[column] {
+            Some(field) => println!("  {} -> {}{}", header.bold(), field.label().green(), sample),
+            None => println!("  {} -> {}{}", header, "ignorada".dimmed(), sample),
+        }
+    }
+}
+
+/// Muestra cuántos casos y pasos resultan, los primeros casos y las advertencias
+fn print_mapped_summary(test_cases: &[TestCase], warnings: &[String]) {
+    let steps: usize = test_cases.iter().map(|tc| tc.steps.len()).sum();
+    println!(
+        "{}",
+        format!("Resultado: {} casos con {} pasos.", test_cases.len(), steps).blue()
+    );
+
+    for test_case in test_cases.iter().take(3) {
+        let priority = test_case
+            .priority
+            .map(|p| format!("[{}] ", p))
+            .unwrap_or_default();
+        println!(
+            "  {} {}{} ({} pasos)",
+            test_case.id,
+            priority,
+            preview(&test_case.description),
+            test_case.steps.len()
+        );
+    }
+    if test_cases.len() > 3 {
+        println!("  ...");
+    }
+
+    if !warnings.is_empty() {
+        println!("{}", format!("{} advertencias:", warnings.len()).yellow());
+        for warning in warnings.iter().take(10) {
+            println!("{}", format!("  {}", warning).yellow());
+        }
+        if warnings.len() > 10 {
+            println!("{}", format!("  y {} más", warnings.len() - 10).yellow());
+        }
+    }
+}
+
+/// Pregunta qué columna cambiar y a qué campo asignarla
+fn change_column(csv: &ExternalCsv, mapping: &mut ColumnMapping) {
+    let columns: Vec<String> = csv
+        .headers
+        .iter()
+        .zip(&mapping.fields)
+        .map(|(header, field)| match field {
+            Some(field) => format!("{} -> {}", header, field),
+            None => format!("{} -> ignorada", header),
+        })
+        .collect();
+
+    let Ok(column) = Select::new("Columna:", columns).raw_prompt() else {
+        return;
+    };
+
+    let mut fields: Vec<String> = vec![String::from(IGNORE_COLUMN)];
+    fields.extend(CaseField::ALL.iter().map(|field| field.label().to_string()));
+
+    if let Ok(selected) = Select::new("Campo:", fields).raw_prompt() {
+        let field = selected
+            .index
+            .checked_sub(1)
+            .map(|index| CaseField::ALL[index]);
+        mapping.assign(column.index, field);
+    }
+}
+
+/// Primera línea de un valor, acortada para la vista previa
+fn preview(value: &str) -> String {
+    let line = value.lines().next().unwrap_or("");
+    if line.chars().count() > 40 || value.lines().nth(1).is_some() {
+        format!("{}…", line.chars().take(40).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
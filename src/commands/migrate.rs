@@ -0,0 +1,85 @@
+use colored::*;
+use std::io;
+
+use crate::storage::{
+    get_definition_files, get_execution_files, migrate_csv, read_csv_document, MigrationDefaults,
+    SCHEMA_VERSION,
+};
+
+/// Actualiza archivos CSV antiguos al esquema actual
+///
+/// Sin archivo, revisa todas las definiciones y ejecuciones (incluida la carpeta `tests/`).
+pub fn migrate_files(file_path: Option<&str>, defaults: &MigrationDefaults) -> io::Result<()> {
+    let files = match file_path {
+        Some(path) => vec![path.to_string()],
+        None => {
+            let mut files = get_definition_files()?;
+            files.extend(get_execution_files()?);
+            files
+        }
+    };
+
+    if files.is_empty() {
+        println!("{}", "No hay archivos de prueba para migrar.".yellow());
+        return Ok(());
+    }
+
+    let mut migrated = 0;
+    let mut failed = 0;
+
+    for file in &files {
+        let document = match read_csv_document(file) {
+            Ok(document) => document,
+            Err(e) => {
+                println!("{}", format!("{}: {}", file, e).red());
+                failed += 1;
+                continue;
+            }
+        };
+
+        if !document.needs_migration() {
+            println!("{}: ya usa el esquema v{}", file, SCHEMA_VERSION);
+            continue;
+        }
+
+        match migrate_csv(file, defaults) {
+            Ok(Some(backup_path)) => {
+                migrated += 1;
+                println!(
+                    "{}",
+                    format!(
+                        "{}: v{} -> v{} ({} casos, copia en {})",
+                        file,
+                        document.schema_version,
+                        SCHEMA_VERSION,
+                        document.test_cases.len(),
+                        backup_path
+                    )
+                    .green()
+                );
+                if !document.missing_columns.is_empty() {
+                    println!(
+                        "  Columnas añadidas: {}",
+                        document.missing_columns.join(", ")
+                    );
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                println!("{}", format!("{}: {}", file, e).red());
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Migración terminada: {} archivo(s) actualizado(s), {} con errores.",
+            migrated, failed
+        )
+        .blue()
+    );
+
+    Ok(())
+}
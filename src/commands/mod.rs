@@ -1,9 +1,49 @@
+pub mod analyze;
+pub mod compare;
 pub mod create;
 pub mod execute;
+pub mod export;
+pub mod generate;
+pub mod history;
+pub mod import;
+pub mod migrate;
 pub mod modify;
+pub mod proofread;
+pub mod spelling;
+pub mod tickets;
 pub mod view;
 
+pub use analyze::*;
+pub use compare::*;
 pub use create::*;
 pub use execute::*;
+pub use export::*;
+pub use generate::*;
+pub use history::*;
+pub use import::*;
+pub use migrate::*;
 pub use modify::*;
+pub use proofread::*;
+pub use tickets::*;
 pub use view::*;
+
+use colored::*;
+use std::io;
+
+use crate::models::TestCase;
+use crate::storage::Storage;
+
+/// Carga los casos de prueba de una definición o ejecución y muestra los
+/// problemas encontrados en filas concretas
+pub fn load_test_cases(storage: &dyn Storage, reference: &str) -> io::Result<Vec<TestCase>> {
+    let (test_cases, warnings) = storage.load_with_warnings(reference)?;
+
+    for warning in &warnings {
+        println!(
+            "{}",
+            format!("Error al leer caso de prueba: {}", warning).red()
+        );
+    }
+
+    Ok(test_cases)
+}
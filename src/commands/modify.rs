@@ -1,14 +1,19 @@
-use std::io;
-use std::path::Path;
 use colored::*;
 use inquire::{Select, Text};
+use std::io;
 
-use crate::models::{TestCase, TestStatus};
-use crate::utils::{load_from_csv, save_to_csv, save_to_markdown};
+use crate::commands::create::{prompt_priority, prompt_steps};
+use crate::commands::execute::prompt_status;
+use crate::commands::load_test_cases;
+use crate::engine::file_stem;
+use crate::models::{parse_tags, TestCase};
+use crate::report::save_to_markdown;
+use crate::storage::{markdown_path, open_storage};
 
 /// Modifica casos de prueba existentes
 pub fn modify_test_cases(file_path: &str) -> io::Result<()> {
-    let mut test_cases = load_from_csv(file_path)?;
+    let storage = open_storage()?;
+    let mut test_cases = load_test_cases(storage.as_ref(), file_path)?;
 
     if test_cases.is_empty() {
         println!("{}", "No hay casos de prueba para modificar.".yellow());
@@ -30,111 +35,140 @@ pub fn modify_test_cases(file_path: &str) -> io::Result<()> {
     // Seleccionar caso de prueba a modificar
     let selection = Select::new(
         "Selecciona un caso de prueba para modificar:",
-        (0..test_cases.len())
-            .map(|i| format!("{}: {}", i + 1, test_cases[i].description))
+        test_cases
+            .iter()
+            .enumerate()
+            .map(|(i, tc)| format!("{}: {}", i + 1, tc.description))
             .collect::<Vec<_>>(),
     )
-    .prompt();
-
-    match selection {
-        Ok(selected) => {
-            let index = selected
-                .split(':')
-                .next()
-                .unwrap_or("1")
-                .parse::<usize>()
-                .unwrap_or(1)
-                - 1;
-
-            if index < test_cases.len() {
-                let options = vec!["Descripción", "Estado", "Observaciones", "Evidencia", "Versión", "Ticket(s)"];
-
-                let field = Select::new("¿Qué campo deseas modificar?", options)
-                    .prompt()
-                    .unwrap_or("Estado");
-
-                match field {
-                    "Descripción" => {
-                        let new_description = Text::new("Nueva descripción:")
-                            .with_initial_value(&test_cases[index].description)
-                            .prompt()
-                            .unwrap_or_else(|_| test_cases[index].description.clone());
-
-                        test_cases[index].description = new_description;
-                    }
-                    "Estado" => {
-                        let status_options = vec![
-                            "⏳ Pendiente",
-                            "✅ Validado",
-                            "❌ Rechazado",
-                            "⏭️ Omitido",
-                            "🚫 Bloqueado",
-                        ];
-
-                        let new_status = Select::new("Nuevo estado:", status_options)
-                            .prompt()
-                            .unwrap_or("⏳ Pendiente");
-
-                        test_cases[index].status = match new_status {
-                            "✅ Validado" => TestStatus::Validated,
-                            "❌ Rechazado" => TestStatus::Rejected,
-                            "⏭️ Omitido" => TestStatus::Skipped,
-                            "🚫 Bloqueado" => TestStatus::Blocked,
-                            _ => TestStatus::Pending,
-                        };
-                    }
-                    "Observaciones" => {
-                        let new_observations = Text::new("Nuevas observaciones:")
-                            .with_initial_value(&test_cases[index].observations)
-                            .prompt()
-                            .unwrap_or_else(|_| test_cases[index].observations.clone());
-
-                        test_cases[index].observations = new_observations;
-                    }
-                    "Evidencia" => {
-                        let new_evidence = Text::new("Nueva evidencia (ruta o URL):")
-                            .with_initial_value(&test_cases[index].evidence)
-                            .prompt()
-                            .unwrap_or_else(|_| test_cases[index].evidence.clone());
-
-                        test_cases[index].evidence = new_evidence;
-                    }
-                    "Versión" => {
-                        let new_version = Text::new("Nueva versión:")
-                            .with_initial_value(&test_cases[index].version)
-                            .prompt()
-                            .unwrap_or_else(|_| test_cases[index].version.clone());
-
-                        test_cases[index].version = new_version;
-                    }
-                    "Ticket(s)" => {
-                        let new_tickets = Text::new("Nuevos ticket(s):")
-                            .with_initial_value(&test_cases[index].ticket_numbers)
-                            .prompt()
-                            .unwrap_or_else(|_| test_cases[index].ticket_numbers.clone());
-
-                        test_cases[index].ticket_numbers = new_tickets;
-                    }
-                    _ => {}
-                }
-
-                // Guardar cambios
-                save_to_csv(file_path, &test_cases)?;
-
-                // Actualizar archivo markdown
-                let md_path = file_path.replace(".csv", ".md");
-                let file_name = Path::new(file_path)
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("test_cases");
-
-                save_to_markdown(&md_path, &test_cases, file_name)?;
-
-                println!("{}", "Caso de prueba modificado correctamente.".green());
-            }
+    .raw_prompt();
+
+    let index = match selection {
+        Ok(selected) => selected.index,
+        Err(_) => {
+            println!("{}", "Operación cancelada.".yellow());
+            return Ok(());
         }
-        Err(_) => println!("{}", "Operación cancelada.".yellow()),
-    }
+    };
+
+    edit_test_case_field(&mut test_cases[index], &CASE_FIELDS);
+
+    // Guardar cambios
+    storage.save(file_path, &test_cases)?;
+
+    // Actualizar archivo markdown
+    save_to_markdown(
+        &markdown_path(file_path),
+        &test_cases,
+        &file_stem(file_path),
+    )?;
+
+    println!("{}", "Caso de prueba modificado correctamente.".green());
 
     Ok(())
 }
+
+/// Campos que se pueden modificar en un caso de prueba
+const CASE_FIELDS: [&str; 11] = [
+    "Descripción",
+    "Estado",
+    "Observaciones",
+    "Evidencia",
+    "Precondiciones",
+    "Datos de prueba",
+    "Pasos",
+    "Prioridad",
+    "Componente",
+    "Etiquetas",
+    "Incidencia",
+];
+
+/// Campos de un caso que todavía no se ha ejecutado
+pub(crate) const DEFINITION_FIELDS: [&str; 7] = [
+    "Descripción",
+    "Precondiciones",
+    "Datos de prueba",
+    "Pasos",
+    "Prioridad",
+    "Componente",
+    "Etiquetas",
+];
+
+/// Pregunta qué campo modificar, entre los indicados, y solicita su nuevo valor
+pub(crate) fn edit_test_case_field(test_case: &mut TestCase, fields: &[&str]) {
+    let Ok(field) = Select::new("¿Qué campo deseas modificar?", fields.to_vec()).prompt() else {
+        return;
+    };
+
+    match field {
+        "Descripción" => {
+            test_case.description = Text::new("Nueva descripción:")
+                .with_initial_value(&test_case.description)
+                .prompt()
+                .unwrap_or_else(|_| test_case.description.clone());
+        }
+        "Estado" => {
+            test_case.status = prompt_status("Nuevo estado:");
+        }
+        "Observaciones" => {
+            test_case.observations = Text::new("Nuevas observaciones:")
+                .with_initial_value(&test_case.observations)
+                .prompt()
+                .unwrap_or_else(|_| test_case.observations.clone());
+        }
+        "Evidencia" => {
+            test_case.evidence = Text::new("Nueva evidencia (ruta o URL):")
+                .with_initial_value(&test_case.evidence)
+                .prompt()
+                .unwrap_or_else(|_| test_case.evidence.clone());
+        }
+        "Precondiciones" => {
+            test_case.preconditions = Text::new("Nuevas precondiciones:")
+                .with_initial_value(&test_case.preconditions)
+                .prompt()
+                .unwrap_or_else(|_| test_case.preconditions.clone());
+        }
+        "Datos de prueba" => {
+            test_case.test_data = Text::new("Nuevos datos de prueba:")
+                .with_initial_value(&test_case.test_data)
+                .prompt()
+                .unwrap_or_else(|_| test_case.test_data.clone());
+        }
+        "Pasos" => {
+            // Mostrar los pasos actuales antes de reemplazarlos
+            for (i, step) in test_case.steps.iter().enumerate() {
+                println!("{}. {} -> {}", i + 1, step.action, step.expected);
+            }
+
+            let steps = prompt_steps();
+            if !steps.is_empty() {
+                test_case.steps = steps;
+            }
+        }
+        "Prioridad" => {
+            test_case.priority = prompt_priority(test_case.priority);
+        }
+        "Componente" => {
+            test_case.component = Text::new("Nuevo componente:")
+                .with_initial_value(&test_case.component)
+                .prompt()
+                .unwrap_or_else(|_| test_case.component.clone());
+        }
+        "Etiquetas" => {
+            if let Ok(tags) = Text::new("Nuevas etiquetas separadas por comas:")
+                .with_initial_value(&test_case.tags.join(", "))
+                .prompt()
+            {
+                test_case.tags = parse_tags(&tags);
+            }
+        }
+        "Incidencia" => {
+            test_case.issue_key = Text::new("Clave de la incidencia (como QA-123):")
+                .with_initial_value(&test_case.issue_key)
+                .prompt()
+                .map(|key| key.trim().to_string())
+                .unwrap_or_else(|_| test_case.issue_key.clone());
+        }
+        _ => {}
+    }
+}
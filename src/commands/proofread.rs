@@ -0,0 +1,167 @@
+use colored::*;
+use std::io;
+
+use crate::commands::load_test_cases;
+use crate::commands::spelling::{correct_fields, prompt_spelling_backend, SpellingField};
+use crate::config::Config;
+use crate::engine::{file_stem, is_definition, Execution};
+use crate::models::TestCase;
+use crate::report::save_to_markdown;
+use crate::storage::{markdown_path, open_storage, Storage};
+
+/// Corrige la ortografía de las descripciones y observaciones de una definición
+/// o ejecución existente y actualiza su informe Markdown
+pub fn proofread_test_cases(file_path: &str) -> io::Result<()> {
+    let storage = open_storage()?;
+    let mut test_cases = load_test_cases(storage.as_ref(), file_path)?;
+
+    if test_cases.is_empty() {
+        println!("{}", "No hay casos de prueba para corregir.".yellow());
+        return Ok(());
+    }
+
+    let spelling = Config::load()?.spelling;
+    let Some(backend) = prompt_spelling_backend(
+        "¿Con qué corrector deseas revisar las descripciones y observaciones?",
+        &spelling,
+    ) else {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
+    };
+
+    let original = test_cases.clone();
+    let mut fields = SpellingField::descriptions_and_observations(&mut test_cases);
+    if !correct_fields(backend, &spelling, &mut fields)? {
+        println!("{}", "Operación cancelada.".yellow());
+        return Ok(());
+    }
+
+    let changed = original
+        .iter()
+        .zip(&test_cases)
+        .filter(|(before, after)| {
+            before.description != after.description || before.observations != after.observations
+        })
+        .count();
+
+    if changed == 0 {
+        println!("{}", "No hay correcciones que guardar.".blue());
+        return Ok(());
+    }
+
+    save_corrections(storage.as_ref(), file_path, test_cases)?;
+
+    println!(
+        "{}",
+        format!(
+            "{} casos corregidos en {} y su informe Markdown actualizado.",
+            changed, file_path
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+/// Guarda los casos corregidos y regenera el informe Markdown que les corresponde
+///
+/// Las ejecuciones se guardan con su informe completo (casos inestables y
+/// tickets); las definiciones y los demás archivos, con el informe básico.
+fn save_corrections(
+    storage: &dyn Storage,
+    file_path: &str,
+    test_cases: Vec<TestCase>,
+) -> io::Result<()> {
+    // `./executions/...` es la misma ejecución que `executions/...`
+    let reference = file_path.trim_start_matches("./");
+    let execution = Execution {
+        name: file_stem(reference),
+        test_cases,
+    };
+
+    if !is_definition(reference) && execution.reference(storage) == reference {
+        execution.save(storage)
+    } else {
+        storage.save(file_path, &execution.test_cases)?;
+        save_to_markdown(
+            &markdown_path(file_path),
+            &execution.test_cases,
+            &execution.name,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::{TicketCache, TicketInfo};
+    use crate::storage::{ensure_directories, CsvStorage};
+    use crate::test_support::TempWorkingDir;
+    use std::fs;
+
+    fn corrected_cases() -> Vec<TestCase> {
+        let mut test_case = TestCase::new("Abrir el menú", "1.0", "QA-1");
+        test_case.observations = "Se muestra el botón".to_string();
+        vec![test_case]
+    }
+
+    fn sync_ticket() {
+        let mut tickets = TicketCache::default();
+        tickets.insert(TicketInfo {
+            key: "QA-1".to_string(),
+            title: "Menú principal".to_string(),
+            state: "open".to_string(),
+            closed: false,
+            synced_at: "2024-05-01 10:00:00".to_string(),
+        });
+        tickets.save().unwrap();
+    }
+
+    #[test]
+    fn definitions_get_the_basic_report() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+        sync_ticket();
+        let definition = CsvStorage.definition_ref("login");
+
+        let test_cases = corrected_cases();
+
+        save_corrections(&CsvStorage, &definition, test_cases.clone()).unwrap();
+
+        let saved = CsvStorage.load(&definition).unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].id, test_cases[0].id);
+        assert_eq!(saved[0].observations, "Se muestra el botón");
+        let report = fs::read_to_string("definitions/login.md").unwrap();
+        assert!(report.contains("login"));
+        assert!(report.contains("Abrir el menú"));
+        assert!(!report.contains("## Tickets"));
+        assert!(CsvStorage.list_executions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn executions_get_the_full_report() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+        sync_ticket();
+        let name = "login-20240501_100000";
+
+        for file_path in [
+            CsvStorage.execution_ref(name),
+            format!("./{}", CsvStorage.execution_ref(name)),
+        ] {
+            save_corrections(&CsvStorage, &file_path, corrected_cases()).unwrap();
+
+            let report = fs::read_to_string(format!("executions/{}.md", name)).unwrap();
+            assert!(report.contains("Se muestra el botón"));
+            assert!(report.contains("## Tickets"), "{}", file_path);
+            assert!(report.contains("Menú principal"));
+            fs::remove_file(format!("executions/{}.md", name)).unwrap();
+        }
+
+        assert_eq!(
+            CsvStorage.list_executions().unwrap(),
+            vec![CsvStorage.execution_ref(name)]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use colored::*;
+use inquire::{Select, Text};
+use std::collections::HashSet;
+use std::io;
+
+use crate::config::{SpellingBackend, SpellingConfig};
+use crate::models::TestCase;
+use crate::utils::{
+    correct_spelling_batch, misspelled_words, remember_corrections, word_diff, Dictionary,
+    LlmProvider, WordDiff,
+};
+
+/// Carga el diccionario Hunspell configurado, informando si no está disponible
+pub(crate) fn load_dictionary(config: &SpellingConfig) -> Option<Dictionary> {
+    match Dictionary::find(&config.language, config.dictionary_dir.as_deref()) {
+        Ok(dictionary) => Some(dictionary),
+        Err(e) => {
+            println!("{}", format!("ADVERTENCIA: {}", e).yellow());
+            None
+        }
+    }
+}
+
+/// Texto a corregir junto con la etiqueta que lo identifica en la revisión
+pub(crate) struct SpellingField<'a> {
+    /// Caso y campo, como `TC-001 · Observaciones`
+    pub label: String,
+    pub text: &'a mut String,
+}
+
+impl<'a> SpellingField<'a> {
+    /// Descripciones de los casos
+    pub fn descriptions(test_cases: &'a mut [TestCase]) -> Vec<Self> {
+        test_cases
+            .iter_mut()
+            .map(|test_case| SpellingField {
+                label: format!("{} · Descripción", test_case.id),
+                text: &mut test_case.description,
+            })
+            .collect()
+    }
+
+    /// Descripciones y observaciones no vacías de los casos
+    pub fn descriptions_and_observations(test_cases: &'a mut [TestCase]) -> Vec<Self> {
+        let mut fields = Vec::new();
+        for test_case in test_cases.iter_mut() {
+            fields.push(SpellingField {
+                label: format!("{} · Descripción", test_case.id),
+                text: &mut test_case.description,
+            });
+            if !test_case.observations.trim().is_empty() {
+                fields.push(SpellingField {
+                    label: format!("{} · Observaciones", test_case.id),
+                    text: &mut test_case.observations,
+                });
+            }
+        }
+        fields
+    }
+}
+
+/// Opciones de corrección ortográfica
+const SPELLING_NONE: &str = "No";
+const SPELLING_HUNSPELL: &str = "Sí, con el diccionario sin conexión (Hunspell)";
+const SPELLING_LLM: &str = "Sí, con el modelo de lenguaje";
+const SPELLING_BOTH: &str = "Sí, con el diccionario y después el modelo de lenguaje";
+
+/// Pregunta con qué corrector revisar los textos, preseleccionando el configurado
+///
+/// Devuelve `None` si el usuario no quiere corregirlos.
+pub(crate) fn prompt_spelling_backend(
+    question: &str,
+    config: &SpellingConfig,
+) -> Option<SpellingBackend> {
+    let options = vec![
+        SPELLING_NONE,
+        SPELLING_HUNSPELL,
+        SPELLING_LLM,
+        SPELLING_BOTH,
+    ];
+    let default_option = match config.backend {
+        SpellingBackend::Hunspell => 1,
+        SpellingBackend::Llm => 2,
+        SpellingBackend::Both => 3,
+    };
+
+    match Select::new(question, options)
+        .with_starting_cursor(default_option)
+        .prompt()
+    {
+        Ok(SPELLING_HUNSPELL) => Some(SpellingBackend::Hunspell),
+        Ok(SPELLING_LLM) => Some(SpellingBackend::Llm),
+        Ok(SPELLING_BOTH) => Some(SpellingBackend::Both),
+        _ => None,
+    }
+}
+
+/// Corrige los textos con el corrector elegido: primero el diccionario y
+/// después el modelo de lenguaje
+///
+/// Devuelve `false` si el usuario cancela la corrección con el modelo.
+pub(crate) fn correct_fields(
+    backend: SpellingBackend,
+    config: &SpellingConfig,
+    fields: &mut [SpellingField],
+) -> io::Result<bool> {
+    if matches!(backend, SpellingBackend::Hunspell | SpellingBackend::Both) {
+        if let Some(dictionary) = load_dictionary(config) {
+            review_fields_offline(&dictionary, fields);
+        }
+    }
+
+    if matches!(backend, SpellingBackend::Llm | SpellingBackend::Both) {
+        return correct_fields_online(fields);
+    }
+
+    Ok(true)
+}
+
+/// Revisa con el diccionario la ortografía de los textos, preguntando por cada
+/// palabra desconocida
+pub(crate) fn review_fields_offline(dictionary: &Dictionary, fields: &mut [SpellingField]) {
+    println!(
+        "{}",
+        format!(
+            "Revisando ortografía con el diccionario {} ({} palabras)...",
+            dictionary.language,
+            dictionary.len()
+        )
+        .blue()
+    );
+
+    // Palabras que el usuario decidió ignorar en el resto de la revisión
+    let mut ignored = HashSet::new();
+
+    for field in fields.iter_mut() {
+        if !misspelled_words(dictionary, field.text)
+            .iter()
+            .any(|(_, word)| !ignored.contains(word))
+        {
+            continue;
+        }
+
+        println!("{}", field.label.bold());
+        let (reviewed, finished) = review_text_offline(dictionary, field.text, &mut ignored);
+
+        if reviewed != *field.text {
+            println!(
+                "{}",
+                format!("Corrección: '{}' -> '{}'", field.text, reviewed).green()
+            );
+            *field.text = reviewed;
+        }
+
+        if !finished {
+            println!("{}", "Revisión ortográfica interrumpida.".yellow());
+            return;
+        }
+    }
+}
+
+/// Revisa un texto palabra por palabra con el diccionario
+///
+/// Devuelve el texto corregido y `false` si se canceló la revisión; en ese caso
+/// se conservan las correcciones hechas hasta entonces.
+pub(crate) fn review_text_offline(
+    dictionary: &Dictionary,
+    text: &str,
+    ignored: &mut HashSet<String>,
+) -> (String, bool) {
+    let mut replacements: Vec<(usize, String, String)> = Vec::new();
+    let mut finished = true;
+
+    for (start, word) in misspelled_words(dictionary, text) {
+        if ignored.contains(&word) {
+            continue;
+        }
+
+        let end = start + word.len();
+        println!(
+            "{}{}{}",
+            &text[..start],
+            word.red().bold().underline(),
+            &text[end..]
+        );
+
+        let keep = format!("Mantener «{}»", word);
+        let ignore = format!("Ignorar «{}» en el resto de la revisión", word);
+        let write = String::from("Escribir la corrección");
+
+        let mut options = dictionary.suggest(&word);
+        options.extend([keep.clone(), ignore.clone(), write.clone()]);
+
+        let selection =
+            match Select::new(&format!("«{}» no está en el diccionario:", word), options).prompt()
+            {
+                Ok(selection) => selection,
+                Err(_) => {
+                    finished = false;
+                    break;
+                }
+            };
+
+        let replacement = if selection == keep {
+            continue;
+        } else if selection == ignore {
+            ignored.insert(word);
+            continue;
+        } else if selection == write {
+            match Text::new("Corrección:").with_initial_value(&word).prompt() {
+                Ok(corrected) => corrected,
+                Err(_) => {
+                    finished = false;
+                    break;
+                }
+            }
+        } else {
+            selection
+        };
+
+        replacements.push((start, word, replacement));
+    }
+
+    // Reemplazar desde el final para que las posiciones sigan siendo válidas
+    let mut reviewed = text.to_string();
+    for (start, word, replacement) in replacements.into_iter().rev() {
+        reviewed.replace_range(start..start + word.len(), &replacement);
+    }
+
+    (reviewed, finished)
+}
+
+/// Corrige los textos con el proveedor LLM, revisando cada cambio
+///
+/// Devuelve `false` si falta la clave del proveedor y el usuario decide cancelar.
+pub(crate) fn correct_fields_online(fields: &mut [SpellingField]) -> io::Result<bool> {
+    let provider = LlmProvider::load()?;
+
+    // Verificar si la clave API del proveedor está configurada
+    if provider.missing_api_key() {
+        println!(
+            "{}",
+            format!(
+                "ADVERTENCIA: No se encontró la clave API en {}.",
+                provider.api_key_env
+            )
+            .yellow()
+        );
+        println!(
+            "{}",
+            format!(
+                "Para usar la corrección ortográfica, configura la variable de entorno {} o un proveedor local en la sección [llm] de test_case_manager.toml.",
+                provider.api_key_env
+            )
+            .yellow()
+        );
+        println!(
+            "{}",
+            format!("Ejemplo: export {}=tu-clave-api", provider.api_key_env).yellow()
+        );
+
+        // Preguntar si desea continuar sin corrección ortográfica
+        let continue_options = vec!["Continuar sin corrección", "Cancelar"];
+        let continue_selection = Select::new("¿Qué deseas hacer?", continue_options).prompt();
+
+        if let Ok("Cancelar") = continue_selection {
+            return Ok(false);
+        }
+
+        // Continuar sin corrección ortográfica
+        println!("{}", "Continuando sin corrección ortográfica.".blue());
+        return Ok(true);
+    }
+
+    println!(
+        "{}",
+        format!("Corrigiendo ortografía con {}...", provider.describe()).blue()
+    );
+
+    // Corregir los textos en lotes, confirmando cada cambio
+    let texts: Vec<String> = fields.iter().map(|field| field.text.clone()).collect();
+    let corrections = correct_spelling_batch(&provider, &texts);
+
+    let mut accept_all = false;
+    // Texto original y decisión del usuario, para no volver a proponer lo rechazado
+    let mut decisions = Vec::new();
+    for (field, corrected) in fields.iter_mut().zip(corrections) {
+        if corrected.trim() == field.text.trim() {
+            continue;
+        }
+
+        println!("{}", field.label.bold());
+        let original = field.text.clone();
+        if let Some(accepted) = review_correction(field.text, &corrected, &mut accept_all) {
+            *field.text = accepted;
+        }
+        decisions.push((original, field.text.clone()));
+    }
+    remember_corrections(&provider, &decisions);
+
+    Ok(true)
+}
+
+/// Opciones al revisar una corrección
+const ACCEPT: &str = "Aceptar";
+const ACCEPT_ALL: &str = "Aceptar esta y las siguientes";
+const REJECT: &str = "Rechazar (mantener el original)";
+const EDIT: &str = "Editar";
+
+/// Muestra los cambios de una corrección palabra a palabra y pregunta si se acepta
+///
+/// Devuelve el texto aceptado (la corrección o su versión editada), o `None` si
+/// no hay cambios o se rechaza. Con `accept_all` se aceptan sin preguntar; la
+/// opción «Aceptar esta y las siguientes» lo activa.
+pub(crate) fn review_correction(
+    original: &str,
+    corrected: &str,
+    accept_all: &mut bool,
+) -> Option<String> {
+    if corrected.trim() == original.trim() {
+        return None;
+    }
+
+    println!("{}", format_word_diff(original, corrected));
+
+    if *accept_all {
+        return Some(corrected.to_string());
+    }
+
+    let options = vec![ACCEPT, ACCEPT_ALL, REJECT, EDIT];
+    match Select::new("¿Aceptar la corrección?", options).prompt() {
+        Ok(ACCEPT) => Some(corrected.to_string()),
+        Ok(ACCEPT_ALL) => {
+            *accept_all = true;
+            Some(corrected.to_string())
+        }
+        Ok(EDIT) => Text::new("Texto corregido:")
+            .with_initial_value(corrected)
+            .prompt()
+            .ok()
+            .filter(|edited| edited.trim() != original.trim()),
+        _ => None,
+    }
+}
+
+/// Cambios entre dos textos: palabras eliminadas en rojo tachado y añadidas en verde
+fn format_word_diff(before: &str, after: &str) -> String {
+    word_diff(before, after)
+        .into_iter()
+        .map(|part| match part {
+            WordDiff::Same(word) => word.normal().to_string(),
+            WordDiff::Removed(word) => word.red().strikethrough().to_string(),
+            WordDiff::Added(word) => word.green().bold().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,153 @@
+use colored::*;
+use std::io;
+
+use crate::analysis::History;
+use crate::config::Config;
+use crate::integrations::{TicketCache, TicketInfo, TrackerClient, TICKETS_FILE};
+use crate::models::TestCase;
+use crate::storage::{open_storage, Storage};
+
+/// Consulta en el gestor de incidencias el estado de todos los tickets de
+/// definiciones y ejecuciones, y lo guarda en `tickets.json`
+pub fn sync_tickets() -> io::Result<()> {
+    let storage = open_storage()?;
+    let client = TrackerClient::from_config(&Config::load()?)?;
+
+    let mut keys: Vec<String> = Vec::new();
+    for reference in storage
+        .list_definitions()?
+        .into_iter()
+        .chain(storage.list_executions()?)
+    {
+        for test_case in storage.load(&reference)? {
+            for key in test_case.tickets() {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+    }
+
+    if keys.is_empty() {
+        println!("{}", "Ningún caso de prueba tiene tickets.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Consultando {} ticket(s)...", keys.len()).blue()
+    );
+
+    let mut cache = TicketCache::load()?;
+    let mut failed = 0;
+    for key in &keys {
+        match client.fetch(key) {
+            Ok(ticket) => {
+                print_ticket(&ticket);
+                cache.insert(ticket);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{}", format!("{}: {}", key, e).red());
+            }
+        }
+    }
+
+    cache.save()?;
+
+    let message = format!(
+        "Se sincronizaron {} de {} ticket(s) en {}",
+        keys.len() - failed,
+        keys.len(),
+        TICKETS_FILE
+    );
+    if failed == 0 {
+        println!("{}", message.green());
+    } else {
+        println!("{}", message.yellow());
+    }
+
+    print_rerun_candidates(storage.as_ref(), &cache)
+}
+
+/// Muestra los tickets sincronizados y los casos que pueden volver a ejecutarse
+pub fn list_tickets(storage: &dyn Storage) -> io::Result<()> {
+    let cache = TicketCache::load()?;
+    if cache.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", "Tickets sincronizados:".green());
+    for ticket in cache.tickets.values() {
+        print_ticket(ticket);
+    }
+
+    print_rerun_candidates(storage, &cache)
+}
+
+/// Línea de un ticket con su estado y título
+fn print_ticket(ticket: &TicketInfo) {
+    let line = format!(
+        "{} {} - {}",
+        ticket.key,
+        ticket.status_label(),
+        ticket.title
+    );
+    if ticket.closed {
+        println!("{}", line.dimmed());
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// Muestra los casos bloqueados en la última ejecución de cada definición
+/// cuyo ticket ya está cerrado
+fn print_rerun_candidates(storage: &dyn Storage, cache: &TicketCache) -> io::Result<()> {
+    let mut candidates: Vec<(String, TestCase, Vec<String>)> = Vec::new();
+
+    for definition in storage.list_definitions()? {
+        let history = History::load(storage, &definition)?;
+        let Some(last_run) = history.runs.last() else {
+            continue;
+        };
+
+        for test_case in &last_run.test_cases {
+            let closed: Vec<String> = cache
+                .closed_blockers(test_case)
+                .iter()
+                .map(|t| t.key.clone())
+                .collect();
+            if !closed.is_empty() {
+                candidates.push((last_run.name.clone(), test_case.clone(), closed));
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{}",
+        "⚠️ Casos bloqueados cuyo ticket ya está cerrado (vuelve a ejecutarlos):"
+            .yellow()
+            .bold()
+    );
+    for (execution, test_case, closed) in candidates {
+        println!(
+            "{}",
+            format!(
+                "  {} [{}] {} ({})",
+                execution,
+                test_case.id,
+                test_case.description,
+                closed.join(", ")
+            )
+            .yellow()
+        );
+    }
+
+    Ok(())
+}
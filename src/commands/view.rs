@@ -0,0 +1,111 @@
+use colored::*;
+use inquire::Select;
+use std::io;
+
+use crate::commands::list_tickets;
+use crate::storage::{get_plan_files, open_storage};
+
+/// Selecciona un archivo de prueba existente
+pub fn select_test_file() -> io::Result<Option<String>> {
+    let storage = open_storage()?;
+
+    // Preguntar si desea seleccionar una definición o una ejecución
+    let options = vec!["Definición", "Ejecución"];
+    let selection = Select::new("¿Qué tipo de archivo deseas seleccionar?", options).prompt();
+
+    match selection {
+        Ok("Definición") => {
+            // Obtener archivos de definición
+            let definition_files = storage.list_definitions()?;
+
+            if definition_files.is_empty() {
+                println!("{}", "No hay archivos de definición disponibles.".red());
+                return Ok(None);
+            }
+
+            let selection =
+                Select::new("Selecciona un archivo de definición:", definition_files).prompt();
+
+            Ok(selection.ok())
+        }
+        Ok("Ejecución") => {
+            // Obtener archivos de ejecución
+            let execution_files = storage.list_executions()?;
+
+            if execution_files.is_empty() {
+                println!("{}", "No hay archivos de ejecución disponibles.".red());
+                return Ok(None);
+            }
+
+            let selection =
+                Select::new("Selecciona un archivo de ejecución:", execution_files).prompt();
+
+            Ok(selection.ok())
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Selecciona un plan de pruebas de `plans/`
+pub fn select_plan_file() -> io::Result<Option<String>> {
+    let plan_files = get_plan_files()?;
+
+    if plan_files.is_empty() {
+        println!("{}", "No hay planes de pruebas disponibles.".red());
+        return Ok(None);
+    }
+
+    let selection = Select::new("Selecciona un plan de pruebas:", plan_files).prompt();
+
+    Ok(selection.ok())
+}
+
+/// Lista los archivos de prueba disponibles
+pub fn list_test_files() -> io::Result<()> {
+    let storage = open_storage()?;
+
+    // Obtener archivos de definición
+    let definition_files = storage.list_definitions()?;
+
+    // Obtener archivos de ejecución
+    let execution_files = storage.list_executions()?;
+
+    if definition_files.is_empty() && execution_files.is_empty() {
+        println!("{}", "No hay archivos de prueba disponibles.".yellow());
+        return Ok(());
+    }
+
+    // Mostrar archivos de definición
+    if !definition_files.is_empty() {
+        println!("{}", "Archivos de definición disponibles:".green());
+        for (i, file) in definition_files.iter().enumerate() {
+            println!("{}: {}", i + 1, file);
+        }
+        println!();
+    } else {
+        println!("{}", "No hay archivos de definición disponibles.".yellow());
+    }
+
+    // Mostrar archivos de ejecución
+    if !execution_files.is_empty() {
+        println!("{}", "Archivos de ejecución disponibles:".green());
+        for (i, file) in execution_files.iter().enumerate() {
+            println!("{}: {}", i + 1, file);
+        }
+    } else {
+        println!("{}", "No hay archivos de ejecución disponibles.".yellow());
+    }
+
+    // Mostrar planes de pruebas
+    let plan_files = get_plan_files()?;
+    if !plan_files.is_empty() {
+        println!();
+        println!("{}", "Planes de pruebas disponibles:".green());
+        for (i, file) in plan_files.iter().enumerate() {
+            println!("{}: {}", i + 1, file);
+        }
+    }
+
+    // Mostrar el estado de los tickets, si se sincronizaron
+    list_tickets(storage.as_ref())
+}
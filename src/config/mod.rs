@@ -0,0 +1,242 @@
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Archivo de configuración, buscado en el directorio de trabajo
+pub const CONFIG_FILE: &str = "test_case_manager.toml";
+
+/// Configuración de la herramienta
+///
+/// Todas las secciones son opcionales; sin archivo se usan los valores por defecto.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub storage: StorageConfig,
+    pub flaky: FlakyConfig,
+    pub jira: JiraConfig,
+    pub tracker: TrackerConfig,
+    pub llm: LlmConfig,
+    pub spelling: SpellingConfig,
+    pub server: ServerConfig,
+}
+
+/// Backend donde se guardan definiciones y ejecuciones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    #[default]
+    Csv,
+    Sqlite,
+}
+
+/// Sección `[storage]`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// Ruta de la base de datos cuando `backend = "sqlite"`
+    pub database: String,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig {
+            backend: StorageBackend::Csv,
+            database: String::from("test_cases.db"),
+        }
+    }
+}
+
+/// Sección `[flaky]`: detección de casos inestables
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct FlakyConfig {
+    /// Cantidad de ejecuciones más recientes que se analizan
+    pub window: usize,
+    /// Proporción mínima de cambios entre ✅ y ❌ para considerar un caso inestable
+    pub threshold: f64,
+}
+
+impl Default for FlakyConfig {
+    fn default() -> Self {
+        FlakyConfig {
+            window: 10,
+            threshold: 0.3,
+        }
+    }
+}
+
+/// Sección `[jira]`: creación de incidencias para casos rechazados
+///
+/// La integración está desactivada mientras no se indiquen `base_url` y `project`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct JiraConfig {
+    /// URL base de la instancia, como `https://empresa.atlassian.net`
+    pub base_url: String,
+    /// Clave del proyecto donde se crean las incidencias, como `QA`
+    pub project: String,
+    /// Tipo de incidencia
+    pub issue_type: String,
+    /// Variable de entorno con el usuario (correo) para la autenticación básica
+    pub user_env: String,
+    /// Variable de entorno con el token de la API
+    pub token_env: String,
+}
+
+impl Default for JiraConfig {
+    fn default() -> Self {
+        JiraConfig {
+            base_url: String::new(),
+            project: String::new(),
+            issue_type: String::from("Bug"),
+            user_env: String::from("JIRA_USER"),
+            token_env: String::from("JIRA_API_TOKEN"),
+        }
+    }
+}
+
+impl JiraConfig {
+    /// Indica si la integración está configurada
+    pub fn is_enabled(&self) -> bool {
+        !self.base_url.trim().is_empty() && !self.project.trim().is_empty()
+    }
+}
+
+/// Gestor de incidencias donde se consultan los tickets de los casos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerKind {
+    Github,
+    Gitlab,
+    Jira,
+}
+
+/// Sección `[tracker]`: consulta del estado de los tickets de `ticket_numbers`
+///
+/// Con `kind = "jira"` se usan la URL y las credenciales de la sección `[jira]`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TrackerConfig {
+    pub kind: Option<TrackerKind>,
+    /// URL base de la API; por defecto, la pública de GitHub o GitLab
+    pub base_url: String,
+    /// Repositorio (`dueño/repo`) en GitHub o proyecto (`grupo/proyecto`) en GitLab
+    pub repository: String,
+    /// Variable de entorno con el token; por defecto `GITHUB_TOKEN` o `GITLAB_TOKEN`
+    pub token_env: String,
+}
+
+/// Sección `[llm]`: proveedor compatible con la API de OpenAI para la corrección ortográfica
+/// y la generación de casos
+///
+/// Sirve cualquier servidor con `/chat/completions`, como Groq, OpenAI, Ollama o llama.cpp.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LlmConfig {
+    /// URL base de la API, sin `/chat/completions`
+    pub base_url: String,
+    pub model: String,
+    /// Variable de entorno con la clave; vacía para servidores locales sin autenticación
+    pub api_key_env: String,
+    /// Instrucciones de sistema para la corrección
+    pub prompt: String,
+    /// Instrucciones de sistema para proponer casos a partir de un requisito
+    pub generation_prompt: String,
+    /// Tiempo máximo de espera de cada petición, en segundos
+    pub timeout_secs: u64,
+    /// Reintentos ante respuestas 429 o 5xx, con espera creciente entre ellos
+    pub max_retries: u32,
+    /// Cantidad de textos que se corrigen en cada petición
+    pub batch_size: usize,
+    /// Guardar las correcciones en `.cache/` para no repetir peticiones
+    pub cache: bool,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        LlmConfig {
+            base_url: String::from("https://api.groq.com/openai/v1"),
+            model: String::from("llama3-8b-8192"),
+            api_key_env: String::from("GROQ_API_KEY"),
+            prompt: String::from(DEFAULT_SPELLING_PROMPT),
+            generation_prompt: String::from(DEFAULT_GENERATION_PROMPT),
+            timeout_secs: 60,
+            max_retries: 3,
+            batch_size: 20,
+            cache: true,
+        }
+    }
+}
+
+/// Instrucciones por defecto para la corrección ortográfica
+pub const DEFAULT_SPELLING_PROMPT: &str = "Eres un asistente especializado en corrección ortográfica y gramatical en español. Tu tarea es corregir errores ortográficos y gramaticales en el texto proporcionado, manteniendo el significado original. Solo debes devolver el texto corregido, sin explicaciones ni comentarios adicionales.";
+
+/// Instrucciones por defecto para proponer casos de prueba
+pub const DEFAULT_GENERATION_PROMPT: &str = "Eres un analista de QA con experiencia en diseño de pruebas. A partir del requisito o historia de usuario proporcionado, propón casos de prueba funcionales que cubran el flujo principal, los flujos alternativos, los casos límite y los errores esperados. Redacta los casos en español, con descripciones breves y pasos concretos y verificables, sin inventar funcionalidades que el requisito no menciona.";
+
+/// Corrector usado por defecto al crear casos
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpellingBackend {
+    /// Modelo de lenguaje de la sección `[llm]`
+    #[default]
+    Llm,
+    /// Diccionario Hunspell sin conexión
+    Hunspell,
+    /// Diccionario y después modelo de lenguaje
+    Both,
+}
+
+/// Sección `[spelling]`: revisión ortográfica
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpellingConfig {
+    pub backend: SpellingBackend,
+    /// Idioma del diccionario Hunspell, como `es_ES` o `en_US`
+    pub language: String,
+    /// Directorio con los archivos `.dic` y `.aff`; si no, se buscan en los del sistema
+    pub dictionary_dir: Option<String>,
+}
+
+impl Default for SpellingConfig {
+    fn default() -> Self {
+        SpellingConfig {
+            backend: SpellingBackend::Llm,
+            language: String::from("es_ES"),
+            dictionary_dir: None,
+        }
+    }
+}
+
+/// Sección `[server]`: API HTTP local
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    /// Orígenes web (`https://panel.example.com`) que pueden usar la API desde
+    /// un navegador; por defecto, ninguno
+    pub allowed_origins: Vec<String>,
+}
+
+impl Config {
+    /// Carga la configuración del directorio de trabajo
+    pub fn load() -> io::Result<Self> {
+        Config::load_from(CONFIG_FILE)
+    }
+
+    /// Carga la configuración de un archivo, o la configuración por defecto si no existe
+    pub fn load_from(file_path: &str) -> io::Result<Self> {
+        if !Path::new(file_path).exists() {
+            return Ok(Config::default());
+        }
+
+        let content = fs::read_to_string(file_path)?;
+        toml::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Configuración inválida en {}: {}", file_path, e),
+            )
+        })
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+use crate::engine::Execution;
+
+/// Directorio donde se guardan las ejecuciones sin terminar
+pub const DRAFTS_DIR: &str = "executions/drafts";
+
+/// Ejecución interrumpida que puede reanudarse desde el primer caso sin responder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Draft {
+    /// Archivo de definición o ejecución a partir del cual se inició
+    pub source: String,
+    /// Cantidad de casos ya respondidos, en orden
+    pub answered: usize,
+    pub execution: Execution,
+    /// Plan al que pertenece la ejecución, para reanudarlo como plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<PlanProgress>,
+}
+
+/// Avance de un plan de pruebas interrumpido
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanProgress {
+    /// Nombre del plan
+    pub name: String,
+    /// Referencias de las ejecuciones del plan ya guardadas, en orden
+    pub completed: Vec<String>,
+    /// Definiciones que quedan después de la ejecución del borrador
+    pub remaining: Vec<Draft>,
+}
+
+impl Draft {
+    /// Crea un borrador para una ejecución recién iniciada
+    pub fn new(source: &str, execution: Execution) -> Self {
+        Draft {
+            source: source.to_string(),
+            answered: 0,
+            execution,
+            plan: None,
+        }
+    }
+
+    /// Ruta del archivo del borrador
+    pub fn path(&self) -> String {
+        format!("{}/{}.json", DRAFTS_DIR, self.execution.name)
+    }
+
+    /// Indica si ya se respondieron todos los casos
+    pub fn is_complete(&self) -> bool {
+        self.answered >= self.execution.test_cases.len()
+    }
+
+    /// Carga un borrador desde su archivo
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        let file = File::open(file_path)?;
+        serde_json::from_reader(file).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Borrador inválido {}: {}", file_path, e),
+            )
+        })
+    }
+
+    /// Guarda el borrador, reemplazando la versión anterior
+    pub fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(DRAFTS_DIR)?;
+
+        // Escribir primero en un archivo temporal para no corromper el borrador si se interrumpe
+        let path = self.path();
+        let tmp_path = format!("{}.tmp", path);
+        let file = File::create(&tmp_path)?;
+        serde_json::to_writer_pretty(file, self).map_err(io::Error::other)?;
+        fs::rename(tmp_path, path)
+    }
+
+    /// Elimina el archivo del borrador
+    pub fn remove(&self) -> io::Result<()> {
+        let path = self.path();
+        if Path::new(&path).exists() {
+            fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Obtiene los borradores guardados, los más recientes primero
+pub fn get_drafts() -> io::Result<Vec<Draft>> {
+    let mut drafts = Vec::new();
+
+    if !Path::new(DRAFTS_DIR).exists() {
+        return Ok(drafts);
+    }
+
+    for entry in fs::read_dir(DRAFTS_DIR)? {
+        let path = entry?.path();
+
+        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("json") {
+            if let Some(path_str) = path.to_str() {
+                drafts.push(Draft::load(path_str)?);
+            }
+        }
+    }
+
+    drafts.sort_by(|a, b| b.execution.name.cmp(&a.execution.name));
+
+    Ok(drafts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TestCase;
+    use crate::test_support::TempWorkingDir;
+
+    #[test]
+    fn keeps_the_plan_progress() {
+        let _dir = TempWorkingDir::new();
+        let next = Draft::new(
+            "definitions/b.csv",
+            Execution::new("b", vec![TestCase::new("Caso b", "1.0", "")]),
+        );
+        let mut draft = Draft::new(
+            "definitions/a.csv",
+            Execution::new("a", vec![TestCase::new("Caso a", "1.0", "")]),
+        );
+        draft.plan = Some(PlanProgress {
+            name: String::from("release"),
+            completed: vec![String::from("executions/z-20250311_112345.csv")],
+            remaining: vec![next],
+        });
+
+        draft.save().unwrap();
+        let loaded = get_drafts().unwrap().remove(0);
+
+        let plan = loaded.plan.unwrap();
+        assert_eq!(plan.name, "release");
+        assert_eq!(plan.completed, vec!["executions/z-20250311_112345.csv"]);
+        assert_eq!(plan.remaining[0].source, "definitions/b.csv");
+        assert_eq!(
+            plan.remaining[0].execution.test_cases[0].description,
+            "Caso b"
+        );
+    }
+
+    #[test]
+    fn loads_drafts_saved_without_a_plan() {
+        let _dir = TempWorkingDir::new();
+        fs::create_dir_all(DRAFTS_DIR).unwrap();
+        fs::write(
+            format!("{}/a-20250311_112345.json", DRAFTS_DIR),
+            r#"{"source": "definitions/a.csv", "answered": 1, "execution": {"name": "a-20250311_112345", "test_cases": []}}"#,
+        )
+        .unwrap();
+
+        let drafts = get_drafts().unwrap();
+
+        assert_eq!(drafts[0].answered, 1);
+        assert!(drafts[0].plan.is_none());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::models::{Priority, TestCase};
+
+/// Filtro de casos de prueba por etiqueta, prioridad y componente
+///
+/// Los valores de un mismo criterio se combinan con «o» y los criterios entre
+/// sí con «y»: `--priority P0 --component checkout` selecciona los casos P0 del
+/// componente checkout. Un criterio vacío no filtra.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CaseFilter {
+    pub tags: Vec<String>,
+    pub priorities: Vec<Priority>,
+    pub components: Vec<String>,
+}
+
+impl CaseFilter {
+    /// Indica si el filtro no tiene ningún criterio
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.priorities.is_empty() && self.components.is_empty()
+    }
+
+    /// Indica si el caso cumple todos los criterios del filtro
+    pub fn matches(&self, test_case: &TestCase) -> bool {
+        let tag_matches = self.tags.is_empty() || self.tags.iter().any(|t| test_case.has_tag(t));
+
+        let priority_matches = self.priorities.is_empty()
+            || test_case
+                .priority
+                .is_some_and(|p| self.priorities.contains(&p));
+
+        let component_matches = self.components.is_empty()
+            || self
+                .components
+                .iter()
+                .any(|c| c.trim().eq_ignore_ascii_case(test_case.component.trim()));
+
+        tag_matches && priority_matches && component_matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(priority: Option<Priority>, component: &str, tags: &[&str]) -> TestCase {
+        let mut test_case = TestCase::new("Caso", "1.0", "");
+        test_case.priority = priority;
+        test_case.component = component.to_string();
+        test_case.tags = tags.iter().map(|t| t.to_string()).collect();
+        test_case
+    }
+
+    #[test]
+    fn empty_filter_matches_every_case() {
+        let filter = CaseFilter::default();
+
+        assert!(filter.is_empty());
+        assert!(filter.matches(&case(None, "", &[])));
+        assert!(filter.matches(&case(Some(Priority::P3), "checkout", &["smoke"])));
+    }
+
+    #[test]
+    fn matches_any_value_of_each_criterion() {
+        let by_tag = CaseFilter {
+            tags: vec![" Smoke ".to_string(), "regresion".to_string()],
+            ..CaseFilter::default()
+        };
+        assert!(!by_tag.is_empty());
+        assert!(by_tag.matches(&case(None, "", &["smoke"])));
+        assert!(by_tag.matches(&case(None, "", &["web", "REGRESION"])));
+        assert!(!by_tag.matches(&case(None, "", &["web"])));
+        assert!(!by_tag.matches(&case(None, "", &[])));
+
+        let by_priority = CaseFilter {
+            priorities: vec![Priority::P0, Priority::P1],
+            ..CaseFilter::default()
+        };
+        assert!(by_priority.matches(&case(Some(Priority::P0), "", &[])));
+        assert!(by_priority.matches(&case(Some(Priority::P1), "", &[])));
+        assert!(!by_priority.matches(&case(Some(Priority::P2), "", &[])));
+        // Los casos sin prioridad no cumplen un filtro de prioridad
+        assert!(!by_priority.matches(&case(None, "", &[])));
+
+        let by_component = CaseFilter {
+            components: vec!["Checkout".to_string(), "auth".to_string()],
+            ..CaseFilter::default()
+        };
+        assert!(by_component.matches(&case(None, " checkout ", &[])));
+        assert!(by_component.matches(&case(None, "AUTH", &[])));
+        assert!(!by_component.matches(&case(None, "catalogo", &[])));
+        assert!(!by_component.matches(&case(None, "", &[])));
+    }
+
+    #[test]
+    fn requires_every_criterion() {
+        let filter = CaseFilter {
+            tags: vec!["smoke".to_string()],
+            priorities: vec![Priority::P0],
+            components: vec!["checkout".to_string()],
+        };
+
+        assert!(filter.matches(&case(Some(Priority::P0), "checkout", &["smoke"])));
+        assert!(!filter.matches(&case(Some(Priority::P1), "checkout", &["smoke"])));
+        assert!(!filter.matches(&case(Some(Priority::P0), "auth", &["smoke"])));
+        assert!(!filter.matches(&case(Some(Priority::P0), "checkout", &["web"])));
+    }
+}
@@ -0,0 +1,360 @@
+pub mod draft;
+pub mod filter;
+pub mod plan;
+pub mod results;
+
+pub use draft::{get_drafts, Draft, PlanProgress, DRAFTS_DIR};
+pub use filter::CaseFilter;
+pub use plan::{PlanEntry, TestPlan};
+pub use results::{load_results, parse_set, result_from_json, results_from_json, CaseResult};
+
+use chrono::{Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+
+use crate::analysis::{flaky_cases, FlakyScore, History};
+use crate::config::Config;
+use crate::integrations::TicketCache;
+use crate::models::{TestCase, TestStatus};
+use crate::report::save_execution_markdown;
+use crate::storage::{Storage, DEFINITIONS_DIR, EXECUTIONS_DIR};
+
+/// Formato del timestamp con el que terminan los nombres de las ejecuciones
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// Ejecución de casos de prueba en curso, lista para guardarse junto a su informe en `executions/`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Execution {
+    /// Nombre de la ejecución: `<definición>-<timestamp>`
+    pub name: String,
+    pub test_cases: Vec<TestCase>,
+}
+
+impl Execution {
+    /// Crea una ejecución con nombre `<base_name>-<timestamp>`
+    ///
+    /// El nombre puede coincidir con el de otra ejecución iniciada en el mismo
+    /// segundo; [`Execution::ensure_unique_name`] lo evita.
+    pub fn new(base_name: &str, test_cases: Vec<TestCase>) -> Self {
+        let timestamp = Local::now().format(TIMESTAMP_FORMAT);
+
+        Execution {
+            name: format!("{}-{}", base_name, timestamp),
+            test_cases,
+        }
+    }
+
+    /// Prepara una ejecución a partir de una definición
+    pub fn from_definition(storage: &dyn Storage, definition: &str) -> io::Result<Self> {
+        let test_cases = storage.load(definition)?;
+
+        let mut execution = Execution::new(&file_stem(definition), test_cases);
+        execution.ensure_unique_name(storage)?;
+        Ok(execution)
+    }
+
+    /// Carga una ejecución guardada para seguir registrando resultados en ella
+    pub fn load(storage: &dyn Storage, execution: &str) -> io::Result<Self> {
+        Ok(Execution {
+            name: file_stem(execution),
+            test_cases: storage.load(execution)?,
+        })
+    }
+
+    /// Prepara una nueva ejecución a partir de una ejecución anterior
+    pub fn from_previous(storage: &dyn Storage, execution: &str) -> io::Result<Self> {
+        let test_cases = storage.load(execution)?;
+
+        let mut execution = Execution::new(&base_name_of(execution), test_cases);
+        execution.ensure_unique_name(storage)?;
+        Ok(execution)
+    }
+
+    /// Prepara una ejecución a partir de una definición o de una ejecución anterior,
+    /// según la referencia indicada
+    pub fn from_reference(storage: &dyn Storage, reference: &str) -> io::Result<Self> {
+        if is_definition(reference) {
+            Execution::from_definition(storage, reference)
+        } else {
+            Execution::from_previous(storage, reference)
+        }
+    }
+
+    /// Prepara una ejecución con los casos ya cargados de una definición o de
+    /// una ejecución anterior
+    pub fn from_loaded(
+        storage: &dyn Storage,
+        reference: &str,
+        test_cases: Vec<TestCase>,
+    ) -> io::Result<Self> {
+        let base_name = if is_definition(reference) {
+            file_stem(reference)
+        } else {
+            base_name_of(reference)
+        };
+
+        let mut execution = Execution::new(&base_name, test_cases);
+        execution.ensure_unique_name(storage)?;
+        Ok(execution)
+    }
+
+    /// Añade un número al timestamp (`-20250311_112345_2`) si ya hay una
+    /// ejecución o un informe con el mismo nombre, para no sobrescribirlos
+    pub fn ensure_unique_name(&mut self, storage: &dyn Storage) -> io::Result<()> {
+        self.ensure_unique_name_among(storage, &[])
+    }
+
+    /// Como [`Execution::ensure_unique_name`], evitando además los nombres de
+    /// otras ejecuciones que todavía no se han guardado
+    pub(crate) fn ensure_unique_name_among(
+        &mut self,
+        storage: &dyn Storage,
+        taken: &[Execution],
+    ) -> io::Result<()> {
+        let name = self.name.clone();
+        let mut number = 1;
+
+        while taken.iter().any(|other| other.name == self.name)
+            || storage.exists(&self.reference(storage))?
+            || Path::new(&self.md_path()).exists()
+        {
+            number += 1;
+            self.name = format!("{}_{}", name, number);
+        }
+
+        Ok(())
+    }
+
+    /// Referencia con la que se guarda la ejecución en el almacenamiento
+    pub fn reference(&self, storage: &dyn Storage) -> String {
+        storage.execution_ref(&self.name)
+    }
+
+    /// Ruta del informe Markdown de la ejecución
+    pub fn md_path(&self) -> String {
+        format!("{}/{}.md", EXECUTIONS_DIR, self.name)
+    }
+
+    /// Conserva solo los casos que cumplen el filtro
+    pub fn retain_matching(&mut self, filter: &CaseFilter) {
+        self.test_cases.retain(|tc| filter.matches(tc));
+    }
+
+    /// Busca un caso de prueba por su identificador
+    pub fn case_mut(&mut self, id: &str) -> io::Result<&mut TestCase> {
+        self.test_cases
+            .iter_mut()
+            .find(|tc| tc.id == id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No existe un caso de prueba con ID '{}'", id),
+                )
+            })
+    }
+
+    /// Registra el resultado de un caso de prueba
+    ///
+    /// Las observaciones y la evidencia solo se reemplazan si se indican.
+    pub fn record(
+        &mut self,
+        id: &str,
+        status: TestStatus,
+        observations: Option<&str>,
+        evidence: Option<&str>,
+    ) -> io::Result<()> {
+        let test_case = self.case_mut(id)?;
+
+        test_case.status = status;
+        if let Some(observations) = observations {
+            test_case.observations = observations.to_string();
+        }
+        if let Some(evidence) = evidence {
+            test_case.evidence = evidence.to_string();
+        }
+
+        Ok(())
+    }
+
+    /// Aplica una lista de resultados por ID de caso
+    ///
+    /// Si algún ID no existe en la ejecución no se aplica ningún resultado.
+    pub fn apply_results(&mut self, results: &[CaseResult]) -> io::Result<()> {
+        let unknown_ids: Vec<&str> = results
+            .iter()
+            .map(|r| r.id.as_str())
+            .filter(|id| !self.test_cases.iter().any(|tc| tc.id == *id))
+            .collect();
+
+        if !unknown_ids.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "IDs de caso de prueba desconocidos en {}: {}",
+                    self.name,
+                    unknown_ids.join(", ")
+                ),
+            ));
+        }
+
+        for result in results {
+            self.record(
+                &result.id,
+                result.status,
+                result.observations.as_deref(),
+                result.evidence.as_deref(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Guarda la ejecución en el almacenamiento y su informe en Markdown
+    ///
+    /// El informe incluye los casos inestables según el historial de la definición,
+    /// contando ya esta ejecución.
+    pub fn save(&self, storage: &dyn Storage) -> io::Result<()> {
+        storage.save(&self.reference(storage), &self.test_cases)?;
+
+        // El análisis es complementario: si falla, el informe se guarda sin él
+        let flaky = Config::load()
+            .and_then(|config| {
+                let history = History::load(storage, &base_name_of(&self.name))?;
+                Ok(flaky_cases(&history, &config.flaky))
+            })
+            .unwrap_or_default();
+
+        let tickets = TicketCache::load().unwrap_or_default();
+
+        self.save_report(&flaky, &tickets)
+    }
+
+    /// Guarda el informe Markdown de la ejecución con los casos inestables y
+    /// los tickets ya calculados, sin volver a leer la configuración ni el historial
+    pub fn save_report(&self, flaky: &[FlakyScore], tickets: &TicketCache) -> io::Result<()> {
+        save_execution_markdown(
+            &self.md_path(),
+            &self.test_cases,
+            &self.name,
+            flaky,
+            tickets,
+        )
+    }
+}
+
+/// Indica si la referencia corresponde a una definición
+pub fn is_definition(reference: &str) -> bool {
+    reference.starts_with(&format!("{}/", DEFINITIONS_DIR))
+}
+
+/// Nombre de archivo sin directorio ni extensión
+pub fn file_stem(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("test_cases")
+        .to_string()
+}
+
+/// Nombre base de una ejecución, sin el timestamp final
+///
+/// Si el nombre no termina en un timestamp de ejecución se devuelve completo:
+/// `login-form` es el nombre de una definición, no `login` con un sufijo.
+pub fn base_name_of(execution_path: &str) -> String {
+    let file_name = file_stem(execution_path);
+
+    match file_name.rsplit_once('-') {
+        Some((base_name, timestamp)) if parse_timestamp(timestamp).is_some() => {
+            base_name.to_string()
+        }
+        _ => file_name,
+    }
+}
+
+/// Fecha de una ejecución, tomada del timestamp final de su nombre
+pub fn executed_at(execution_path: &str) -> Option<NaiveDateTime> {
+    let file_name = file_stem(execution_path);
+    let (_base_name, timestamp) = file_name.rsplit_once('-')?;
+
+    parse_timestamp(timestamp)
+}
+
+/// Fecha del timestamp de un nombre de ejecución, con o sin el número que
+/// añade [`Execution::ensure_unique_name`]
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<NaiveDateTime> {
+    let date_time = match timestamp.rsplit_once('_') {
+        Some((date_time, number))
+            if date_time.contains('_') && number.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            date_time
+        }
+        _ => timestamp,
+    };
+
+    NaiveDateTime::parse_from_str(date_time, TIMESTAMP_FORMAT).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{ensure_directories, CsvStorage};
+    use crate::test_support::TempWorkingDir;
+
+    #[test]
+    fn executions_started_in_the_same_second_get_different_names() {
+        let _dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+        let storage = CsvStorage;
+        let definition = storage.definition_ref("smoke");
+        storage
+            .save(&definition, &[TestCase::new("Caso", "1.0", "")])
+            .unwrap();
+
+        let first = Execution::from_definition(&storage, &definition).unwrap();
+        first.save(&storage).unwrap();
+        let mut second = Execution::from_definition(&storage, &definition).unwrap();
+        // Mismo segundo que la primera, aunque el reloj haya avanzado
+        second.name = first.name.clone();
+        second.ensure_unique_name(&storage).unwrap();
+        second.save(&storage).unwrap();
+        let mut third = Execution::new("smoke", Vec::new());
+        third.name = first.name.clone();
+        third.ensure_unique_name(&storage).unwrap();
+
+        assert_eq!(second.name, format!("{}_2", first.name));
+        assert_eq!(third.name, format!("{}_3", first.name));
+        assert_eq!(storage.list_executions().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reads_names_with_a_sequence_number() {
+        let expected = NaiveDateTime::parse_from_str("20250311_112345", TIMESTAMP_FORMAT).ok();
+
+        assert_eq!(
+            executed_at("executions/smoke-login-20250311_112345.csv"),
+            expected
+        );
+        assert_eq!(
+            executed_at("executions/smoke-login-20250311_112345_2.csv"),
+            expected
+        );
+        assert_eq!(
+            base_name_of("executions/smoke-login-20250311_112345_2.csv"),
+            "smoke-login"
+        );
+        assert_eq!(executed_at("executions/smoke-login.csv"), None);
+    }
+
+    #[test]
+    fn strips_only_execution_timestamps_from_base_names() {
+        assert_eq!(
+            base_name_of("executions/login-form-20250311_112345.csv"),
+            "login-form"
+        );
+        assert_eq!(base_name_of("executions/login-form.csv"), "login-form");
+        assert_eq!(base_name_of("executions/login-v2.csv"), "login-v2");
+        assert_eq!(base_name_of("executions/login-2025.csv"), "login-2025");
+        assert_eq!(base_name_of("executions/smoke.csv"), "smoke");
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::engine::{file_stem, is_definition, Execution};
+use crate::storage::{Storage, PLANS_DIR};
+
+/// Plan de pruebas: varias definiciones que se ejecutan juntas en una sola sesión
+///
+/// Se guarda como TOML en `plans/<nombre>.toml`:
+///
+/// ```toml
+/// description = "Aprobación de la versión 1.2"
+///
+/// [[definitions]]
+/// definition = "smoke-login"
+///
+/// [[definitions]]
+/// definition = "regression-checkout"
+/// cases = ["a1b2c3d4", "e5f6a7b8"]
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestPlan {
+    /// Nombre del plan, tomado del nombre del archivo
+    #[serde(skip)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub definitions: Vec<PlanEntry>,
+}
+
+/// Definición incluida en un plan, con un filtro opcional de casos
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlanEntry {
+    /// Nombre base de la definición o su referencia
+    pub definition: String,
+    /// IDs de los casos a ejecutar; vacío para ejecutarlos todos
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cases: Vec<String>,
+}
+
+impl TestPlan {
+    /// Ruta del archivo de un plan a partir de su nombre
+    pub fn path_for(name: &str) -> String {
+        format!("{}/{}.toml", PLANS_DIR, name)
+    }
+
+    /// Carga un plan por su ruta o por su nombre
+    pub fn load(plan: &str) -> io::Result<Self> {
+        let file_path = if Path::new(plan).exists() {
+            plan.to_string()
+        } else {
+            TestPlan::path_for(plan)
+        };
+
+        if !Path::new(&file_path).exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("El plan {} no existe", file_path),
+            ));
+        }
+
+        let content = fs::read_to_string(&file_path)?;
+        let mut test_plan: TestPlan = toml::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Plan inválido en {}: {}", file_path, e),
+            )
+        })?;
+        test_plan.name = file_stem(&file_path);
+
+        Ok(test_plan)
+    }
+
+    /// Guarda el plan en `plans/<nombre>.toml`
+    pub fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(PLANS_DIR)?;
+        let content = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(TestPlan::path_for(&self.name), content)
+    }
+
+    /// Prepara una ejecución por cada definición del plan, aplicando los filtros de casos
+    ///
+    /// Falla si alguna definición no existe o si un filtro nombra casos que no están en ella.
+    pub fn executions(&self, storage: &dyn Storage) -> io::Result<Vec<Execution>> {
+        let mut executions = Vec::new();
+
+        for entry in &self.definitions {
+            let reference = entry.reference(storage);
+            if !storage.exists(&reference)? {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "La definición {} del plan {} no existe",
+                        reference, self.name
+                    ),
+                ));
+            }
+
+            // Una definición repetida en el plan se ejecuta con otro nombre
+            let mut execution = Execution::new(&file_stem(&reference), storage.load(&reference)?);
+            execution.ensure_unique_name_among(storage, &executions)?;
+
+            if !entry.cases.is_empty() {
+                let unknown_ids: Vec<&str> = entry
+                    .cases
+                    .iter()
+                    .map(|id| id.as_str())
+                    .filter(|id| !execution.test_cases.iter().any(|tc| tc.id == *id))
+                    .collect();
+
+                if !unknown_ids.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!(
+                            "IDs de caso de prueba desconocidos en {}: {}",
+                            reference,
+                            unknown_ids.join(", ")
+                        ),
+                    ));
+                }
+
+                execution
+                    .test_cases
+                    .retain(|tc| entry.cases.contains(&tc.id));
+            }
+
+            executions.push(execution);
+        }
+
+        Ok(executions)
+    }
+}
+
+impl PlanEntry {
+    /// Referencia de la definición en el almacenamiento
+    pub fn reference(&self, storage: &dyn Storage) -> String {
+        if is_definition(&self.definition) {
+            self.definition.clone()
+        } else {
+            storage.definition_ref(&self.definition)
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use csv::Reader;
+use serde::Deserialize;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use crate::models::TestStatus;
+
+/// Resultado de un caso de prueba indicado desde fuera de los menús interactivos
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaseResult {
+    pub id: String,
+    pub status: TestStatus,
+    pub observations: Option<String>,
+    pub evidence: Option<String>,
+}
+
+/// Fila de un archivo de resultados, antes de validar el estado
+#[derive(Debug, Deserialize)]
+struct RawCaseResult {
+    /// Opcional solo cuando el ID se indica aparte, como en [`result_from_json`]
+    #[serde(default)]
+    id: String,
+    status: String,
+    #[serde(default)]
+    observations: Option<String>,
+    #[serde(default)]
+    evidence: Option<String>,
+}
+
+impl RawCaseResult {
+    fn into_result(self) -> io::Result<CaseResult> {
+        let status = self.status.parse::<TestStatus>().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Caso '{}': {}", self.id, e),
+            )
+        })?;
+
+        Ok(CaseResult {
+            id: self.id,
+            status,
+            observations: self.observations,
+            evidence: self.evidence,
+        })
+    }
+}
+
+/// Carga resultados desde un archivo JSON o CSV
+///
+/// El JSON debe ser una lista de objetos y el CSV tener cabecera; en ambos casos
+/// se esperan los campos `id` y `status`, y opcionalmente `observations` y `evidence`.
+pub fn load_results(file_path: &str) -> io::Result<Vec<CaseResult>> {
+    let extension = Path::new(file_path)
+        .extension()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_lowercase());
+
+    let raw_results: Vec<RawCaseResult> = match extension.as_deref() {
+        Some("json") => {
+            let file = File::open(file_path)?;
+            serde_json::from_reader(file).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Error al leer {}: {}", file_path, e),
+                )
+            })?
+        }
+        Some("csv") => {
+            let mut reader = Reader::from_reader(File::open(file_path)?);
+            reader
+                .deserialize()
+                .collect::<Result<_, _>>()
+                .map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Error al leer {}: {}", file_path, e),
+                    )
+                })?
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Formato de resultados no soportado: {}. Use un archivo .json o .csv",
+                    file_path
+                ),
+            ))
+        }
+    };
+
+    raw_results
+        .into_iter()
+        .map(RawCaseResult::into_result)
+        .collect()
+}
+
+/// Interpreta una lista de resultados en JSON, con el mismo formato que los archivos `.json`
+pub fn results_from_json(json: &str) -> io::Result<Vec<CaseResult>> {
+    let raw_results: Vec<RawCaseResult> = serde_json::from_str(json).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Resultados inválidos: {}", e),
+        )
+    })?;
+
+    raw_results
+        .into_iter()
+        .map(RawCaseResult::into_result)
+        .collect()
+}
+
+/// Interpreta el resultado de un caso en JSON (`status` y opcionalmente
+/// `observations` y `evidence`) cuyo ID se conoce aparte
+pub fn result_from_json(id: &str, json: &str) -> io::Result<CaseResult> {
+    let mut raw_result: RawCaseResult = serde_json::from_str(json).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Resultado inválido: {}", e),
+        )
+    })?;
+    raw_result.id = id.to_string();
+
+    raw_result.into_result()
+}
+
+/// Interpreta un resultado con el formato `ID=estado[:observación]`
+pub fn parse_set(value: &str) -> io::Result<CaseResult> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Valor inválido '{}'. Use el formato ID=estado[:observación]",
+                value
+            ),
+        )
+    };
+
+    let (id, rest) = value.split_once('=').ok_or_else(invalid)?;
+    if id.trim().is_empty() {
+        return Err(invalid());
+    }
+
+    let (status, observations) = match rest.split_once(':') {
+        Some((status, observations)) => (status, Some(observations.to_string())),
+        None => (rest, None),
+    };
+
+    RawCaseResult {
+        id: id.trim().to_string(),
+        status: status.to_string(),
+        observations,
+        evidence: None,
+    }
+    .into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::Execution;
+    use crate::models::TestCase;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_set_values() {
+        assert_eq!(
+            parse_set(" TC-1 =Validated").unwrap(),
+            CaseResult {
+                id: "TC-1".to_string(),
+                status: TestStatus::Validated,
+                observations: None,
+                evidence: None,
+            }
+        );
+
+        // Solo el primer `:` separa la observación
+        let result = parse_set("TC-2=Rejected:Error: timeout").unwrap();
+        assert_eq!(result.status, TestStatus::Rejected);
+        assert_eq!(result.observations.as_deref(), Some("Error: timeout"));
+
+        for value in ["TC-1", "=Validated", "  =Validated"] {
+            let error = parse_set(value).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", value);
+        }
+        let error = parse_set("TC-1=Quizás").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().starts_with("Caso 'TC-1'"));
+    }
+
+    #[test]
+    fn loads_json_and_csv_results() {
+        let dir = TempDir::new().unwrap();
+        let json_path = dir.path().join("resultados.json");
+        fs::write(
+            &json_path,
+            r#"[{"id":"TC-1","status":"Validated"},
+                {"id":"TC-2","status":"Rejected","observations":"Falla","evidence":"captura.png"}]"#,
+        )
+        .unwrap();
+        let csv_path = dir.path().join("resultados.CSV");
+        fs::write(
+            &csv_path,
+            "id,status,observations\nTC-1,Validated,\nTC-2,Rejected,Falla\n",
+        )
+        .unwrap();
+
+        let from_json = load_results(json_path.to_str().unwrap()).unwrap();
+        assert_eq!(from_json.len(), 2);
+        assert_eq!(from_json[1].status, TestStatus::Rejected);
+        assert_eq!(from_json[1].evidence.as_deref(), Some("captura.png"));
+
+        let from_csv = load_results(csv_path.to_str().unwrap()).unwrap();
+        assert_eq!(from_csv.len(), 2);
+        assert_eq!(from_csv[0].id, "TC-1");
+        assert_eq!(from_csv[1].observations.as_deref(), Some("Falla"));
+    }
+
+    #[test]
+    fn rejects_invalid_result_files() {
+        let dir = TempDir::new().unwrap();
+        let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+
+        fs::write(path("estado.json"), r#"[{"id":"TC-1","status":"Quizás"}]"#).unwrap();
+        let error = load_results(&path("estado.json")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        fs::write(path("sin_estado.csv"), "id\nTC-1\n").unwrap();
+        let error = load_results(&path("sin_estado.csv")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        fs::write(path("resultados.txt"), "").unwrap();
+        let error = load_results(&path("resultados.txt")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn unknown_ids_apply_no_result() {
+        let mut test_case = TestCase::new("Caso", "", "");
+        test_case.id = "TC-1".to_string();
+        let mut execution = Execution::new("smoke", vec![test_case]);
+
+        let results = vec![
+            parse_set("TC-1=Validated").unwrap(),
+            parse_set("TC-9=Rejected").unwrap(),
+        ];
+        let error = execution.apply_results(&results).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().ends_with(": TC-9"));
+        assert_eq!(execution.test_cases[0].status, TestStatus::Pending);
+    }
+}
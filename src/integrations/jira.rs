@@ -0,0 +1,352 @@
+use reqwest::blocking::{Client, RequestBuilder};
+use serde_json::{json, Value};
+use std::io;
+use std::time::Duration;
+
+use crate::config::JiraConfig;
+use crate::models::TestCase;
+
+/// Tiempo máximo de espera de una petición a Jira
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Cliente de la API REST de Jira (v2) para crear incidencias
+pub struct JiraClient {
+    client: Client,
+    base_url: String,
+    project: String,
+    issue_type: String,
+    user: Option<String>,
+    token: Option<String>,
+}
+
+impl JiraClient {
+    /// Crea el cliente a partir de la sección `[jira]`, o `None` si no está configurada
+    ///
+    /// Las credenciales se leen de las variables de entorno indicadas en la
+    /// configuración. Con usuario y token se usa autenticación básica; solo con
+    /// token, un token personal (`Bearer`); sin ninguno, peticiones anónimas.
+    pub fn from_config(config: &JiraConfig) -> io::Result<Option<Self>> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+
+        let client = Client::builder()
+            .timeout(TIMEOUT)
+            .build()
+            .map_err(io::Error::other)?;
+
+        Ok(Some(JiraClient {
+            client,
+            base_url: config.base_url.trim().trim_end_matches('/').to_string(),
+            project: config.project.trim().to_string(),
+            issue_type: config.issue_type.clone(),
+            user: std::env::var(&config.user_env).ok(),
+            token: std::env::var(&config.token_env).ok(),
+        }))
+    }
+
+    /// Crea una incidencia para un caso rechazado y devuelve su clave
+    pub fn create_issue(&self, test_case: &TestCase) -> io::Result<String> {
+        let body = json!({
+            "fields": {
+                "project": { "key": self.project },
+                "issuetype": { "name": self.issue_type },
+                "summary": issue_summary(test_case),
+                "description": issue_description(test_case),
+            }
+        });
+
+        let url = format!("{}/rest/api/2/issue", self.base_url);
+        let response = self
+            .authorize(self.client.post(&url))
+            .json(&body)
+            .send()
+            .map_err(|e| io::Error::other(format!("Error al conectar con Jira: {}", e)))?;
+
+        let status = response.status();
+        let json: Value = response.json().unwrap_or(Value::Null);
+
+        if !status.is_success() {
+            return Err(io::Error::other(format!(
+                "Jira respondió {}: {}",
+                status,
+                error_message(&json)
+            )));
+        }
+
+        json.get("key")
+            .and_then(|k| k.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "La respuesta de Jira no incluye la clave de la incidencia",
+                )
+            })
+    }
+
+    /// URL de una incidencia en la interfaz web
+    pub fn browse_url(&self, issue_key: &str) -> String {
+        format!("{}/browse/{}", self.base_url, issue_key)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match (&self.user, &self.token) {
+            (Some(user), Some(token)) => request.basic_auth(user, Some(token)),
+            (None, Some(token)) => request.bearer_auth(token),
+            _ => request,
+        }
+    }
+}
+
+/// Longitud máxima del título de una incidencia que acepta Jira, en caracteres
+const MAX_SUMMARY_CHARS: usize = 255;
+
+/// Título de la incidencia de un caso
+///
+/// Jira rechaza títulos con saltos de línea o de más de 255 caracteres: la
+/// descripción se une en una sola línea y se recorta con `…`.
+pub fn issue_summary(test_case: &TestCase) -> String {
+    let summary = format!(
+        "[{}] {}",
+        test_case.id,
+        test_case
+            .description
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    if summary.chars().count() <= MAX_SUMMARY_CHARS {
+        return summary;
+    }
+
+    let mut truncated: String = summary.chars().take(MAX_SUMMARY_CHARS - 1).collect();
+    truncated.truncate(truncated.trim_end().len());
+    truncated.push('…');
+    truncated
+}
+
+/// Descripción de la incidencia de un caso, en el formato de texto de Jira
+pub fn issue_description(test_case: &TestCase) -> String {
+    let mut lines = vec![
+        format!("*Caso de prueba:* {}", test_case.id),
+        format!("*Descripción:* {}", test_case.description),
+    ];
+
+    let fields = [
+        ("Observaciones", &test_case.observations),
+        ("Evidencia", &test_case.evidence),
+        ("Versión", &test_case.version),
+        ("Tickets", &test_case.ticket_numbers),
+        ("Precondiciones", &test_case.preconditions),
+        ("Datos de prueba", &test_case.test_data),
+    ];
+    for (label, value) in fields {
+        if !value.is_empty() {
+            lines.push(format!("*{}:* {}", label, value));
+        }
+    }
+
+    if !test_case.steps.is_empty() {
+        lines.push(String::new());
+        lines.push("||#||Acción||Resultado esperado||Estado||".to_string());
+        for (i, step) in test_case.steps.iter().enumerate() {
+            lines.push(format!(
+                "|{}|{}|{}|{}|",
+                i + 1,
+                table_cell(&step.action),
+                table_cell(&step.expected),
+                step.status
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Texto de una celda de tabla de Jira
+///
+/// Las celdas vacías, las barras verticales y los saltos de línea rompen la
+/// tabla: se usa un espacio, `\|` y el salto forzado `\\`.
+fn table_cell(text: &str) -> String {
+    if text.trim().is_empty() {
+        return String::from(" ");
+    }
+
+    text.trim()
+        .replace('|', "\\|")
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" \\\\ ")
+}
+
+/// Mensaje de error de una respuesta de Jira
+fn error_message(json: &Value) -> String {
+    let mut messages: Vec<String> = json
+        .get("errorMessages")
+        .and_then(|m| m.as_array())
+        .map(|m| {
+            m.iter()
+                .filter_map(|m| m.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(errors) = json.get("errors").and_then(|e| e.as_object()) {
+        for (field, message) in errors {
+            messages.push(format!("{}: {}", field, message.as_str().unwrap_or("")));
+        }
+    }
+
+    if messages.is_empty() {
+        String::from("Error desconocido")
+    } else {
+        messages.join("; ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TestStep;
+    use crate::test_support::{MockResponse, MockServer};
+
+    fn client_for(server: &MockServer, user_env: &str, token_env: &str) -> JiraClient {
+        let config = JiraConfig {
+            base_url: format!("{}/", server.url),
+            project: " QA ".to_string(),
+            issue_type: "Bug".to_string(),
+            user_env: user_env.to_string(),
+            token_env: token_env.to_string(),
+        };
+        JiraClient::from_config(&config).unwrap().unwrap()
+    }
+
+    fn rejected_case() -> TestCase {
+        let mut test_case = TestCase::new("El login acepta credenciales válidas", "1.2.0", "QA-1");
+        test_case.id = "TC-1".to_string();
+        test_case.observations = "Devuelve 500".to_string();
+        test_case.steps = vec![TestStep::new("Enviar el formulario", "")];
+        test_case
+    }
+
+    #[test]
+    fn create_issue_posts_the_case_and_returns_the_key() {
+        std::env::set_var("TCM_TEST_JIRA_USER", "qa@example.com");
+        std::env::set_var("TCM_TEST_JIRA_TOKEN", "secreto");
+        let server = MockServer::start(vec![MockResponse::json(
+            201,
+            r#"{"id":"10001","key":"QA-7","self":"http://localhost/rest/api/2/issue/10001"}"#,
+        )]);
+        let client = client_for(&server, "TCM_TEST_JIRA_USER", "TCM_TEST_JIRA_TOKEN");
+
+        let key = client.create_issue(&rejected_case()).unwrap();
+
+        assert_eq!(key, "QA-7");
+        assert_eq!(
+            client.browse_url(&key),
+            format!("{}/browse/QA-7", server.url)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/rest/api/2/issue");
+        // Base64 de "qa@example.com:secreto"
+        assert_eq!(
+            request.header("Authorization"),
+            Some("Basic cWFAZXhhbXBsZS5jb206c2VjcmV0bw==")
+        );
+
+        let body = request.json();
+        assert_eq!(body["fields"]["project"]["key"], "QA");
+        assert_eq!(body["fields"]["issuetype"]["name"], "Bug");
+        assert_eq!(
+            body["fields"]["summary"],
+            "[TC-1] El login acepta credenciales válidas"
+        );
+        let description = body["fields"]["description"].as_str().unwrap();
+        assert!(description.contains("*Observaciones:* Devuelve 500"));
+        assert!(description.contains("|1|Enviar el formulario| |"));
+    }
+
+    #[test]
+    fn summaries_are_single_line_and_short_enough_for_jira() {
+        let mut test_case = rejected_case();
+        test_case.description = "Pulsar  Guardar\ny luego\r\n  Salir".to_string();
+        assert_eq!(
+            issue_summary(&test_case),
+            "[TC-1] Pulsar Guardar y luego Salir"
+        );
+
+        test_case.description = "á".repeat(300);
+        let summary = issue_summary(&test_case);
+        assert_eq!(summary.chars().count(), MAX_SUMMARY_CHARS);
+        assert!(summary.starts_with("[TC-1] ááá"));
+        assert!(summary.ends_with("á…"));
+    }
+
+    #[test]
+    fn step_cells_do_not_break_the_jira_table() {
+        let mut test_case = rejected_case();
+        test_case.steps = vec![
+            TestStep::new("Elegir A | B", "Se muestra\nel resumen"),
+            TestStep::new("Pulsar ||", "  "),
+        ];
+
+        let description = issue_description(&test_case);
+        let rows: Vec<&str> = description.lines().filter(|l| l.starts_with('|')).collect();
+
+        assert_eq!(
+            rows,
+            vec![
+                "||#||Acción||Resultado esperado||Estado||",
+                "|1|Elegir A \\| B|Se muestra \\\\ el resumen|⏳ Pendiente|",
+                "|2|Pulsar \\|\\|| |⏳ Pendiente|",
+            ]
+        );
+    }
+
+    #[test]
+    fn create_issue_uses_a_bearer_token_without_user() {
+        std::env::set_var("TCM_TEST_JIRA_PAT", "pat-123");
+        let server = MockServer::start(vec![MockResponse::json(201, r#"{"key":"QA-8"}"#)]);
+        let client = client_for(&server, "TCM_TEST_JIRA_NO_USER", "TCM_TEST_JIRA_PAT");
+
+        assert_eq!(client.create_issue(&rejected_case()).unwrap(), "QA-8");
+        assert_eq!(
+            server.requests()[0].header("Authorization"),
+            Some("Bearer pat-123")
+        );
+    }
+
+    #[test]
+    fn create_issue_reports_jira_error_messages() {
+        let server = MockServer::start(vec![MockResponse::json(
+            400,
+            r#"{"errorMessages":["Proyecto inexistente"],"errors":{"issuetype":"Tipo no válido"}}"#,
+        )]);
+        let client = client_for(&server, "TCM_TEST_JIRA_NONE", "TCM_TEST_JIRA_NONE");
+
+        let error = client.create_issue(&rejected_case()).unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.starts_with("Jira respondió 400"), "{}", message);
+        assert!(message.contains("Proyecto inexistente; issuetype: Tipo no válido"));
+        assert_eq!(server.requests()[0].header("Authorization"), None);
+    }
+
+    #[test]
+    fn create_issue_fails_when_the_response_has_no_key() {
+        let server = MockServer::start(vec![MockResponse::json(201, r#"{"id":"10001"}"#)]);
+        let client = client_for(&server, "TCM_TEST_JIRA_NONE", "TCM_TEST_JIRA_NONE");
+
+        let error = client.create_issue(&rejected_case()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        server.requests();
+    }
+}
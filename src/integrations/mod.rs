@@ -0,0 +1,5 @@
+pub mod jira;
+pub mod tracker;
+
+pub use jira::*;
+pub use tracker::*;
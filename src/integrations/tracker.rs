@@ -0,0 +1,515 @@
+use chrono::Local;
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::config::{Config, TrackerKind};
+use crate::models::{TestCase, TestStatus};
+
+/// Archivo donde se guarda el estado de los tickets tras `tickets sync`
+pub const TICKETS_FILE: &str = "tickets.json";
+
+/// Tiempo máximo de espera de una petición al gestor de incidencias
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Estado de un ticket en el gestor de incidencias
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TicketInfo {
+    pub key: String,
+    pub title: String,
+    /// Estado tal como lo devuelve el gestor, como `open` o `Done`
+    pub state: String,
+    pub closed: bool,
+    pub synced_at: String,
+}
+
+impl TicketInfo {
+    /// Estado con un icono que distingue abiertos y cerrados
+    pub fn status_label(&self) -> String {
+        let icon = if self.closed { "🔒" } else { "🔓" };
+        format!("{} {}", icon, self.state)
+    }
+}
+
+/// Estado de los tickets de la última sincronización, guardado en [`TICKETS_FILE`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketCache {
+    pub tickets: BTreeMap<String, TicketInfo>,
+}
+
+impl TicketCache {
+    /// Carga los tickets sincronizados del directorio de trabajo
+    pub fn load() -> io::Result<Self> {
+        TicketCache::load_from(TICKETS_FILE)
+    }
+
+    /// Carga los tickets de un archivo, o una lista vacía si no existe
+    pub fn load_from(file_path: &str) -> io::Result<Self> {
+        if !Path::new(file_path).exists() {
+            return Ok(TicketCache::default());
+        }
+
+        let content = fs::read_to_string(file_path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Archivo de tickets inválido {}: {}", file_path, e),
+            )
+        })
+    }
+
+    /// Guarda los tickets en el directorio de trabajo
+    pub fn save(&self) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(TICKETS_FILE, content)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tickets.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&TicketInfo> {
+        self.tickets.get(key)
+    }
+
+    pub fn insert(&mut self, ticket: TicketInfo) {
+        self.tickets.insert(ticket.key.clone(), ticket);
+    }
+
+    /// Tickets sincronizados de un caso, en el orden de `ticket_numbers`
+    pub fn tickets_of(&self, test_case: &TestCase) -> Vec<&TicketInfo> {
+        test_case
+            .tickets()
+            .iter()
+            .filter_map(|key| self.get(key))
+            .collect()
+    }
+
+    /// Tickets cerrados de un caso bloqueado, que indican que se puede volver a ejecutar
+    pub fn closed_blockers(&self, test_case: &TestCase) -> Vec<&TicketInfo> {
+        if test_case.status != TestStatus::Blocked {
+            return Vec::new();
+        }
+
+        self.tickets_of(test_case)
+            .into_iter()
+            .filter(|t| t.closed)
+            .collect()
+    }
+}
+
+/// Autenticación de las peticiones al gestor
+enum Auth {
+    None,
+    Basic(String, String),
+    Bearer(String),
+    /// Cabecera propia, como `PRIVATE-TOKEN` en GitLab
+    Header(&'static str, String),
+}
+
+/// Cliente de GitHub Issues, GitLab o Jira para consultar el estado de los tickets
+pub struct TrackerClient {
+    client: Client,
+    kind: TrackerKind,
+    base_url: String,
+    repository: String,
+    auth: Auth,
+}
+
+impl TrackerClient {
+    /// Crea el cliente a partir de la sección `[tracker]`
+    pub fn from_config(config: &Config) -> io::Result<Self> {
+        let tracker = &config.tracker;
+        let kind = tracker.kind.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No hay gestor de incidencias configurado. Añada la sección [tracker] con kind = \"github\", \"gitlab\" o \"jira\" a test_case_manager.toml",
+            )
+        })?;
+
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let token_env = |default: &str| {
+            if tracker.token_env.is_empty() {
+                default.to_string()
+            } else {
+                tracker.token_env.clone()
+            }
+        };
+
+        let (base_url, auth) = match kind {
+            TrackerKind::Github => (
+                or_default(&tracker.base_url, "https://api.github.com"),
+                env(&token_env("GITHUB_TOKEN")).map_or(Auth::None, Auth::Bearer),
+            ),
+            TrackerKind::Gitlab => (
+                or_default(&tracker.base_url, "https://gitlab.com"),
+                env(&token_env("GITLAB_TOKEN"))
+                    .map_or(Auth::None, |token| Auth::Header("PRIVATE-TOKEN", token)),
+            ),
+            TrackerKind::Jira => {
+                let jira = &config.jira;
+                let auth = match (env(&jira.user_env), env(&jira.token_env)) {
+                    (Some(user), Some(token)) => Auth::Basic(user, token),
+                    (None, Some(token)) => Auth::Bearer(token),
+                    _ => Auth::None,
+                };
+                (or_default(&tracker.base_url, &jira.base_url), auth)
+            }
+        };
+
+        if base_url.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Falta la URL de Jira: indique base_url en [tracker] o en [jira]",
+            ));
+        }
+        if kind != TrackerKind::Jira && tracker.repository.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Falta el repositorio en [tracker]: repository = \"dueño/repo\"",
+            ));
+        }
+
+        let client = Client::builder()
+            .timeout(TIMEOUT)
+            // GitHub rechaza las peticiones sin User-Agent
+            .user_agent("test_case_manager")
+            .build()
+            .map_err(io::Error::other)?;
+
+        Ok(TrackerClient {
+            client,
+            kind,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            repository: tracker.repository.trim().to_string(),
+            auth,
+        })
+    }
+
+    /// Consulta el título y el estado de un ticket
+    ///
+    /// En GitHub y GitLab el ticket es un número de issue del repositorio
+    /// configurado, o `repositorio#número` para otro repositorio.
+    pub fn fetch(&self, key: &str) -> io::Result<TicketInfo> {
+        let url = match self.kind {
+            TrackerKind::Github => {
+                let (repository, number) = self.issue_number(key)?;
+                format!("{}/repos/{}/issues/{}", self.base_url, repository, number)
+            }
+            TrackerKind::Gitlab => {
+                let (project, number) = self.issue_number(key)?;
+                format!(
+                    "{}/api/v4/projects/{}/issues/{}",
+                    self.base_url,
+                    project.replace('/', "%2F"),
+                    number
+                )
+            }
+            TrackerKind::Jira => format!(
+                "{}/rest/api/2/issue/{}?fields=summary,status",
+                self.base_url,
+                jira_key(key)?
+            ),
+        };
+
+        let response = self
+            .authorize(self.client.get(&url))
+            .send()
+            .map_err(|e| io::Error::other(format!("Error al conectar con {}: {}", url, e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("El ticket {} no existe", key),
+            ));
+        }
+        if !status.is_success() {
+            return Err(io::Error::other(format!(
+                "El gestor respondió {} para el ticket {}",
+                status, key
+            )));
+        }
+
+        let json: Value = response.json().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Respuesta inválida para el ticket {}: {}", key, e),
+            )
+        })?;
+
+        Ok(self.ticket_from_json(key, &json))
+    }
+
+    /// Repositorio y número de issue de un ticket de GitHub o GitLab
+    fn issue_number<'a>(&'a self, key: &'a str) -> io::Result<(&'a str, u64)> {
+        let (repository, number) = key
+            .rsplit_once('#')
+            .unwrap_or((self.repository.as_str(), key));
+
+        let number = number.parse::<u64>().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{}' no es un número de issue", key),
+            )
+        })?;
+
+        // El repositorio va en la ruta de la URL: solo se aceptan segmentos de nombre
+        let valid_repository = repository.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        });
+        if !valid_repository {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "'{}' no es un repositorio válido en el ticket {}",
+                    repository, key
+                ),
+            ));
+        }
+
+        Ok((repository, number))
+    }
+
+    fn ticket_from_json(&self, key: &str, json: &Value) -> TicketInfo {
+        let text = |value: Option<&Value>| {
+            value
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let (title, state, closed) = match self.kind {
+            TrackerKind::Github | TrackerKind::Gitlab => {
+                let state = text(json.get("state"));
+                let closed = state == "closed";
+                (text(json.get("title")), state, closed)
+            }
+            TrackerKind::Jira => {
+                let fields = json.get("fields");
+                let status = fields.and_then(|f| f.get("status"));
+                // La categoría "done" agrupa los estados finales de cualquier flujo
+                let closed = status
+                    .and_then(|s| s.pointer("/statusCategory/key"))
+                    .and_then(|k| k.as_str())
+                    == Some("done");
+                (
+                    text(fields.and_then(|f| f.get("summary"))),
+                    text(status.and_then(|s| s.get("name"))),
+                    closed,
+                )
+            }
+        };
+
+        TicketInfo {
+            key: key.to_string(),
+            title,
+            state,
+            closed,
+            synced_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.auth {
+            Auth::None => request,
+            Auth::Basic(user, token) => request.basic_auth(user, Some(token)),
+            Auth::Bearer(token) => request.bearer_auth(token),
+            Auth::Header(name, token) => request.header(*name, token),
+        }
+    }
+}
+
+/// Valida una clave de incidencia de Jira (`PROYECTO-123`) antes de usarla en la URL
+///
+/// La clave del proyecto empieza por una letra mayúscula y sigue con mayúsculas,
+/// dígitos o `_`, como las que admite Jira.
+fn jira_key(key: &str) -> io::Result<&str> {
+    let valid = key.split_once('-').is_some_and(|(project, number)| {
+        let mut chars = project.chars();
+        chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && project.len() >= 2
+            && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+    });
+
+    if valid {
+        Ok(key)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{}' no es una clave de Jira (PROYECTO-123)", key),
+        ))
+    }
+}
+
+fn or_default(value: &str, default: &str) -> String {
+    if value.trim().is_empty() {
+        default.trim().to_string()
+    } else {
+        value.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TrackerConfig;
+    use crate::test_support::{MockResponse, MockServer};
+
+    fn client(kind: TrackerKind, server: &MockServer, token_env: &str) -> TrackerClient {
+        let mut config = Config {
+            tracker: TrackerConfig {
+                kind: Some(kind),
+                base_url: format!("{}/", server.url),
+                repository: "acme/web".to_string(),
+                token_env: token_env.to_string(),
+            },
+            ..Config::default()
+        };
+        config.jira.user_env = "TCM_TEST_TRACKER_NO_USER".to_string();
+        config.jira.token_env = token_env.to_string();
+        TrackerClient::from_config(&config).unwrap()
+    }
+
+    #[test]
+    fn fetches_github_issues() {
+        std::env::set_var("TCM_TEST_GITHUB_TOKEN", "gh-123");
+        let server = MockServer::start(vec![
+            MockResponse::json(200, r#"{"title":"Falla el login","state":"closed"}"#),
+            MockResponse::json(200, r#"{"title":"Otro repo","state":"open"}"#),
+        ]);
+        let client = client(TrackerKind::Github, &server, "TCM_TEST_GITHUB_TOKEN");
+
+        let closed = client.fetch("34").unwrap();
+        let open = client.fetch("acme/api#7").unwrap();
+
+        assert_eq!(closed.key, "34");
+        assert_eq!(closed.title, "Falla el login");
+        assert!(closed.closed);
+        assert_eq!(closed.status_label(), "🔒 closed");
+        assert_eq!((open.state.as_str(), open.closed), ("open", false));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/repos/acme/web/issues/34");
+        assert_eq!(requests[0].header("Authorization"), Some("Bearer gh-123"));
+        assert_eq!(requests[0].header("User-Agent"), Some("test_case_manager"));
+        assert_eq!(requests[1].url, "/repos/acme/api/issues/7");
+    }
+
+    #[test]
+    fn fetches_gitlab_issues() {
+        std::env::set_var("TCM_TEST_GITLAB_TOKEN", "gl-123");
+        let server = MockServer::start(vec![MockResponse::json(
+            200,
+            r#"{"title":"Error al pagar","state":"opened"}"#,
+        )]);
+        let client = client(TrackerKind::Gitlab, &server, "TCM_TEST_GITLAB_TOKEN");
+
+        let ticket = client.fetch("grupo/sub/tienda#12").unwrap();
+
+        assert_eq!(ticket.title, "Error al pagar");
+        assert_eq!((ticket.state.as_str(), ticket.closed), ("opened", false));
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url,
+            "/api/v4/projects/grupo%2Fsub%2Ftienda/issues/12"
+        );
+        assert_eq!(requests[0].header("PRIVATE-TOKEN"), Some("gl-123"));
+    }
+
+    #[test]
+    fn fetches_jira_issues() {
+        std::env::set_var("TCM_TEST_JIRA_TRACKER_TOKEN", "pat-123");
+        let server = MockServer::start(vec![
+            MockResponse::json(
+                200,
+                r#"{"key":"QA-12","fields":{"summary":"Botón roto",
+                    "status":{"name":"Hecho","statusCategory":{"key":"done"}}}}"#,
+            ),
+            MockResponse::json(
+                200,
+                r#"{"fields":{"summary":"En curso",
+                    "status":{"name":"In Progress","statusCategory":{"key":"indeterminate"}}}}"#,
+            ),
+        ]);
+        let client = client(TrackerKind::Jira, &server, "TCM_TEST_JIRA_TRACKER_TOKEN");
+
+        let done = client.fetch("QA-12").unwrap();
+        let in_progress = client.fetch("WEB_2-7").unwrap();
+
+        assert_eq!(done.title, "Botón roto");
+        assert_eq!((done.state.as_str(), done.closed), ("Hecho", true));
+        assert_eq!(
+            (in_progress.state.as_str(), in_progress.closed),
+            ("In Progress", false)
+        );
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url,
+            "/rest/api/2/issue/QA-12?fields=summary,status"
+        );
+        assert_eq!(requests[0].header("Authorization"), Some("Bearer pat-123"));
+    }
+
+    #[test]
+    fn reports_missing_tickets_and_server_errors() {
+        let server = MockServer::start(vec![
+            MockResponse::json(404, r#"{"message":"Not Found"}"#),
+            MockResponse::json(500, "{}"),
+            MockResponse::json(200, "no es JSON"),
+        ]);
+        let client = client(TrackerKind::Github, &server, "TCM_TEST_TRACKER_NO_TOKEN");
+
+        let missing = client.fetch("1").unwrap_err();
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+        assert_eq!(missing.to_string(), "El ticket 1 no existe");
+
+        let failed = client.fetch("2").unwrap_err();
+        assert!(failed.to_string().starts_with("El gestor respondió 500"));
+
+        let invalid = client.fetch("3").unwrap_err();
+        assert_eq!(invalid.kind(), io::ErrorKind::InvalidData);
+
+        assert_eq!(server.requests()[0].header("Authorization"), None);
+    }
+
+    #[test]
+    fn rejects_keys_that_would_change_the_url() {
+        let server = MockServer::start(Vec::new());
+        let jira = client(TrackerKind::Jira, &server, "TCM_TEST_TRACKER_NO_TOKEN");
+        let github = client(TrackerKind::Github, &server, "TCM_TEST_TRACKER_NO_TOKEN");
+
+        for key in [
+            "QA-12?fields=*all",
+            "QA-12/../../myself",
+            "qa-12",
+            "Q-12",
+            "QA-",
+            "QA-1a",
+            "12",
+            "QA 12",
+        ] {
+            let error = jira.fetch(key).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", key);
+        }
+        for key in ["abc", "../admin#1", "acme/web?x=1#2", "acme//web#3"] {
+            let error = github.fetch(key).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", key);
+        }
+
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+//! Gestión de casos de prueba para pruebas de regresión, humo y funcionales.
+//!
+//! La biblioteca expone los mismos componentes que usa la herramienta de línea
+//! de comandos, para poder crear definiciones y registrar ejecuciones sin pasar
+//! por los menús interactivos:
+//!
+//! - [`models`]: casos de prueba y sus estados.
+//! - [`storage`]: almacenamiento de definiciones y ejecuciones en CSV o SQLite.
+//! - [`report`]: informes de ejecución, archivos Gherkin `.feature` y CSV de TestRail y Xray.
+//! - [`engine`]: preparación, registro de resultados y guardado de ejecuciones.
+//! - [`analysis`]: comparación, historial y casos inestables de las ejecuciones.
+//! - [`commands`]: flujos interactivos usados por la CLI.
+//! - [`tui`]: ejecución de casos a pantalla completa.
+//! - [`server`]: API HTTP/JSON local.
+//! - [`integrations`]: incidencias en Jira y estado de los tickets.
+//! - [`config`]: configuración leída de `test_case_manager.toml`.
+//!
+//! ```no_run
+//! use test_case_manager::engine::Execution;
+//! use test_case_manager::models::{TestCase, TestStatus};
+//! use test_case_manager::storage::{ensure_directories, open_storage};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! ensure_directories()?;
+//! let storage = open_storage()?;
+//!
+//! let case = TestCase::new("El login acepta credenciales válidas", "1.2.0", "QA-12");
+//! let id = case.id.clone();
+//! let definition = storage.definition_ref("smoke-login");
+//! storage.save(&definition, &[case])?;
+//!
+//! let mut execution = Execution::from_definition(storage.as_ref(), &definition)?;
+//! execution.record(&id, TestStatus::Validated, Some("Sin incidencias"), None)?;
+//! execution.save(storage.as_ref())?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod analysis;
+pub mod commands;
+pub mod config;
+pub mod engine;
+pub mod integrations;
+pub mod models;
+pub mod report;
+pub mod server;
+pub mod storage;
+pub mod tui;
+pub mod utils;
+
+#[cfg(test)]
+mod test_support;
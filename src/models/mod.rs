@@ -1,5 +1,9 @@
+pub mod priority;
 pub mod test_case;
 pub mod test_status;
+pub mod test_step;
 
-pub use test_case::TestCase;
+pub use priority::Priority;
+pub use test_case::{generate_id, parse_tags, parse_tickets, TestCase};
 pub use test_status::TestStatus;
+pub use test_step::{suggested_status, TestStep};
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Prioridad de un caso de prueba, de P0 (crítica) a P3 (baja)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    P0,
+    P1,
+    P2,
+    P3,
+}
+
+impl Priority {
+    /// Todas las prioridades, de mayor a menor
+    pub const ALL: [Priority; 4] = [Priority::P0, Priority::P1, Priority::P2, Priority::P3];
+
+    /// Descripción de la prioridad para los menús
+    pub fn label(&self) -> &'static str {
+        match self {
+            Priority::P0 => "P0 - Crítica",
+            Priority::P1 => "P1 - Alta",
+            Priority::P2 => "P2 - Media",
+            Priority::P3 => "P3 - Baja",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    /// Acepta `P0`…`P3` sin distinguir mayúsculas, o solo el número
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_uppercase();
+        let digit = value.strip_prefix('P').unwrap_or(&value);
+
+        match digit {
+            "0" => Ok(Priority::P0),
+            "1" => Ok(Priority::P1),
+            "2" => Ok(Priority::P2),
+            "3" => Ok(Priority::P3),
+            _ => Err(format!("Prioridad inválida '{}'. Use P0, P1, P2 o P3", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_names_and_digits() {
+        for (text, expected) in [
+            ("P0", Priority::P0),
+            ("p1", Priority::P1),
+            (" P2 ", Priority::P2),
+            ("3", Priority::P3),
+        ] {
+            assert_eq!(text.parse::<Priority>(), Ok(expected), "{}", text);
+        }
+        for priority in Priority::ALL {
+            assert_eq!(priority.to_string().parse::<Priority>(), Ok(priority));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_priorities() {
+        for text in ["", "P", "P4", "PP1", "-1", "P0-P1", "alta"] {
+            let error = text.parse::<Priority>().unwrap_err();
+            assert_eq!(
+                error,
+                format!("Prioridad inválida '{}'. Use P0, P1, P2 o P3", text)
+            );
+        }
+    }
+
+    #[test]
+    fn orders_from_critical_to_low() {
+        assert!(Priority::P0 < Priority::P1);
+        let mut priorities = vec![Priority::P3, Priority::P0, Priority::P2, Priority::P1];
+        priorities.sort();
+        assert_eq!(priorities, Priority::ALL);
+    }
+}
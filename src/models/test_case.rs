@@ -1,6 +1,10 @@
-use serde::{Deserialize, Serialize};
-use crate::models::TestStatus;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
 
+use crate::models::test_step::steps_column;
+use crate::models::{Priority, TestStatus, TestStep};
+
+/// Caso de prueba tal como se guarda en los archivos CSV de definición y ejecución
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TestCase {
     pub id: String,
@@ -10,4 +14,122 @@ pub struct TestCase {
     pub evidence: String,
     pub version: String,
     pub ticket_numbers: String,
+    #[serde(default)]
+    pub preconditions: String,
+    #[serde(default)]
+    pub test_data: String,
+    /// Pasos ordenados; en el CSV se guardan como JSON en la columna `steps`
+    #[serde(default, with = "steps_column")]
+    pub steps: Vec<TestStep>,
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    /// Componente o área funcional, como `checkout`
+    #[serde(default)]
+    pub component: String,
+    /// Etiquetas libres; en el CSV se guardan separadas por comas en la columna `tags`
+    #[serde(default, with = "tags_column")]
+    pub tags: Vec<String>,
+    /// Clave de la incidencia abierta para el caso, como `QA-123`
+    #[serde(default)]
+    pub issue_key: String,
+}
+
+impl TestCase {
+    /// Crea un caso de prueba pendiente con un identificador generado
+    pub fn new(description: &str, version: &str, ticket_numbers: &str) -> Self {
+        TestCase {
+            id: generate_id(),
+            description: description.to_string(),
+            status: TestStatus::Pending,
+            observations: String::new(),
+            evidence: String::new(),
+            version: version.to_string(),
+            ticket_numbers: ticket_numbers.to_string(),
+            preconditions: String::new(),
+            test_data: String::new(),
+            steps: Vec::new(),
+            priority: None,
+            component: String::new(),
+            tags: Vec::new(),
+            issue_key: String::new(),
+        }
+    }
+
+    /// Tickets de `ticket_numbers`, sin repetir
+    pub fn tickets(&self) -> Vec<String> {
+        parse_tickets(&self.ticket_numbers)
+    }
+
+    /// Indica si el caso tiene la etiqueta, sin distinguir mayúsculas
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag.trim()))
+    }
+}
+
+/// Separa una lista de etiquetas escrita como `a, b, c`, descartando las vacías
+pub fn parse_tags(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Separa un texto libre de tickets como `QA-12, #34; QA-56` en una lista
+///
+/// Se aceptan comas, puntos y coma y espacios como separadores, y se quita el
+/// `#` inicial de los números de issue.
+pub fn parse_tickets(text: &str) -> Vec<String> {
+    let mut tickets: Vec<String> = Vec::new();
+
+    for ticket in text.split(|c: char| c == ',' || c == ';' || c.is_whitespace()) {
+        let ticket = ticket.trim().trim_start_matches('#');
+        if !ticket.is_empty() && !tickets.iter().any(|t| t == ticket) {
+            tickets.push(ticket.to_string());
+        }
+    }
+
+    tickets
+}
+
+/// Genera un identificador corto para un caso de prueba
+pub fn generate_id() -> String {
+    Uuid::new_v4()
+        .to_string()
+        .split('-')
+        .next()
+        .unwrap_or("TC")
+        .to_string()
+}
+
+/// Serialización de las etiquetas como texto separado por comas
+pub mod tags_column {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tags: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&tags.join(", "))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        Ok(parse_tags(&String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ticket_lists() {
+        assert_eq!(
+            parse_tickets("QA-12, #34; QA-56  QA-12\n#7"),
+            vec!["QA-12", "34", "QA-56", "7"]
+        );
+        assert_eq!(parse_tickets("acme/api#8"), vec!["acme/api#8"]);
+        assert!(parse_tickets(" , ; # ").is_empty());
+
+        let test_case = TestCase::new("Caso", "", "QA-1;QA-2");
+        assert_eq!(test_case.tickets(), vec!["QA-1", "QA-2"]);
+    }
 }
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+/// Resultado de la ejecución de un caso de prueba
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TestStatus {
     Pending,
     Validated,
@@ -10,6 +12,33 @@ pub enum TestStatus {
     Blocked,
 }
 
+impl TestStatus {
+    /// Todos los estados, en el orden en que se ofrecen al usuario
+    pub const ALL: [TestStatus; 5] = [
+        TestStatus::Pending,
+        TestStatus::Validated,
+        TestStatus::Rejected,
+        TestStatus::Skipped,
+        TestStatus::Blocked,
+    ];
+
+    /// Indica si el estado cuenta como fallo (rechazado o bloqueado)
+    pub fn is_failure(&self) -> bool {
+        matches!(self, TestStatus::Rejected | TestStatus::Blocked)
+    }
+
+    /// Emoji que representa el estado
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            TestStatus::Pending => "⏳",
+            TestStatus::Validated => "✅",
+            TestStatus::Rejected => "❌",
+            TestStatus::Skipped => "⏭️",
+            TestStatus::Blocked => "🚫",
+        }
+    }
+}
+
 impl fmt::Display for TestStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -21,3 +50,28 @@ impl fmt::Display for TestStatus {
         }
     }
 }
+
+impl FromStr for TestStatus {
+    type Err = String;
+
+    /// Acepta el nombre del estado en inglés o en español, con o sin emoji
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .trim()
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .trim()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "pending" | "pendiente" => Ok(TestStatus::Pending),
+            "validated" | "validado" => Ok(TestStatus::Validated),
+            "rejected" | "rechazado" => Ok(TestStatus::Rejected),
+            "skipped" | "omitido" => Ok(TestStatus::Skipped),
+            "blocked" | "bloqueado" => Ok(TestStatus::Blocked),
+            _ => Err(format!(
+                "Estado inválido '{}'. Use Pending, Validated, Rejected, Skipped o Blocked",
+                s
+            )),
+        }
+    }
+}
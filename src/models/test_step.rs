@@ -0,0 +1,154 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::models::TestStatus;
+
+/// Paso de un caso de prueba, con su acción y el resultado esperado
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TestStep {
+    pub action: String,
+    pub expected: String,
+    #[serde(default = "pending")]
+    pub status: TestStatus,
+}
+
+fn pending() -> TestStatus {
+    TestStatus::Pending
+}
+
+impl TestStep {
+    /// Crea un paso pendiente
+    pub fn new(action: &str, expected: &str) -> Self {
+        TestStep {
+            action: action.to_string(),
+            expected: expected.to_string(),
+            status: TestStatus::Pending,
+        }
+    }
+}
+
+/// Estado sugerido para un caso a partir del resultado de sus pasos
+///
+/// Un paso rechazado o bloqueado determina el estado del caso; si todos los
+/// pasos están validados, el caso también. En otro caso no hay sugerencia.
+pub fn suggested_status(steps: &[TestStep]) -> Option<TestStatus> {
+    if steps.is_empty() {
+        return None;
+    }
+
+    for status in [TestStatus::Rejected, TestStatus::Blocked] {
+        if steps.iter().any(|s| s.status == status) {
+            return Some(status);
+        }
+    }
+
+    if steps.iter().all(|s| s.status == TestStatus::Validated) {
+        return Some(TestStatus::Validated);
+    }
+
+    None
+}
+
+/// Serialización de la lista de pasos como una única celda JSON, para poder
+/// guardarla en una columna del CSV
+pub mod steps_column {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(steps: &[TestStep], serializer: S) -> Result<S::Ok, S::Error> {
+        if steps.is_empty() {
+            return serializer.serialize_str("");
+        }
+
+        let json = serde_json::to_string(steps).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<TestStep>, D::Error> {
+        let json = String::deserialize(deserializer)?;
+        if json.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_str(&json).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(statuses: &[TestStatus]) -> Vec<TestStep> {
+        statuses
+            .iter()
+            .map(|status| TestStep {
+                status: *status,
+                ..TestStep::new("Acción", "Resultado")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn suggests_validated_only_when_every_step_passed() {
+        use TestStatus::*;
+
+        assert_eq!(suggested_status(&steps(&[Validated])), Some(Validated));
+        assert_eq!(
+            suggested_status(&steps(&[Validated, Validated, Validated])),
+            Some(Validated)
+        );
+        assert_eq!(suggested_status(&[]), None);
+    }
+
+    #[test]
+    fn a_failed_step_decides_the_case() {
+        use TestStatus::*;
+
+        assert_eq!(
+            suggested_status(&steps(&[Validated, Rejected, Pending])),
+            Some(Rejected)
+        );
+        assert_eq!(
+            suggested_status(&steps(&[Validated, Blocked, Skipped])),
+            Some(Blocked)
+        );
+        // Un rechazo pesa más que un bloqueo, sin importar el orden
+        assert_eq!(
+            suggested_status(&steps(&[Blocked, Validated, Rejected])),
+            Some(Rejected)
+        );
+    }
+
+    #[test]
+    fn no_suggestion_for_pending_or_mixed_steps() {
+        use TestStatus::*;
+
+        assert_eq!(suggested_status(&steps(&[Pending, Pending])), None);
+        assert_eq!(suggested_status(&steps(&[Validated, Pending])), None);
+        assert_eq!(suggested_status(&steps(&[Validated, Skipped])), None);
+        assert_eq!(suggested_status(&steps(&[Skipped])), None);
+    }
+
+    #[test]
+    fn steps_column_round_trips_and_defaults_to_pending() {
+        #[derive(Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "steps_column")]
+            steps: Vec<TestStep>,
+        }
+
+        let row = Row {
+            steps: steps(&[TestStatus::Validated]),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap().steps, row.steps);
+
+        let empty: Row = serde_json::from_str(r#"{"steps": " "}"#).unwrap();
+        assert!(empty.steps.is_empty());
+
+        let legacy: Row =
+            serde_json::from_str(r#"{"steps": "[{\"action\": \"a\", \"expected\": \"b\"}]"}"#)
+                .unwrap();
+        assert_eq!(legacy.steps, vec![TestStep::new("a", "b")]);
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::analysis::{ChangeKind, Comparison};
+use crate::models::TestStatus;
+use crate::report::markdown::table_cell;
+
+/// Guarda la comparación de dos ejecuciones en formato Markdown
+pub fn save_comparison_to_markdown(file_path: &str, comparison: &Comparison) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_comparison_markdown(&mut file, comparison)
+}
+
+/// Escribe el informe Markdown de la comparación de dos ejecuciones
+pub fn write_comparison_markdown<W: Write>(out: &mut W, comparison: &Comparison) -> io::Result<()> {
+    // Escribir encabezado
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(
+        out,
+        "# Comparación de Ejecuciones: {} → {}",
+        comparison.base_name, comparison.target_name
+    )?;
+    writeln!(out, "\nFecha de comparación: {}\n", timestamp)?;
+
+    // Resumen por estado de ambas ejecuciones
+    writeln!(out, "## Resumen Numérico\n")?;
+    writeln!(
+        out,
+        "| Estado | {} | {} | Diferencia |",
+        table_cell(&comparison.base_name),
+        table_cell(&comparison.target_name)
+    )?;
+    writeln!(out, "|--------|-----|-----|------------|")?;
+    writeln!(
+        out,
+        "| Total de casos | {} | {} | {} |",
+        comparison.base_summary.total,
+        comparison.target_summary.total,
+        signed_difference(
+            comparison.base_summary.total,
+            comparison.target_summary.total
+        )
+    )?;
+    for status in TestStatus::ALL {
+        let before = comparison.base_summary.count(status);
+        let after = comparison.target_summary.count(status);
+        writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            status,
+            before,
+            after,
+            signed_difference(before, after)
+        )?;
+    }
+
+    writeln!(out, "\n## Cambios\n")?;
+    for kind in ChangeKind::ALL {
+        writeln!(
+            out,
+            "- {}: {}",
+            kind.title(),
+            comparison.changes_of(kind).count()
+        )?;
+    }
+    writeln!(out, "- Sin cambios: {}", comparison.unchanged)?;
+
+    // Una tabla por tipo de cambio, omitiendo las vacías
+    for kind in ChangeKind::ALL {
+        let changes: Vec<_> = comparison.changes_of(kind).collect();
+        if changes.is_empty() {
+            continue;
+        }
+
+        writeln!(out, "\n## {}\n", kind.title())?;
+        writeln!(out, "| ID | Descripción | Antes | Después |")?;
+        writeln!(out, "|-----|------------|-------|---------|")?;
+
+        for change in changes {
+            writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                table_cell(&change.id),
+                table_cell(&change.description),
+                status_cell(change.before),
+                status_cell(change.after)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Estado de un caso en una de las ejecuciones, o un guion si no existe en ella
+fn status_cell(status: Option<TestStatus>) -> String {
+    status.map_or_else(|| "—".to_string(), |s| s.to_string())
+}
+
+/// Diferencia con signo entre dos cantidades
+fn signed_difference(before: usize, after: usize) -> String {
+    let difference = after as i64 - before as i64;
+    if difference > 0 {
+        format!("+{}", difference)
+    } else {
+        difference.to_string()
+    }
+}
@@ -0,0 +1,805 @@
+use csv::{ReaderBuilder, Writer};
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+
+use crate::models::{Priority, TestCase, TestStep};
+
+/// Herramienta de gestión de pruebas con la que se intercambian archivos CSV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalTool {
+    TestRail,
+    Xray,
+}
+
+impl fmt::Display for ExternalTool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExternalTool::TestRail => write!(f, "TestRail"),
+            ExternalTool::Xray => write!(f, "Xray"),
+        }
+    }
+}
+
+/// Campo del caso de prueba al que se asigna una columna del CSV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseField {
+    Id,
+    Title,
+    Section,
+    Priority,
+    Preconditions,
+    Steps,
+    Expected,
+    TestData,
+    References,
+    Labels,
+    Version,
+}
+
+impl CaseField {
+    /// Todos los campos, en el orden en que se muestran
+    pub const ALL: [CaseField; 11] = [
+        CaseField::Id,
+        CaseField::Title,
+        CaseField::Section,
+        CaseField::Priority,
+        CaseField::Preconditions,
+        CaseField::Steps,
+        CaseField::Expected,
+        CaseField::TestData,
+        CaseField::References,
+        CaseField::Labels,
+        CaseField::Version,
+    ];
+
+    /// Nombre del campo para los menús
+    pub fn label(&self) -> &'static str {
+        match self {
+            CaseField::Id => "Identificador",
+            CaseField::Title => "Descripción (título)",
+            CaseField::Section => "Componente (sección)",
+            CaseField::Priority => "Prioridad",
+            CaseField::Preconditions => "Precondiciones",
+            CaseField::Steps => "Pasos (acción)",
+            CaseField::Expected => "Resultado esperado",
+            CaseField::TestData => "Datos de prueba",
+            CaseField::References => "Tickets (referencias)",
+            CaseField::Labels => "Etiquetas",
+            CaseField::Version => "Versión",
+        }
+    }
+
+    /// Cabeceras con las que TestRail, Xray y esta herramienta exportan el campo,
+    /// en minúsculas
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            CaseField::Id => &[
+                "id",
+                "tcid",
+                "test id",
+                "test case id",
+                "case id",
+                "issue id",
+                "issue key",
+                "key",
+            ],
+            CaseField::Title => &["title", "summary", "name", "título", "resumen"],
+            CaseField::Section => &[
+                "section",
+                "section hierarchy",
+                "component",
+                "components",
+                "componente",
+                "sección",
+            ],
+            CaseField::Priority => &["priority", "prioridad"],
+            CaseField::Preconditions => &[
+                "preconditions",
+                "precondition",
+                "precondiciones",
+                "description",
+            ],
+            CaseField::Steps => &["steps", "steps (step)", "step", "action", "acción", "pasos"],
+            CaseField::Expected => &[
+                "expected result",
+                "expected results",
+                "steps (expected result)",
+                "expected",
+                "resultado esperado",
+            ],
+            CaseField::TestData => &[
+                "test data",
+                "data",
+                "steps (additional info)",
+                "datos de prueba",
+            ],
+            CaseField::References => &["references", "refs", "requirements", "tickets"],
+            CaseField::Labels => &["labels", "tags", "etiquetas"],
+            CaseField::Version => &["version", "versión", "fix version", "fix versions"],
+        }
+    }
+}
+
+impl fmt::Display for CaseField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Archivo CSV de otra herramienta: cabeceras y filas tal como se leyeron
+#[derive(Debug, Clone)]
+pub struct ExternalCsv {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl ExternalCsv {
+    /// Lee un CSV con cabecera, quitando la marca BOM que añaden algunas exportaciones
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        let content = fs::read_to_string(file_path).map_err(|e| {
+            io::Error::new(e.kind(), format!("No se pudo leer {}: {}", file_path, e))
+        })?;
+        let content = content.trim_start_matches('\u{feff}');
+
+        let mut reader = ReaderBuilder::new()
+            .has_headers(true)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+
+        let headers = reader
+            .headers()?
+            .iter()
+            .map(|h| h.trim().to_string())
+            .collect();
+
+        let mut rows = Vec::new();
+        for (i, record) in reader.records().enumerate() {
+            let record = record.map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("Fila {}: {}", i + 2, e))
+            })?;
+            rows.push(record.iter().map(str::to_string).collect());
+        }
+
+        Ok(ExternalCsv { headers, rows })
+    }
+
+    /// Primer valor no vacío de una columna, como ejemplo en la vista previa
+    pub fn sample(&self, column: usize) -> Option<&str> {
+        self.rows
+            .iter()
+            .filter_map(|row| row.get(column))
+            .map(|value| value.trim())
+            .find(|value| !value.is_empty())
+    }
+}
+
+/// Asignación de cada columna del CSV a un campo del caso, o a ninguno
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    pub fields: Vec<Option<CaseField>>,
+}
+
+impl ColumnMapping {
+    /// Deduce la asignación por el nombre de las cabeceras; cada campo se asigna
+    /// como mucho a una columna, la primera que coincide
+    pub fn detect(headers: &[String]) -> Self {
+        let mut fields: Vec<Option<CaseField>> = vec![None; headers.len()];
+
+        for field in CaseField::ALL {
+            let column = headers.iter().enumerate().position(|(i, header)| {
+                fields[i].is_none() && field.aliases().contains(&header.to_lowercase().as_str())
+            });
+            if let Some(column) = column {
+                fields[column] = Some(field);
+            }
+        }
+
+        ColumnMapping { fields }
+    }
+
+    /// Columna asignada a un campo
+    pub fn column(&self, field: CaseField) -> Option<usize> {
+        self.fields.iter().position(|f| *f == Some(field))
+    }
+
+    /// Asigna una columna a un campo, quitándoselo a la columna que lo tenía
+    pub fn assign(&mut self, column: usize, field: Option<CaseField>) {
+        if let Some(field) = field {
+            for assigned in self.fields.iter_mut() {
+                if *assigned == Some(field) {
+                    *assigned = None;
+                }
+            }
+        }
+        if let Some(assigned) = self.fields.get_mut(column) {
+            *assigned = field;
+        }
+    }
+}
+
+/// Resultado de aplicar una asignación de columnas a las filas del CSV
+#[derive(Debug, Clone, Default)]
+pub struct MappedCases {
+    pub test_cases: Vec<TestCase>,
+    /// Filas omitidas o valores que no se pudieron interpretar
+    pub warnings: Vec<String>,
+}
+
+/// Convierte las filas del CSV en casos según la asignación de columnas
+///
+/// Una fila sin título, o con el mismo identificador que la anterior, añade sus
+/// pasos al caso anterior: así exportan TestRail los pasos separados y Xray los
+/// pasos de cada prueba.
+pub fn map_external_rows(csv: &ExternalCsv, mapping: &ColumnMapping) -> MappedCases {
+    let mut mapped = MappedCases::default();
+
+    if mapping.column(CaseField::Title).is_none() {
+        mapped.warnings.push(String::from(
+            "Ninguna columna está asignada al título del caso",
+        ));
+        return mapped;
+    }
+
+    let mut last_id = String::new();
+
+    for (i, row) in csv.rows.iter().enumerate() {
+        // La fila 1 es la cabecera de columnas
+        let line = i + 2;
+        let value = |field: CaseField| {
+            mapping
+                .column(field)
+                .and_then(|column| row.get(column))
+                .map(|v| v.trim())
+                .unwrap_or("")
+        };
+
+        let title = value(CaseField::Title);
+        let id = value(CaseField::Id);
+        let continues = title.is_empty() || (!id.is_empty() && id == last_id);
+
+        if continues {
+            match mapped.test_cases.last_mut() {
+                Some(test_case) => {
+                    add_steps(
+                        test_case,
+                        value(CaseField::Steps),
+                        value(CaseField::Expected),
+                    );
+                    append_line(&mut test_case.test_data, value(CaseField::TestData));
+                }
+                None if row.iter().any(|v| !v.trim().is_empty()) => mapped
+                    .warnings
+                    .push(format!("Fila {}: sin título, se omite", line)),
+                None => {}
+            }
+            continue;
+        }
+
+        let mut test_case = TestCase::new(
+            title,
+            value(CaseField::Version),
+            &split_values(value(CaseField::References)).join(", "),
+        );
+        if !id.is_empty() {
+            test_case.id = id.to_string();
+        }
+        test_case.component = value(CaseField::Section).to_string();
+        test_case.preconditions = value(CaseField::Preconditions).to_string();
+        test_case.test_data = value(CaseField::TestData).to_string();
+        test_case.tags = split_values(value(CaseField::Labels));
+
+        let priority = value(CaseField::Priority);
+        if !priority.is_empty() {
+            test_case.priority = parse_priority(priority);
+            if test_case.priority.is_none() {
+                mapped.warnings.push(format!(
+                    "Fila {}: prioridad '{}' desconocida, se deja sin prioridad",
+                    line, priority
+                ));
+            }
+        }
+
+        add_steps(
+            &mut test_case,
+            value(CaseField::Steps),
+            value(CaseField::Expected),
+        );
+
+        last_id = id.to_string();
+        mapped.test_cases.push(test_case);
+    }
+
+    mapped
+}
+
+/// Añade los pasos de una celda de acciones y otra de resultados esperados
+///
+/// Si las celdas son listas numeradas (`1. ...`, `2) ...`), cada elemento es un
+/// paso y se emparejan por posición.
+fn add_steps(test_case: &mut TestCase, actions: &str, expected: &str) {
+    let actions = split_numbered(actions);
+    let expected = split_numbered(expected);
+
+    for i in 0..actions.len().max(expected.len()) {
+        test_case.steps.push(TestStep::new(
+            actions.get(i).map_or("", String::as_str),
+            expected.get(i).map_or("", String::as_str),
+        ));
+    }
+}
+
+/// Elementos de una lista numerada (`1.`, `2.`...), o el texto completo si no lo es
+///
+/// Las líneas sin número se añaden al elemento anterior.
+fn split_numbered(text: &str) -> Vec<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let mut items: Vec<String> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match strip_number(line) {
+            Some((number, item)) if number == items.len() + 1 => items.push(item.to_string()),
+            _ => match items.last_mut() {
+                Some(last) => append_line(last, line),
+                None => return vec![text.to_string()],
+            },
+        }
+    }
+
+    if items.len() > 1 {
+        items
+    } else {
+        vec![text.to_string()]
+    }
+}
+
+/// Número y texto de un elemento numerado como `1. Abrir` o `2) Guardar`
+fn strip_number(line: &str) -> Option<(usize, &str)> {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let number = line[..digits].parse().ok()?;
+    let rest = &line[digits..];
+    rest.strip_prefix('.')
+        .or_else(|| rest.strip_prefix(')'))
+        .map(|item| (number, item.trim()))
+}
+
+fn append_line(text: &mut String, line: &str) {
+    if line.is_empty() {
+        return;
+    }
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(line);
+}
+
+/// Valores de una celda con varios elementos separados por comas o punto y coma,
+/// o por espacios si no hay ninguno de los dos (como las etiquetas de Jira)
+fn split_values(text: &str) -> Vec<String> {
+    let values: Vec<&str> = if text.contains([',', ';']) {
+        text.split([',', ';']).collect()
+    } else {
+        text.split_whitespace().collect()
+    };
+
+    values
+        .into_iter()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Prioridad a partir de los nombres de TestRail, Jira o `P0`…`P3`
+fn parse_priority(value: &str) -> Option<Priority> {
+    // TestRail antepone a veces el número: "4 - Must Test"
+    let name = value
+        .split_once(" - ")
+        .map_or(value, |(_, name)| name)
+        .trim()
+        .to_lowercase();
+
+    match name.as_str() {
+        "critical" | "highest" | "blocker" | "must test" | "crítica" => Some(Priority::P0),
+        "high" | "major" | "should test" | "alta" => Some(Priority::P1),
+        "medium" | "normal" | "test if time" | "media" => Some(Priority::P2),
+        "low" | "lowest" | "minor" | "trivial" | "don't test" | "baja" => Some(Priority::P3),
+        _ if name.starts_with('p') => name.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Nombre de la prioridad en TestRail
+fn testrail_priority(priority: Priority) -> &'static str {
+    match priority {
+        Priority::P0 => "Critical",
+        Priority::P1 => "High",
+        Priority::P2 => "Medium",
+        Priority::P3 => "Low",
+    }
+}
+
+/// Nombre de la prioridad en Jira, usada por Xray
+fn jira_priority(priority: Priority) -> &'static str {
+    match priority {
+        Priority::P0 => "Highest",
+        Priority::P1 => "High",
+        Priority::P2 => "Medium",
+        Priority::P3 => "Low",
+    }
+}
+
+/// Guarda los casos como CSV para el importador de TestRail
+///
+/// Hay una fila por caso; con más de un paso, las acciones y los resultados
+/// esperados se escriben como listas numeradas en `Steps` y `Expected Result`.
+pub fn save_to_testrail(file_path: &str, test_cases: &[TestCase]) -> io::Result<()> {
+    let mut writer = Writer::from_writer(File::create(file_path)?);
+    writer.write_record([
+        "ID",
+        "Title",
+        "Section",
+        "Priority",
+        "Preconditions",
+        "Test Data",
+        "Steps",
+        "Expected Result",
+        "References",
+        "Labels",
+    ])?;
+
+    for test_case in test_cases {
+        let numbered = test_case.steps.len() > 1;
+        let list = |text: fn(&TestStep) -> &str| {
+            test_case
+                .steps
+                .iter()
+                .enumerate()
+                .map(|(i, step)| {
+                    if numbered {
+                        format!("{}. {}", i + 1, text(step))
+                    } else {
+                        text(step).to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        writer.write_record([
+            test_case.id.as_str(),
+            &test_case.description,
+            &test_case.component,
+            test_case.priority.map_or("", testrail_priority),
+            &test_case.preconditions,
+            &test_case.test_data,
+            &list(|step| &step.action),
+            &list(|step| &step.expected),
+            &test_case.ticket_numbers,
+            &test_case.tags.join(", "),
+        ])?;
+    }
+
+    writer.flush()
+}
+
+/// Guarda los casos como CSV para el importador de pruebas de Xray
+///
+/// Hay una fila por paso agrupada por `TCID`; los datos del caso van solo en la
+/// primera. Las precondiciones se escriben en `Description` y las etiquetas se
+/// separan por `;`.
+pub fn save_to_xray(file_path: &str, test_cases: &[TestCase]) -> io::Result<()> {
+    let mut writer = Writer::from_writer(File::create(file_path)?);
+    writer.write_record([
+        "TCID",
+        "Summary",
+        "Description",
+        "Priority",
+        "Component",
+        "Labels",
+        "Test Type",
+        "Action",
+        "Data",
+        "Expected Result",
+    ])?;
+
+    for test_case in test_cases {
+        let labels: Vec<String> = test_case
+            .tags
+            .iter()
+            .map(|tag| tag.split_whitespace().collect::<Vec<_>>().join("_"))
+            .collect();
+        let empty_step = TestStep::new("", "");
+        let steps = if test_case.steps.is_empty() {
+            std::slice::from_ref(&empty_step)
+        } else {
+            test_case.steps.as_slice()
+        };
+
+        for (i, step) in steps.iter().enumerate() {
+            if i == 0 {
+                writer.write_record([
+                    test_case.id.as_str(),
+                    &test_case.description,
+                    &test_case.preconditions,
+                    test_case.priority.map_or("", jira_priority),
+                    &test_case.component,
+                    &labels.join(";"),
+                    "Manual",
+                    &step.action,
+                    &test_case.test_data,
+                    &step.expected,
+                ])?;
+            } else {
+                writer.write_record([
+                    test_case.id.as_str(),
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    &step.action,
+                    "",
+                    &step.expected,
+                ])?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const TESTRAIL_EXPORT: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/external/testrail_export.csv"
+    );
+    const XRAY_EXPORT: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/external/xray_export.csv"
+    );
+
+    fn import(file_path: &str) -> MappedCases {
+        let csv = ExternalCsv::load(file_path).unwrap();
+        let mapping = ColumnMapping::detect(&csv.headers);
+        map_external_rows(&csv, &mapping)
+    }
+
+    fn actions(test_case: &TestCase) -> Vec<&str> {
+        test_case.steps.iter().map(|s| s.action.as_str()).collect()
+    }
+
+    fn expected(test_case: &TestCase) -> Vec<&str> {
+        test_case
+            .steps
+            .iter()
+            .map(|s| s.expected.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn imports_a_testrail_export() {
+        let mapped = import(TESTRAIL_EXPORT);
+
+        assert_eq!(mapped.test_cases.len(), 3);
+        assert_eq!(
+            mapped.warnings,
+            vec!["Fila 5: prioridad 'Urgente' desconocida, se deja sin prioridad"]
+        );
+
+        let login = &mapped.test_cases[0];
+        assert_eq!(login.id, "C101");
+        assert_eq!(login.description, "Login con credenciales válidas");
+        assert_eq!(login.component, "Login");
+        assert_eq!(login.priority, Some(Priority::P1));
+        assert_eq!(login.preconditions, "Usuario registrado");
+        assert_eq!(login.ticket_numbers, "QA-12");
+        assert_eq!(
+            actions(login),
+            vec![
+                "Abrir la página de login",
+                "Ingresar usuario y contraseña\nrespetando mayúsculas",
+                "Pulsar \"Entrar\"",
+            ]
+        );
+        assert_eq!(
+            expected(login),
+            vec![
+                "Se muestra el formulario",
+                "Los campos aceptan el texto",
+                "Se abre el panel",
+            ]
+        );
+
+        // La fila sin título añade un paso al caso anterior
+        let lockout = &mapped.test_cases[1];
+        assert_eq!(lockout.priority, Some(Priority::P0));
+        assert_eq!(lockout.ticket_numbers, "QA-13, QA-14");
+        assert_eq!(
+            actions(lockout),
+            vec![
+                "Ingresar una contraseña incorrecta tres veces",
+                "Esperar 15 minutos",
+            ]
+        );
+        assert_eq!(
+            expected(lockout),
+            vec!["Se bloquea la cuenta", "Se desbloquea la cuenta"]
+        );
+
+        let recovery = &mapped.test_cases[2];
+        assert_eq!(recovery.priority, None);
+        assert!(recovery.steps.is_empty());
+    }
+
+    #[test]
+    fn imports_an_xray_export_with_one_step_per_row() {
+        let mapped = import(XRAY_EXPORT);
+
+        assert!(mapped.warnings.is_empty());
+        assert_eq!(mapped.test_cases.len(), 2);
+
+        let cart = &mapped.test_cases[0];
+        assert_eq!(cart.id, "1");
+        assert_eq!(cart.description, "Agregar al carrito");
+        assert_eq!(cart.preconditions, "Producto con stock");
+        assert_eq!(cart.priority, Some(Priority::P1));
+        assert_eq!(cart.component, "Carrito");
+        assert_eq!(cart.tags, vec!["smoke", "regresión"]);
+        assert_eq!(cart.test_data, "iPhone 15\nCantidad: 1");
+        assert_eq!(
+            actions(cart),
+            vec!["Buscar el producto", "Pulsar Agregar", "Abrir el carrito"]
+        );
+
+        let empty = &mapped.test_cases[1];
+        assert_eq!(empty.priority, Some(Priority::P3));
+        assert_eq!(empty.steps.len(), 1);
+    }
+
+    #[test]
+    fn reports_rows_that_cannot_be_mapped() {
+        let csv = ExternalCsv {
+            headers: vec!["Title".to_string(), "Steps".to_string()],
+            rows: vec![
+                vec![String::new(), "Paso suelto".to_string()],
+                vec![String::new(), String::new()],
+                vec!["Caso".to_string(), "Abrir".to_string()],
+            ],
+        };
+
+        let mapped = map_external_rows(&csv, &ColumnMapping::detect(&csv.headers));
+        assert_eq!(mapped.warnings, vec!["Fila 2: sin título, se omite"]);
+        assert_eq!(mapped.test_cases.len(), 1);
+        assert_eq!(actions(&mapped.test_cases[0]), vec!["Abrir"]);
+
+        let mut mapping = ColumnMapping::detect(&csv.headers);
+        mapping.assign(0, None);
+        let mapped = map_external_rows(&csv, &mapping);
+        assert!(mapped.test_cases.is_empty());
+        assert_eq!(
+            mapped.warnings,
+            vec!["Ninguna columna está asignada al título del caso"]
+        );
+    }
+
+    #[test]
+    fn splits_only_sequential_numbered_lists() {
+        assert_eq!(
+            split_numbered("1. Abrir\n2) Guardar\n   en disco\n3. Cerrar"),
+            vec!["Abrir", "Guardar\nen disco", "Cerrar"]
+        );
+        // Un solo elemento, números no consecutivos o texto previo: un solo paso
+        assert_eq!(split_numbered("1. Abrir"), vec!["1. Abrir"]);
+        assert_eq!(
+            split_numbered("1. Abrir\n3. Cerrar"),
+            vec!["1. Abrir\n3. Cerrar"]
+        );
+        assert_eq!(
+            split_numbered("Pasos:\n1. Abrir\n2. Cerrar"),
+            vec!["Pasos:\n1. Abrir\n2. Cerrar"]
+        );
+        assert_eq!(split_numbered("Ingresar 2.5 kg"), vec!["Ingresar 2.5 kg"]);
+        assert!(split_numbered("  \n ").is_empty());
+    }
+
+    #[test]
+    fn parses_priorities_from_testrail_jira_and_p_levels() {
+        let cases = [
+            ("Critical", Some(Priority::P0)),
+            ("4 - Must Test", Some(Priority::P0)),
+            ("Highest", Some(Priority::P0)),
+            ("High", Some(Priority::P1)),
+            ("3 - Should Test", Some(Priority::P1)),
+            ("medium", Some(Priority::P2)),
+            ("Normal", Some(Priority::P2)),
+            ("Lowest", Some(Priority::P3)),
+            ("Baja", Some(Priority::P3)),
+            ("p2", Some(Priority::P2)),
+            ("P9", None),
+            ("Urgente", None),
+        ];
+
+        for (value, priority) in cases {
+            assert_eq!(parse_priority(value), priority, "{}", value);
+        }
+    }
+
+    fn sample_cases() -> Vec<TestCase> {
+        let mut login = TestCase::new("Login con \"comillas\", y comas", "", "QA-1, QA-2");
+        login.id = "TC-1".to_string();
+        login.component = "Login".to_string();
+        login.priority = Some(Priority::P0);
+        login.preconditions = "Usuario registrado".to_string();
+        login.test_data = "usuario: qa".to_string();
+        login.tags = vec!["smoke".to_string(), "web".to_string()];
+        login.steps = vec![
+            TestStep::new("Abrir la página\nde login", "Se muestra el formulario"),
+            TestStep::new("Ingresar credenciales", ""),
+            TestStep::new("Pulsar Entrar", "Se abre el panel"),
+        ];
+
+        let mut single = TestCase::new("Un solo paso", "", "");
+        single.id = "TC-2".to_string();
+        single.priority = Some(Priority::P2);
+        single.steps = vec![TestStep::new("1. Paso con número", "Resultado")];
+
+        let mut without_steps = TestCase::new("Sin pasos", "", "");
+        without_steps.id = "TC-3".to_string();
+        without_steps.priority = Some(Priority::P3);
+
+        vec![login, single, without_steps]
+    }
+
+    fn assert_round_trip(exported: &[TestCase], imported: &MappedCases, with_references: bool) {
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        assert_eq!(imported.test_cases.len(), exported.len());
+
+        for (original, test_case) in exported.iter().zip(&imported.test_cases) {
+            assert_eq!(test_case.id, original.id);
+            assert_eq!(test_case.description, original.description);
+            assert_eq!(test_case.component, original.component);
+            assert_eq!(test_case.priority, original.priority);
+            assert_eq!(test_case.preconditions, original.preconditions);
+            assert_eq!(test_case.test_data, original.test_data);
+            assert_eq!(test_case.tags, original.tags);
+            assert_eq!(test_case.steps, original.steps);
+            if with_references {
+                assert_eq!(test_case.ticket_numbers, original.ticket_numbers);
+            }
+        }
+    }
+
+    #[test]
+    fn testrail_export_imports_back_unchanged() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("testrail.csv");
+        let path = path.to_str().unwrap();
+        let exported = sample_cases();
+
+        save_to_testrail(path, &exported).unwrap();
+
+        assert_round_trip(&exported, &import(path), true);
+    }
+
+    #[test]
+    fn xray_export_imports_back_unchanged() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("xray.csv");
+        let path = path.to_str().unwrap();
+        let exported = sample_cases();
+
+        save_to_xray(path, &exported).unwrap();
+
+        // El CSV de Xray no tiene columna de referencias
+        assert_round_trip(&exported, &import(path), false);
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::analysis::FlakyScore;
+use crate::config::FlakyConfig;
+use crate::report::markdown::table_cell;
+
+/// Guarda el análisis de inestabilidad de una definición en formato Markdown
+pub fn save_flaky_report(
+    file_path: &str,
+    definition: &str,
+    scores: &[FlakyScore],
+    config: &FlakyConfig,
+) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(file, "# Análisis de Inestabilidad: {}", definition)?;
+    writeln!(file, "\nFecha del análisis: {}", timestamp)?;
+    writeln!(
+        file,
+        "Ventana: {} ejecuciones, umbral: {:.0}%",
+        config.window,
+        config.threshold * 100.0
+    )?;
+
+    let flaky: Vec<FlakyScore> = scores
+        .iter()
+        .filter(|s| s.is_flaky(config))
+        .cloned()
+        .collect();
+    if flaky.is_empty() {
+        writeln!(file, "\nNo se detectaron casos inestables.")?;
+    }
+    write_flaky_section(&mut file, &flaky)?;
+
+    // Resto de casos con algún cambio, por debajo del umbral
+    let unstable: Vec<&FlakyScore> = scores
+        .iter()
+        .filter(|s| s.flips > 0 && !s.is_flaky(config))
+        .collect();
+    if !unstable.is_empty() {
+        writeln!(file, "\n## Casos con cambios por debajo del umbral\n")?;
+        write_score_table(&mut file, unstable)?;
+    }
+
+    Ok(())
+}
+
+/// Escribe la sección de casos inestables de un informe
+///
+/// No escribe nada si no hay casos inestables.
+pub fn write_flaky_section<W: Write>(out: &mut W, flaky: &[FlakyScore]) -> io::Result<()> {
+    if flaky.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "\n## Casos inestables (flaky)\n")?;
+    writeln!(
+        out,
+        "Casos que alternan entre ✅ y ❌ en las últimas ejecuciones de la definición.\n"
+    )?;
+    write_score_table(out, flaky.iter().collect())
+}
+
+fn write_score_table<W: Write>(out: &mut W, scores: Vec<&FlakyScore>) -> io::Result<()> {
+    writeln!(
+        out,
+        "| ID | Descripción | Cambios | Ejecuciones | Puntuación | Evolución |"
+    )?;
+    writeln!(
+        out,
+        "|-----|------------|---------|-------------|------------|-----------|"
+    )?;
+
+    for score in scores {
+        let timeline: Vec<&str> = score
+            .timeline
+            .iter()
+            .map(|status| status.map_or("—", |s| s.emoji()))
+            .collect();
+
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {:.0}% | {} |",
+            table_cell(&score.id),
+            table_cell(&score.description),
+            score.flips,
+            score.decided_runs,
+            score.score * 100.0,
+            timeline.join(" ")
+        )?;
+    }
+
+    Ok(())
+}
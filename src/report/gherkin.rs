@@ -0,0 +1,795 @@
+use percent_encoding::percent_decode_str;
+use std::fs::{self, File};
+use std::io::{self, Write};
+
+use crate::models::{Priority, TestCase, TestStep};
+
+/// Prefijos de las etiquetas que guardan los campos del caso en el archivo `.feature`
+const ID_TAG: &str = "id:";
+const COMPONENT_TAG: &str = "component:";
+const VERSION_TAG: &str = "version:";
+const TICKET_TAG: &str = "ticket:";
+const ISSUE_TAG: &str = "issue:";
+
+/// Prefijo de las líneas con los datos de prueba en la descripción del escenario
+const TEST_DATA_PREFIX: &str = "Datos:";
+
+/// Palabras clave de Gherkin en inglés y en español, seguidas de `:`
+const FEATURE_KEYWORDS: [&str; 3] = ["Feature", "Característica", "Funcionalidad"];
+const RULE_KEYWORDS: [&str; 2] = ["Rule", "Regla"];
+const BACKGROUND_KEYWORDS: [&str; 3] = ["Background", "Antecedentes", "Contexto"];
+const OUTLINE_KEYWORDS: [&str; 4] = [
+    "Scenario Outline",
+    "Scenario Template",
+    "Esquema del escenario",
+    "Plantilla del escenario",
+];
+const SCENARIO_KEYWORDS: [&str; 4] = ["Scenario", "Example", "Escenario", "Ejemplo"];
+const EXAMPLES_KEYWORDS: [&str; 4] = ["Examples", "Scenarios", "Ejemplos", "Escenarios"];
+
+/// Palabras clave de los pasos, sin `:`
+const GIVEN_KEYWORDS: [&str; 5] = ["Given", "Dado", "Dada", "Dados", "Dadas"];
+const WHEN_KEYWORDS: [&str; 2] = ["When", "Cuando"];
+const THEN_KEYWORDS: [&str; 2] = ["Then", "Entonces"];
+const AND_KEYWORDS: [&str; 6] = ["And", "But", "*", "Y", "E", "Pero"];
+
+/// Guarda una definición como archivo Gherkin `.feature`
+pub fn save_to_feature(file_path: &str, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_feature(&mut file, test_cases, title)
+}
+
+/// Escribe los casos como escenarios de una característica Gherkin en español
+///
+/// Las precondiciones son pasos `Dado`, la acción de cada paso un `Cuando` y el
+/// resultado esperado un `Entonces`; los datos de prueba van en la descripción
+/// del escenario, con cada línea precedida de `Datos:` para que no se lean como
+/// comentarios, etiquetas o pasos. La prioridad, el componente, el
+/// identificador, la versión, los tickets y la incidencia se guardan como
+/// etiquetas (`@P1`, `@component:checkout`, `@id:...`) junto a las etiquetas
+/// del caso.
+pub fn write_feature<W: Write>(
+    out: &mut W,
+    test_cases: &[TestCase],
+    title: &str,
+) -> io::Result<()> {
+    writeln!(out, "# language: es")?;
+    writeln!(out, "Característica: {}", single_line(title))?;
+
+    for test_case in test_cases {
+        writeln!(out)?;
+        writeln!(out, "  {}", feature_tags(test_case).join(" "))?;
+        writeln!(out, "  Escenario: {}", single_line(&test_case.description))?;
+
+        for line in test_case.test_data.lines() {
+            if line.is_empty() {
+                writeln!(out, "    {}", TEST_DATA_PREFIX)?;
+            } else {
+                writeln!(out, "    {} {}", TEST_DATA_PREFIX, line)?;
+            }
+        }
+
+        write_step_lines(out, "Dado", &test_case.preconditions)?;
+
+        for step in &test_case.steps {
+            let mut action = step.action.lines();
+            if let Some(first) = action.next() {
+                writeln!(out, "    Cuando {}", first.trim())?;
+
+                // Las acciones de varias líneas continúan en un bloque de texto
+                let rest: Vec<&str> = action.collect();
+                if !rest.is_empty() {
+                    let delimiter = if rest.iter().any(|line| line.trim() == "\"\"\"") {
+                        "```"
+                    } else {
+                        "\"\"\""
+                    };
+                    writeln!(out, "      {}", delimiter)?;
+                    for line in rest {
+                        writeln!(out, "      {}", line)?;
+                    }
+                    writeln!(out, "      {}", delimiter)?;
+                }
+            }
+            write_step_lines(out, "Entonces", &step.expected)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Escribe un texto de varias líneas como un paso seguido de pasos `Y`
+fn write_step_lines<W: Write>(out: &mut W, keyword: &str, text: &str) -> io::Result<()> {
+    for (i, line) in text.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+        let keyword = if i == 0 { keyword } else { "Y" };
+        writeln!(out, "    {} {}", keyword, line.trim())?;
+    }
+    Ok(())
+}
+
+/// Etiquetas de un escenario: primero los campos del caso y después sus etiquetas
+fn feature_tags(test_case: &TestCase) -> Vec<String> {
+    let mut tags = Vec::new();
+
+    if let Some(priority) = test_case.priority {
+        tags.push(format!("@{}", priority));
+    }
+    if !test_case.component.is_empty() {
+        tags.push(format!(
+            "@{}{}",
+            COMPONENT_TAG,
+            tag_value(&test_case.component)
+        ));
+    }
+    tags.push(format!("@{}{}", ID_TAG, tag_value(&test_case.id)));
+    if !test_case.version.is_empty() {
+        tags.push(format!("@{}{}", VERSION_TAG, tag_value(&test_case.version)));
+    }
+    for ticket in split_tickets(&test_case.ticket_numbers) {
+        tags.push(format!("@{}{}", TICKET_TAG, ticket));
+    }
+    if !test_case.issue_key.is_empty() {
+        tags.push(format!("@{}{}", ISSUE_TAG, tag_value(&test_case.issue_key)));
+    }
+    tags.extend(
+        test_case
+            .tags
+            .iter()
+            .map(|tag| format!("@{}", tag_value(tag))),
+    );
+
+    tags
+}
+
+/// Las etiquetas no admiten espacios: los espacios y `%` se codifican como en
+/// una URL (`TC 1` se escribe `TC%201`) para poder recuperar el valor original
+fn tag_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_whitespace() || c == '%' {
+            let mut bytes = [0; 4];
+            for byte in c.encode_utf8(&mut bytes).bytes() {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            encoded.push(c);
+        }
+    }
+    encoded
+}
+
+/// Valor original de una etiqueta escrita con [`tag_value`]
+fn decode_tag(tag: &str) -> String {
+    percent_decode_str(tag).decode_utf8_lossy().into_owned()
+}
+
+/// Tickets de un texto libre, conservando cada uno tal como se escribió
+fn split_tickets(ticket_numbers: &str) -> Vec<&str> {
+    ticket_numbers
+        .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Carga los escenarios de un archivo `.feature` como casos de prueba
+pub fn load_from_feature(file_path: &str) -> io::Result<Vec<TestCase>> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|e| io::Error::new(e.kind(), format!("No se pudo leer {}: {}", file_path, e)))?;
+    parse_feature(&content)
+}
+
+/// Tipo de un paso según su palabra clave
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepKind {
+    Given,
+    When,
+    Then,
+}
+
+/// Paso leído del archivo
+#[derive(Debug, Clone)]
+struct ParsedStep {
+    kind: StepKind,
+    text: String,
+    /// Escrito con `Y`, `Pero` o `*`: continúa el paso anterior
+    continued: bool,
+}
+
+/// Escenario leído del archivo, antes de convertirlo en casos
+#[derive(Debug, Default)]
+struct Scenario {
+    name: String,
+    tags: Vec<String>,
+    description: Vec<String>,
+    steps: Vec<ParsedStep>,
+    outline: bool,
+    examples: Vec<Examples>,
+}
+
+/// Tabla `Ejemplos` de un esquema de escenario
+#[derive(Debug, Default)]
+struct Examples {
+    tags: Vec<String>,
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Bloque en el que se encuentra el lector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Feature,
+    Background,
+    Scenario,
+    Examples,
+}
+
+/// Convierte el contenido de un archivo `.feature` en casos de prueba
+///
+/// Acepta palabras clave en inglés y en español. Cada escenario es un caso y
+/// cada fila de los ejemplos de un esquema de escenario, otro. Las etiquetas de
+/// la característica y de la regla se heredan, y los antecedentes se añaden a
+/// cada escenario.
+pub fn parse_feature(content: &str) -> io::Result<Vec<TestCase>> {
+    let mut feature_tags: Vec<String> = Vec::new();
+    let mut rule_tags: Vec<String> = Vec::new();
+    let mut pending_tags: Vec<String> = Vec::new();
+    let mut background: Vec<ParsedStep> = Vec::new();
+    let mut scenarios: Vec<Scenario> = Vec::new();
+    let mut section = Section::Feature;
+    let mut found_feature = false;
+    // Pasos de los antecedentes de la característica; los de una regla solo valen dentro de ella
+    let mut feature_background: Option<usize> = None;
+
+    let mut lines = content.lines().enumerate();
+    while let Some((number, raw_line)) = lines.next() {
+        let line = raw_line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('@') {
+            // Un comentario puede seguir a las etiquetas en la misma línea
+            let tags = line.split(" #").next().unwrap_or(line);
+            pending_tags.extend(
+                tags.split_whitespace()
+                    .filter_map(|tag| tag.strip_prefix('@'))
+                    .filter(|tag| !tag.is_empty())
+                    .map(decode_tag),
+            );
+            continue;
+        }
+
+        if let Some(_title) = keyword_line(line, &FEATURE_KEYWORDS) {
+            feature_tags = std::mem::take(&mut pending_tags);
+            section = Section::Feature;
+            found_feature = true;
+        } else if keyword_line(line, &RULE_KEYWORDS).is_some() {
+            rule_tags = std::mem::take(&mut pending_tags);
+            let feature_steps = *feature_background.get_or_insert(background.len());
+            background.truncate(feature_steps);
+            section = Section::Feature;
+        } else if keyword_line(line, &BACKGROUND_KEYWORDS).is_some() {
+            section = Section::Background;
+        } else if let Some(name) = keyword_line(line, &OUTLINE_KEYWORDS) {
+            scenarios.push(new_scenario(
+                name,
+                true,
+                [
+                    &feature_tags,
+                    &rule_tags,
+                    &std::mem::take(&mut pending_tags),
+                ],
+                &background,
+            ));
+            section = Section::Scenario;
+        } else if let Some(name) = keyword_line(line, &SCENARIO_KEYWORDS) {
+            scenarios.push(new_scenario(
+                name,
+                false,
+                [
+                    &feature_tags,
+                    &rule_tags,
+                    &std::mem::take(&mut pending_tags),
+                ],
+                &background,
+            ));
+            section = Section::Scenario;
+        } else if keyword_line(line, &EXAMPLES_KEYWORDS).is_some() {
+            let Some(scenario) = scenarios.last_mut() else {
+                return Err(syntax_error(number, "ejemplos fuera de un escenario"));
+            };
+            scenario.examples.push(Examples {
+                tags: std::mem::take(&mut pending_tags),
+                ..Examples::default()
+            });
+            section = Section::Examples;
+        } else if line.starts_with('|') {
+            let cells = table_cells(line);
+            match section {
+                Section::Examples => {
+                    if let Some(examples) = scenarios.last_mut().and_then(|s| s.examples.last_mut())
+                    {
+                        if examples.header.is_empty() {
+                            examples.header = cells;
+                        } else {
+                            examples.rows.push(cells);
+                        }
+                    }
+                }
+                // Las tablas de datos de un paso se añaden a su texto
+                _ => append_to_last_step(
+                    current_steps(section, &mut scenarios, &mut background),
+                    &format!("| {} |", cells.join(" | ")),
+                ),
+            }
+        } else if line.starts_with("\"\"\"") || line.starts_with("```") {
+            let delimiter = &line[..3];
+            let indent = raw_line.len() - raw_line.trim_start().len();
+            let mut doc_string = Vec::new();
+            let mut closed = false;
+            for (_, doc_line) in lines.by_ref() {
+                if doc_line.trim() == delimiter {
+                    closed = true;
+                    break;
+                }
+                doc_string.push(doc_line.get(indent..).unwrap_or(doc_line.trim_start()));
+            }
+            if !closed {
+                return Err(syntax_error(number, "bloque de texto sin cerrar"));
+            }
+            append_to_last_step(
+                current_steps(section, &mut scenarios, &mut background),
+                &doc_string.join("\n"),
+            );
+        } else if let Some((kind, text)) = step_line(line) {
+            let steps = current_steps(section, &mut scenarios, &mut background);
+            steps.push(ParsedStep {
+                kind: kind.unwrap_or_else(|| steps.last().map_or(StepKind::When, |s| s.kind)),
+                text: text.to_string(),
+                continued: kind.is_none(),
+            });
+        } else if section == Section::Scenario {
+            // Texto libre antes de los pasos: descripción del escenario
+            if let Some(scenario) = scenarios.last_mut() {
+                if scenario.steps.len() == background.len() {
+                    scenario.description.push(description_line(raw_line));
+                }
+            }
+        }
+    }
+
+    if !found_feature {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "El archivo no contiene ninguna característica (Feature: o Característica:)",
+        ));
+    }
+
+    Ok(scenarios.iter().flat_map(expand_scenario).collect())
+}
+
+/// Línea de la descripción de un escenario; las escritas con `Datos:` se
+/// conservan tal cual, sin el prefijo
+fn description_line(raw_line: &str) -> String {
+    match raw_line.trim_start().strip_prefix(TEST_DATA_PREFIX) {
+        Some(data) => data.strip_prefix(' ').unwrap_or(data).to_string(),
+        None => raw_line.trim().to_string(),
+    }
+}
+
+/// Título de una línea que empieza por alguna de las palabras clave seguida de `:`
+fn keyword_line<'a>(line: &'a str, keywords: &[&str]) -> Option<&'a str> {
+    keywords.iter().find_map(|keyword| {
+        line.strip_prefix(keyword)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .map(str::trim)
+    })
+}
+
+/// Tipo y texto de un paso; el tipo es `None` para `Y`, `Pero` y `*`, que
+/// continúan el paso anterior
+fn step_line(line: &str) -> Option<(Option<StepKind>, &str)> {
+    let groups: [(&[&str], Option<StepKind>); 4] = [
+        (&GIVEN_KEYWORDS, Some(StepKind::Given)),
+        (&WHEN_KEYWORDS, Some(StepKind::When)),
+        (&THEN_KEYWORDS, Some(StepKind::Then)),
+        (&AND_KEYWORDS, None),
+    ];
+
+    groups.iter().find_map(|(keywords, kind)| {
+        keywords.iter().find_map(|keyword| {
+            let rest = line.strip_prefix(keyword)?;
+            (rest.is_empty() || rest.starts_with(char::is_whitespace)).then(|| (*kind, rest.trim()))
+        })
+    })
+}
+
+fn new_scenario(
+    name: &str,
+    outline: bool,
+    tags: [&Vec<String>; 3],
+    background: &[ParsedStep],
+) -> Scenario {
+    Scenario {
+        name: name.to_string(),
+        tags: tags.into_iter().flatten().cloned().collect(),
+        steps: background.to_vec(),
+        outline,
+        ..Scenario::default()
+    }
+}
+
+/// Pasos a los que se añaden las líneas leídas en la sección actual
+fn current_steps<'a>(
+    section: Section,
+    scenarios: &'a mut [Scenario],
+    background: &'a mut Vec<ParsedStep>,
+) -> &'a mut Vec<ParsedStep> {
+    match (section, scenarios.last_mut()) {
+        (Section::Scenario | Section::Examples, Some(scenario)) => &mut scenario.steps,
+        _ => background,
+    }
+}
+
+fn append_to_last_step(steps: &mut [ParsedStep], text: &str) {
+    if let Some(step) = steps.last_mut() {
+        step.text.push('\n');
+        step.text.push_str(text);
+    }
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+fn syntax_error(line_index: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Línea {}: {}", line_index + 1, message),
+    )
+}
+
+/// Casos de un escenario: uno, o uno por fila de ejemplos si es un esquema
+fn expand_scenario(scenario: &Scenario) -> Vec<TestCase> {
+    if !scenario.outline {
+        return vec![build_test_case(
+            &scenario.name,
+            &scenario.tags,
+            &scenario.description,
+            &scenario.steps,
+            true,
+        )];
+    }
+
+    let mut test_cases = Vec::new();
+    for examples in &scenario.examples {
+        let tags: Vec<String> = scenario
+            .tags
+            .iter()
+            .chain(&examples.tags)
+            .cloned()
+            .collect();
+
+        for row in &examples.rows {
+            let values: Vec<(&String, &String)> = examples.header.iter().zip(row).collect();
+            let substitute = |text: &str| {
+                values.iter().fold(text.to_string(), |text, (name, value)| {
+                    text.replace(&format!("<{}>", name), value)
+                })
+            };
+
+            let mut name = substitute(&scenario.name);
+            if name == scenario.name {
+                let row_values: Vec<&str> = row.iter().map(String::as_str).collect();
+                name = format!("{} ({})", name, row_values.join(", "));
+            }
+
+            let mut description: Vec<String> =
+                scenario.description.iter().map(|l| substitute(l)).collect();
+            description.push(
+                values
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+
+            let steps: Vec<ParsedStep> = scenario
+                .steps
+                .iter()
+                .map(|step| ParsedStep {
+                    text: substitute(&step.text),
+                    ..step.clone()
+                })
+                .collect();
+
+            // Las filas comparten las etiquetas del esquema, pero no su identificador
+            test_cases.push(build_test_case(&name, &tags, &description, &steps, false));
+        }
+    }
+
+    test_cases
+}
+
+/// Construye un caso a partir de las partes de un escenario
+fn build_test_case(
+    name: &str,
+    tags: &[String],
+    description: &[String],
+    steps: &[ParsedStep],
+    keep_id: bool,
+) -> TestCase {
+    let mut test_case = TestCase::new(name, "", "");
+    let mut tickets: Vec<&str> = Vec::new();
+
+    for tag in tags {
+        if let Some(id) = tag.strip_prefix(ID_TAG) {
+            if keep_id && !id.is_empty() {
+                test_case.id = id.to_string();
+            }
+        } else if let Some(component) = tag.strip_prefix(COMPONENT_TAG) {
+            test_case.component = component.to_string();
+        } else if let Some(version) = tag.strip_prefix(VERSION_TAG) {
+            test_case.version = version.to_string();
+        } else if let Some(ticket) = tag.strip_prefix(TICKET_TAG) {
+            tickets.push(ticket);
+        } else if let Some(issue) = tag.strip_prefix(ISSUE_TAG) {
+            test_case.issue_key = issue.to_string();
+        } else if let Some(priority) = priority_tag(tag) {
+            test_case.priority = Some(priority);
+        } else if !test_case.tags.contains(tag) {
+            test_case.tags.push(tag.clone());
+        }
+    }
+    test_case.ticket_numbers = tickets.join(", ");
+    test_case.test_data = description.join("\n");
+
+    let mut preconditions: Vec<&str> = Vec::new();
+    let mut previous: Option<StepKind> = None;
+    for step in steps {
+        let text = step.text.as_str();
+        match step.kind {
+            StepKind::Given => preconditions.push(text),
+            StepKind::When => test_case.steps.push(TestStep::new(text, "")),
+            StepKind::Then => {
+                // El resultado esperado completa la última acción; los `Y` que
+                // lo siguen se unen por líneas
+                let completes_last = match previous {
+                    Some(StepKind::When) => true,
+                    Some(StepKind::Then) => step.continued,
+                    _ => false,
+                };
+                match test_case.steps.last_mut() {
+                    Some(last) if completes_last => {
+                        if !last.expected.is_empty() {
+                            last.expected.push('\n');
+                        }
+                        last.expected.push_str(text);
+                    }
+                    _ => test_case.steps.push(TestStep::new("", text)),
+                }
+            }
+        }
+        previous = Some(step.kind);
+    }
+    test_case.preconditions = preconditions.join("\n");
+
+    test_case
+}
+
+/// Prioridad de una etiqueta `P0`…`P3`
+fn priority_tag(tag: &str) -> Option<Priority> {
+    let digit = tag.strip_prefix('P').or_else(|| tag.strip_prefix('p'))?;
+    if digit.len() != 1 {
+        return None;
+    }
+    tag.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export(test_cases: &[TestCase]) -> String {
+        let mut out = Vec::new();
+        write_feature(&mut out, test_cases, "Pagos").unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn round_trip_keeps_test_data_and_tag_values() {
+        let mut test_case = TestCase::new("Pago con tarjeta", "2.1 beta", "QA-1, QA-2");
+        test_case.id = String::from("TC 1");
+        test_case.component = String::from("Pagos web");
+        test_case.tags = vec![String::from("smoke test"), String::from("50%")];
+        test_case.issue_key = String::from("PAY-9");
+        test_case.priority = Some(Priority::P1);
+        test_case.test_data =
+            String::from("# tarjeta 4111\n@admin rol\nY también\n\n  Datos: sangrado");
+        test_case.preconditions = String::from("la tienda está abierta\nhay stock");
+        test_case.steps = vec![
+            TestStep::new(
+                "pagar con\n\"\"\"\n  importe 10",
+                "se cobra\nllega un correo",
+            ),
+            TestStep::new("", "no hay cargos duplicados"),
+        ];
+
+        let parsed = parse_feature(&export(std::slice::from_ref(&test_case))).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        let parsed = &parsed[0];
+        assert_eq!(parsed.id, test_case.id);
+        assert_eq!(parsed.description, test_case.description);
+        assert_eq!(parsed.version, test_case.version);
+        assert_eq!(parsed.ticket_numbers, test_case.ticket_numbers);
+        assert_eq!(parsed.component, test_case.component);
+        assert_eq!(parsed.tags, test_case.tags);
+        assert_eq!(parsed.issue_key, test_case.issue_key);
+        assert_eq!(parsed.priority, test_case.priority);
+        assert_eq!(parsed.test_data, test_case.test_data);
+        assert_eq!(parsed.preconditions, test_case.preconditions);
+        assert_eq!(parsed.steps, test_case.steps);
+    }
+
+    #[test]
+    fn reads_tags_written_before_the_encoding() {
+        let feature = "Feature: F\n  @id:TC_1 @component:checkout\n  Scenario: S\n    When a\n";
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(parsed[0].id, "TC_1");
+        assert_eq!(parsed[0].component, "checkout");
+    }
+
+    #[test]
+    fn expands_scenario_outline_examples() {
+        let feature = r#"
+Feature: Login
+  @outline
+  Scenario Outline: Login as <user>
+    Given the user <user> exists
+    When they enter <password>
+    Then they see "<message>"
+
+    @negative
+    Examples:
+      | user | password | message     |
+      | ana  | 1        | too short   |
+      | bob  | 12       | too short   |
+"#;
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].description, "Login as ana");
+        assert_eq!(parsed[1].description, "Login as bob");
+        assert_eq!(parsed[0].preconditions, "the user ana exists");
+        assert_eq!(
+            parsed[1].steps,
+            vec![TestStep::new("they enter 12", "they see \"too short\"")]
+        );
+        assert_eq!(
+            parsed[0].test_data,
+            "user=ana, password=1, message=too short"
+        );
+        assert_eq!(parsed[0].tags, vec!["outline", "negative"]);
+        assert_ne!(parsed[0].id, parsed[1].id);
+    }
+
+    #[test]
+    fn outline_without_placeholders_in_name_appends_row_values() {
+        let feature = "Feature: F\n  Scenario Outline: Sumar\n    When sumo <a>\n    Examples:\n      | a |\n      | 1 |\n";
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(parsed[0].description, "Sumar (1)");
+    }
+
+    #[test]
+    fn applies_feature_and_rule_backgrounds() {
+        let feature = r#"
+Feature: Checkout
+  Background:
+    Given the shop is open
+
+  Rule: Cards
+    Background:
+      Given a card is saved
+
+    Scenario: Pay with card
+      When I pay
+      Then it is charged
+
+  Rule: Vouchers
+    Scenario: Pay with voucher
+      When I redeem it
+      Then the total is zero
+"#;
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].preconditions, "the shop is open\na card is saved");
+        assert_eq!(parsed[1].preconditions, "the shop is open");
+        assert_eq!(
+            parsed[1].steps,
+            vec![TestStep::new("I redeem it", "the total is zero")]
+        );
+    }
+
+    #[test]
+    fn joins_multi_line_steps() {
+        let feature = r#"
+# language: es
+Característica: Pedidos
+  Escenario: Crear pedido
+    Cuando envío el pedido
+      """
+      {"id": 1}
+      """
+    Entonces se guarda
+    Y se notifica
+    Cuando consulto los pedidos
+      | id | estado |
+      | 1  | nuevo  |
+    Entonces aparece el pedido
+    Entonces no hay errores
+"#;
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(
+            parsed[0].steps,
+            vec![
+                TestStep::new("envío el pedido\n{\"id\": 1}", "se guarda\nse notifica"),
+                TestStep::new(
+                    "consulto los pedidos\n| id | estado |\n| 1 | nuevo |",
+                    "aparece el pedido"
+                ),
+                TestStep::new("", "no hay errores"),
+            ]
+        );
+    }
+
+    #[test]
+    fn inherits_feature_and_rule_tags() {
+        let feature = r#"
+@web @component:tienda
+Feature: Tienda
+  @rule-tag
+  Rule: Carrito
+    @P0 @id:C-1 @ticket:QA-1 @ticket:QA-2
+    Scenario: Añadir producto # comentario
+      When añado un producto
+
+  Rule: Pago
+    Scenario: Pagar
+      When pago
+"#;
+
+        let parsed = parse_feature(feature).unwrap();
+
+        assert_eq!(parsed[0].id, "C-1");
+        assert_eq!(parsed[0].priority, Some(Priority::P0));
+        assert_eq!(parsed[0].component, "tienda");
+        assert_eq!(parsed[0].ticket_numbers, "QA-1, QA-2");
+        assert_eq!(parsed[0].tags, vec!["web", "rule-tag"]);
+        assert_eq!(parsed[1].tags, vec!["web"]);
+    }
+
+    #[test]
+    fn rejects_files_without_feature() {
+        assert!(parse_feature("Scenario: suelto\n  When algo\n").is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::analysis::History;
+use crate::report::markdown::table_cell;
+
+/// Guarda el informe de tendencia de una definición en formato Markdown
+pub fn save_history_to_markdown(file_path: &str, history: &History) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_history_markdown(&mut file, history)
+}
+
+/// Escribe el informe de tendencia de una definición: ejecuciones, gráfico de
+/// tasa de éxito y matriz de estados por caso
+pub fn write_history_markdown<W: Write>(out: &mut W, history: &History) -> io::Result<()> {
+    // Escribir encabezado
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(out, "# Historial de Ejecuciones: {}", history.definition)?;
+    writeln!(out, "\nFecha del informe: {}", timestamp)?;
+    writeln!(out, "Ejecuciones: {}\n", history.runs.len())?;
+
+    if history.runs.is_empty() {
+        return Ok(());
+    }
+
+    // Tabla de ejecuciones con su tasa de éxito
+    writeln!(out, "## Ejecuciones\n")?;
+    writeln!(
+        out,
+        "| # | Ejecución | Fecha | Total | ✅ | ❌ | ⏳ | ⏭️ | 🚫 | Tasa de éxito |"
+    )?;
+    writeln!(
+        out,
+        "|---|-----------|-------|-------|----|----|----|----|----|---------------|"
+    )?;
+    for (i, run) in history.runs.iter().enumerate() {
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {:.1}% |",
+            i + 1,
+            table_cell(&run.name),
+            run.label(),
+            run.summary.total,
+            run.summary.validated,
+            run.summary.rejected,
+            run.summary.pending,
+            run.summary.skipped,
+            run.summary.blocked,
+            run.pass_rate()
+        )?;
+    }
+
+    // Gráfico de la tasa de éxito con Mermaid
+    writeln!(out, "\n## Tendencia\n")?;
+    writeln!(out, "```mermaid")?;
+    writeln!(out, "xychart-beta")?;
+    writeln!(out, "    title \"Tasa de éxito por ejecución\"")?;
+    let labels: Vec<String> = history
+        .runs
+        .iter()
+        .map(|run| format!("\"{}\"", run.label().replace('"', "'")))
+        .collect();
+    writeln!(out, "    x-axis [{}]", labels.join(", "))?;
+    writeln!(out, "    y-axis \"Validados (%)\" 0 --> 100")?;
+    let rates: Vec<String> = history
+        .runs
+        .iter()
+        .map(|run| format!("{:.1}", run.pass_rate()))
+        .collect();
+    writeln!(out, "    line [{}]", rates.join(", "))?;
+    writeln!(out, "```\n")?;
+
+    // Matriz caso × ejecución; las columnas siguen la numeración de la tabla de ejecuciones
+    writeln!(out, "## Evolución por caso\n")?;
+    let numbers: Vec<String> = (1..=history.runs.len()).map(|i| i.to_string()).collect();
+    writeln!(out, "| ID | Descripción | {} |", numbers.join(" | "))?;
+    writeln!(
+        out,
+        "|-----|------------|{}",
+        "---|".repeat(history.runs.len())
+    )?;
+
+    for (id, description) in history.cases() {
+        let cells: Vec<&str> = history
+            .timeline(&id)
+            .into_iter()
+            .map(|status| status.map_or("—", |s| s.emoji()))
+            .collect();
+
+        writeln!(
+            out,
+            "| {} | {} | {} |",
+            table_cell(&id),
+            table_cell(&description),
+            cells.join(" | ")
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,605 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::Local;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use std::env;
+use std::f64::consts::PI;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::models::{TestCase, TestStatus};
+use crate::report::junit::escape_xml;
+use crate::report::StatusSummary;
+
+/// Tamaño máximo de una imagen de evidencia que se incrusta en el informe
+const MAX_EMBEDDED_IMAGE_BYTES: u64 = 2 * 1024 * 1024;
+
+const IMAGE_EXTENSIONS: [&str; 7] = ["png", "jpg", "jpeg", "gif", "webp", "svg", "bmp"];
+
+/// Esquemas de URL que se enlazan; los demás, como `javascript:`, se muestran como texto
+const LINK_SCHEMES: [&str; 3] = ["http", "https", "file"];
+
+/// Caracteres que se codifican en los segmentos de la ruta de un enlace local
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Como [`PATH_SEGMENT`], también con `:` para que un segmento no parezca un esquema
+const RELATIVE_PATH_SEGMENT: &AsciiSet = &PATH_SEGMENT.add(b':');
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, Helvetica, Arial, sans-serif; margin: 2rem; color: #222; }
+h1 { margin-bottom: 0.2rem; }
+.meta { color: #555; margin: 0.1rem 0; }
+.summary { display: flex; align-items: center; gap: 2rem; margin: 1.5rem 0; }
+.summary ul { list-style: none; padding: 0; margin: 0; }
+.summary li { margin: 0.25rem 0; }
+.swatch { display: inline-block; width: 0.8rem; height: 0.8rem; border-radius: 2px; margin-right: 0.4rem; }
+.filters { margin: 1rem 0; }
+.filters label { display: inline-block; padding: 0.3rem 0.8rem; margin: 0 0.3rem 0.3rem 0; border: 1px solid #ccc; border-radius: 1rem; cursor: pointer; }
+#f-all:checked ~ .filters label[for=f-all],
+#f-validated:checked ~ .filters label[for=f-validated],
+#f-rejected:checked ~ .filters label[for=f-rejected],
+#f-pending:checked ~ .filters label[for=f-pending],
+#f-skipped:checked ~ .filters label[for=f-skipped],
+#f-blocked:checked ~ .filters label[for=f-blocked] { background: #333; color: #fff; border-color: #333; }
+#f-validated:checked ~ table tbody tr:not(.validated),
+#f-rejected:checked ~ table tbody tr:not(.rejected),
+#f-pending:checked ~ table tbody tr:not(.pending),
+#f-skipped:checked ~ table tbody tr:not(.skipped),
+#f-blocked:checked ~ table tbody tr:not(.blocked) { display: none; }
+input[name=filter] { display: none; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ddd; padding: 0.5rem; text-align: left; vertical-align: top; }
+th { background: #f4f4f4; }
+td.text { white-space: pre-wrap; }
+tr.validated td.status { color: #2e7d32; }
+tr.rejected td.status { color: #c62828; }
+tr.pending td.status { color: #b58900; }
+tr.skipped td.status { color: #616161; }
+tr.blocked td.status { color: #e65100; }
+img.thumb { max-width: 160px; max-height: 120px; display: block; margin-top: 0.3rem; border: 1px solid #ddd; }
+details { margin-top: 0.4rem; }
+ol.steps { margin: 0.3rem 0; padding-left: 1.2rem; }
+"#;
+
+/// Guarda casos de prueba como informe HTML autocontenido
+///
+/// Los enlaces a la evidencia local se escriben relativos al directorio del informe.
+pub fn save_to_html(file_path: &str, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    let report_dir = Path::new(file_path).parent().unwrap_or(Path::new(""));
+    let mut file = File::create(file_path)?;
+    write_html_in(&mut file, test_cases, title, report_dir)
+}
+
+/// Escribe un informe HTML de un único archivo, con estilos en línea, gráfico
+/// circular en SVG, filtros por estado y miniaturas de la evidencia en imagen
+///
+/// Los enlaces a la evidencia local son relativos al directorio de trabajo.
+pub fn write_html<W: Write>(out: &mut W, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    write_html_in(out, test_cases, title, Path::new(""))
+}
+
+/// Como [`write_html`], con los enlaces a la evidencia local relativos al
+/// directorio donde se guarda el informe
+///
+/// Las rutas de la evidencia son relativas al directorio de trabajo, donde se
+/// registraron; las imágenes se siguen leyendo desde ahí para incrustarlas.
+pub fn write_html_in<W: Write>(
+    out: &mut W,
+    test_cases: &[TestCase],
+    title: &str,
+    report_dir: &Path,
+) -> io::Result<()> {
+    let summary = StatusSummary::from_cases(test_cases);
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    let title = escape_xml(title);
+
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, r#"<html lang="es">"#)?;
+    writeln!(out, "<head>")?;
+    writeln!(out, r#"<meta charset="utf-8">"#)?;
+    writeln!(out, "<title>Informe de Pruebas: {}</title>", title)?;
+    writeln!(out, "<style>{}</style>", STYLE)?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+
+    // Encabezado con versión y tickets
+    writeln!(out, "<h1>Informe de Pruebas: {}</h1>", title)?;
+    writeln!(
+        out,
+        r#"<p class="meta">Fecha de ejecución: {}</p>"#,
+        timestamp
+    )?;
+    if let Some(first_case) = test_cases.first() {
+        let ticket_info = if first_case.ticket_numbers.is_empty() {
+            "N/A"
+        } else {
+            first_case.ticket_numbers.as_str()
+        };
+        writeln!(
+            out,
+            r#"<p class="meta">Versión de prueba: {}</p>"#,
+            escape_xml(&first_case.version)
+        )?;
+        writeln!(
+            out,
+            r#"<p class="meta">Ticket(s): {}</p>"#,
+            escape_xml(ticket_info)
+        )?;
+    }
+
+    // Resumen numérico y gráfico
+    writeln!(out, r#"<div class="summary">"#)?;
+    write_pie_chart(out, &summary)?;
+    writeln!(out, "<ul>")?;
+    writeln!(
+        out,
+        "<li><strong>Total de casos: {}</strong></li>",
+        summary.total
+    )?;
+    for status in TestStatus::ALL {
+        writeln!(
+            out,
+            r#"<li><span class="swatch" style="background:{}"></span>{}: {}</li>"#,
+            status_color(status),
+            status,
+            summary.count(status)
+        )?;
+    }
+    writeln!(out, "</ul>")?;
+    writeln!(out, "</div>")?;
+
+    // Filtros por estado: radios seguidos de la tabla, sin JavaScript
+    writeln!(
+        out,
+        r#"<input type="radio" name="filter" id="f-all" checked>"#
+    )?;
+    for status in TestStatus::ALL {
+        writeln!(
+            out,
+            r#"<input type="radio" name="filter" id="f-{}">"#,
+            status_class(status)
+        )?;
+    }
+    writeln!(out, r#"<div class="filters">"#)?;
+    writeln!(
+        out,
+        r#"<label for="f-all">Todos ({})</label>"#,
+        summary.total
+    )?;
+    for status in TestStatus::ALL {
+        writeln!(
+            out,
+            r#"<label for="f-{}">{} ({})</label>"#,
+            status_class(status),
+            status,
+            summary.count(status)
+        )?;
+    }
+    writeln!(out, "</div>")?;
+
+    // Detalle de casos
+    writeln!(out, "<table>")?;
+    writeln!(
+        out,
+        "<thead><tr><th>ID</th><th>Descripción</th><th>Estado</th><th>Observaciones</th><th>Evidencia</th></tr></thead>"
+    )?;
+    writeln!(out, "<tbody>")?;
+    for test_case in test_cases {
+        write_case_row(out, test_case, report_dir)?;
+    }
+    writeln!(out, "</tbody>")?;
+    writeln!(out, "</table>")?;
+
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+
+    Ok(())
+}
+
+fn write_case_row<W: Write>(
+    out: &mut W,
+    test_case: &TestCase,
+    report_dir: &Path,
+) -> io::Result<()> {
+    writeln!(out, r#"<tr class="{}">"#, status_class(test_case.status))?;
+    writeln!(out, "<td>{}</td>", escape_xml(&test_case.id))?;
+
+    write!(
+        out,
+        r#"<td class="text">{}"#,
+        escape_xml(&test_case.description)
+    )?;
+    if !test_case.steps.is_empty()
+        || !test_case.preconditions.is_empty()
+        || !test_case.test_data.is_empty()
+    {
+        write!(out, "<details><summary>Detalle</summary>")?;
+        if !test_case.preconditions.is_empty() {
+            write!(
+                out,
+                "<p><strong>Precondiciones:</strong> {}</p>",
+                escape_xml(&test_case.preconditions)
+            )?;
+        }
+        if !test_case.test_data.is_empty() {
+            write!(
+                out,
+                "<p><strong>Datos de prueba:</strong> {}</p>",
+                escape_xml(&test_case.test_data)
+            )?;
+        }
+        if !test_case.steps.is_empty() {
+            write!(out, r#"<ol class="steps">"#)?;
+            for step in &test_case.steps {
+                write!(
+                    out,
+                    "<li>{} {} → {}</li>",
+                    step.status.emoji(),
+                    escape_xml(&step.action),
+                    escape_xml(&step.expected)
+                )?;
+            }
+            write!(out, "</ol>")?;
+        }
+        write!(out, "</details>")?;
+    }
+    writeln!(out, "</td>")?;
+
+    writeln!(out, r#"<td class="status">{}</td>"#, test_case.status)?;
+    writeln!(
+        out,
+        r#"<td class="text">{}</td>"#,
+        escape_xml(&test_case.observations)
+    )?;
+
+    write!(out, "<td>")?;
+    for evidence in split_evidence(&test_case.evidence) {
+        write_evidence(out, evidence, report_dir)?;
+    }
+    writeln!(out, "</td>")?;
+
+    writeln!(out, "</tr>")?;
+
+    Ok(())
+}
+
+/// Enlace a una evidencia, con miniatura si es una imagen
+///
+/// Solo se enlazan las URLs `http`, `https` y `file` y las rutas locales; el
+/// resto (`javascript:`, `data:`...) se muestra como texto.
+fn write_evidence<W: Write>(out: &mut W, evidence: &str, report_dir: &Path) -> io::Result<()> {
+    let text = escape_xml(evidence);
+
+    let (href, src) = match evidence_target(evidence) {
+        EvidenceTarget::Url(url) => (escape_xml(url), None),
+        // Las imágenes locales se incrustan para que el informe funcione fuera de la máquina
+        EvidenceTarget::Local(path) => (
+            escape_xml(&local_href(path, report_dir)),
+            embedded_image(evidence),
+        ),
+        EvidenceTarget::Unsafe => return write!(out, "<div>{}</div>", text),
+    };
+    write!(out, r#"<div><a href="{}">{}</a>"#, href, text)?;
+
+    if is_image(evidence) {
+        let src = src.unwrap_or_else(|| href.clone());
+        write!(
+            out,
+            r#"<a href="{}"><img class="thumb" src="{}" alt="{}"></a>"#,
+            href, src, text
+        )?;
+    }
+
+    write!(out, "</div>")
+}
+
+/// Destino de una evidencia
+enum EvidenceTarget<'a> {
+    Url(&'a str),
+    Local(&'a Path),
+    Unsafe,
+}
+
+fn evidence_target(evidence: &str) -> EvidenceTarget<'_> {
+    // Los navegadores ignoran tabuladores y saltos dentro de una URL (`java\tscript:`)
+    if evidence.chars().any(char::is_control) {
+        return EvidenceTarget::Unsafe;
+    }
+
+    match url_scheme(evidence) {
+        Some(scheme) if LINK_SCHEMES.contains(&scheme.to_lowercase().as_str()) => {
+            EvidenceTarget::Url(evidence)
+        }
+        Some(_) => EvidenceTarget::Unsafe,
+        None => EvidenceTarget::Local(Path::new(evidence)),
+    }
+}
+
+/// Esquema de una URL, como `https`; las unidades de Windows (`C:\`) no lo son
+fn url_scheme(evidence: &str) -> Option<&str> {
+    let (scheme, rest) = evidence.split_once(':')?;
+    let valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    let drive = scheme.len() == 1 && rest.starts_with(['\\', '/']);
+
+    (valid && !drive).then_some(scheme)
+}
+
+/// Enlace a un archivo local que funciona desde el directorio del informe
+fn local_href(path: &Path, report_dir: &Path) -> String {
+    if path.is_absolute() {
+        return file_url(path);
+    }
+
+    let Ok(working_dir) = env::current_dir() else {
+        return encode_path(path, RELATIVE_PATH_SEGMENT);
+    };
+    let target = normalize(&working_dir.join(path));
+    let base = normalize(&working_dir.join(report_dir));
+
+    let common = target
+        .components()
+        .zip(base.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return file_url(&target);
+    }
+
+    let mut relative = PathBuf::new();
+    for _ in base.components().skip(common) {
+        relative.push("..");
+    }
+    relative.extend(target.components().skip(common));
+
+    encode_path(&relative, RELATIVE_PATH_SEGMENT)
+}
+
+/// URL `file://` de una ruta absoluta
+fn file_url(path: &Path) -> String {
+    let encoded = encode_path(path, PATH_SEGMENT);
+    if encoded.starts_with('/') {
+        format!("file://{}", encoded)
+    } else {
+        format!("file:///{}", encoded)
+    }
+}
+
+/// Ruta con `/` como separador y cada segmento codificado como en una URL
+fn encode_path(path: &Path, segment: &'static AsciiSet) -> String {
+    let mut encoded = String::new();
+
+    for component in path.components() {
+        match component {
+            Component::RootDir => encoded.push('/'),
+            Component::Prefix(prefix) => {
+                encoded.push_str(&prefix.as_os_str().to_string_lossy().replace('\\', "/"))
+            }
+            Component::CurDir => {}
+            other => {
+                if !encoded.is_empty() && !encoded.ends_with('/') {
+                    encoded.push('/');
+                }
+                let text = other.as_os_str().to_string_lossy();
+                encoded.extend(utf8_percent_encode(&text, segment));
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Ruta sin componentes `.` ni `..`, sin consultar el sistema de archivos
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+
+    normalized
+}
+
+/// Separa un campo de evidencia con varias rutas o URLs
+fn split_evidence(evidence: &str) -> impl Iterator<Item = &str> {
+    evidence
+        .split([',', ';', '\n'])
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+}
+
+fn is_image(evidence: &str) -> bool {
+    let path = evidence.split(['?', '#']).next().unwrap_or(evidence);
+
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+}
+
+/// Imagen local codificada como `data:` URI, si existe y no es demasiado grande
+fn embedded_image(evidence: &str) -> Option<String> {
+    let metadata = fs::metadata(evidence).ok()?;
+    if !metadata.is_file() || metadata.len() > MAX_EMBEDDED_IMAGE_BYTES {
+        return None;
+    }
+
+    let extension = Path::new(evidence).extension()?.to_str()?.to_lowercase();
+    let mime = match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg".to_string(),
+        "svg" => "image/svg+xml".to_string(),
+        other => format!("image/{}", other),
+    };
+
+    let data = fs::read(evidence).ok()?;
+    Some(format!("data:{};base64,{}", mime, BASE64.encode(data)))
+}
+
+/// Gráfico circular en SVG con la distribución de estados
+fn write_pie_chart<W: Write>(out: &mut W, summary: &StatusSummary) -> io::Result<()> {
+    const RADIUS: f64 = 80.0;
+    const CENTER: f64 = 90.0;
+
+    writeln!(
+        out,
+        r#"<svg width="180" height="180" viewBox="0 0 180 180" role="img" aria-label="Distribución de Casos de Prueba">"#
+    )?;
+
+    if summary.total == 0 {
+        writeln!(
+            out,
+            r##"<circle cx="{c}" cy="{c}" r="{r}" fill="#eee"/>"##,
+            c = CENTER,
+            r = RADIUS
+        )?;
+    }
+
+    let mut angle = -PI / 2.0;
+    for status in TestStatus::ALL {
+        let count = summary.count(status);
+        if count == 0 {
+            continue;
+        }
+
+        let label = format!("{}: {}", status, count);
+
+        // Un único estado ocupa el círculo completo; un arco no puede dibujarlo
+        if count == summary.total {
+            writeln!(
+                out,
+                r#"<circle cx="{c}" cy="{c}" r="{r}" fill="{}"><title>{}</title></circle>"#,
+                status_color(status),
+                label,
+                c = CENTER,
+                r = RADIUS
+            )?;
+            break;
+        }
+
+        let sweep = 2.0 * PI * count as f64 / summary.total as f64;
+        let (x1, y1) = (CENTER + RADIUS * angle.cos(), CENTER + RADIUS * angle.sin());
+        angle += sweep;
+        let (x2, y2) = (CENTER + RADIUS * angle.cos(), CENTER + RADIUS * angle.sin());
+        let large_arc = if sweep > PI { 1 } else { 0 };
+
+        writeln!(
+            out,
+            r#"<path d="M{c},{c} L{:.2},{:.2} A{r},{r} 0 {} 1 {:.2},{:.2} Z" fill="{}"><title>{}</title></path>"#,
+            x1,
+            y1,
+            large_arc,
+            x2,
+            y2,
+            status_color(status),
+            label,
+            c = CENTER,
+            r = RADIUS
+        )?;
+    }
+
+    writeln!(out, "</svg>")
+}
+
+/// Clase CSS usada para filtrar por estado
+fn status_class(status: TestStatus) -> &'static str {
+    match status {
+        TestStatus::Pending => "pending",
+        TestStatus::Validated => "validated",
+        TestStatus::Rejected => "rejected",
+        TestStatus::Skipped => "skipped",
+        TestStatus::Blocked => "blocked",
+    }
+}
+
+fn status_color(status: TestStatus) -> &'static str {
+    match status {
+        TestStatus::Validated => "#43a047",
+        TestStatus::Rejected => "#e53935",
+        TestStatus::Pending => "#fdd835",
+        TestStatus::Skipped => "#9e9e9e",
+        TestStatus::Blocked => "#fb8c00",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempWorkingDir;
+
+    fn evidence_html(evidence: &str, report_dir: &str) -> String {
+        let mut out = Vec::new();
+        write_evidence(&mut out, evidence, Path::new(report_dir)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn does_not_link_unsafe_schemes() {
+        for evidence in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "vbscript:msgbox",
+            "java\tscript:alert(1)",
+        ] {
+            let html = evidence_html(evidence, "");
+            assert!(!html.contains("href"), "{}", html);
+            assert!(!html.contains("<script>"), "{}", html);
+        }
+    }
+
+    #[test]
+    fn links_web_urls_as_they_are() {
+        let html = evidence_html("https://ci.example.com/run?id=1&x=2", "reports");
+
+        assert!(html.contains(r#"href="https://ci.example.com/run?id=1&amp;x=2""#));
+    }
+
+    #[test]
+    fn links_local_paths_relative_to_the_report() {
+        assert!(evidence_html("evidence/login fail.log", "reports")
+            .contains(r#"href="../evidence/login%20fail.log""#));
+        assert!(evidence_html("evidence/a.log", "").contains(r#"href="evidence/a.log""#));
+        assert!(
+            evidence_html("./evidence/a.log", "executions/../reports/html")
+                .contains(r#"href="../../evidence/a.log""#)
+        );
+        assert!(evidence_html("a:b.log", "").contains(r#"<div>a:b.log</div>"#));
+        assert!(evidence_html("/var/evidence/a b.log", "reports")
+            .contains(r#"href="file:///var/evidence/a%20b.log""#));
+    }
+
+    #[test]
+    fn embeds_images_read_from_the_working_directory() {
+        let _dir = TempWorkingDir::new();
+        fs::create_dir_all("evidence").unwrap();
+        fs::create_dir_all("reports").unwrap();
+        fs::write("evidence/shot.png", [137, 80, 78, 71]).unwrap();
+        let mut test_case = TestCase::new("Caso", "1.0", "");
+        test_case.evidence = String::from("evidence/shot.png");
+
+        save_to_html("reports/report.html", &[test_case], "Informe").unwrap();
+
+        let html = fs::read_to_string("reports/report.html").unwrap();
+        assert!(html.contains(r#"href="../evidence/shot.png""#));
+        assert!(html.contains(r#"src="data:image/png;base64,iVBORw==""#));
+    }
+}
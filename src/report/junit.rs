@@ -0,0 +1,234 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::models::{TestCase, TestStatus};
+use crate::report::StatusSummary;
+
+/// Guarda casos de prueba como informe JUnit XML
+pub fn save_to_junit(file_path: &str, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_junit(&mut file, test_cases, title)
+}
+
+/// Escribe el informe JUnit XML de una lista de casos de prueba
+///
+/// Cada caso es un `<testcase>`: los rechazados son fallos, los bloqueados
+/// errores y los omitidos o pendientes se marcan como `<skipped>`. Las
+/// observaciones y la evidencia van en `<system-out>`.
+pub fn write_junit<W: Write>(out: &mut W, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    let summary = StatusSummary::from_cases(test_cases);
+    let timestamp = Local::now().format("%Y-%m-%dT%H:%M:%S");
+    let title = escape_xml(title);
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites name="{}" tests="{}" failures="{}" errors="{}" skipped="{}">"#,
+        title,
+        summary.total,
+        summary.rejected,
+        summary.blocked,
+        summary.skipped + summary.pending
+    )?;
+    writeln!(
+        out,
+        r#"  <testsuite name="{}" tests="{}" failures="{}" errors="{}" skipped="{}" timestamp="{}">"#,
+        title,
+        summary.total,
+        summary.rejected,
+        summary.blocked,
+        summary.skipped + summary.pending,
+        timestamp
+    )?;
+
+    // Versión y tickets como propiedades de la suite
+    if let Some(first_case) = test_cases.first() {
+        writeln!(out, "    <properties>")?;
+        writeln!(
+            out,
+            r#"      <property name="version" value="{}"/>"#,
+            escape_xml(&first_case.version)
+        )?;
+        writeln!(
+            out,
+            r#"      <property name="ticket_numbers" value="{}"/>"#,
+            escape_xml(&first_case.ticket_numbers)
+        )?;
+        writeln!(out, "    </properties>")?;
+    }
+
+    for test_case in test_cases {
+        write_test_case(out, test_case, &title)?;
+    }
+
+    writeln!(out, "  </testsuite>")?;
+    writeln!(out, "</testsuites>")?;
+
+    Ok(())
+}
+
+fn write_test_case<W: Write>(out: &mut W, test_case: &TestCase, classname: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        r#"    <testcase name="{}: {}" classname="{}">"#,
+        escape_xml(&test_case.id),
+        escape_xml(&test_case.description),
+        classname
+    )?;
+
+    let message = if test_case.observations.is_empty() {
+        test_case.status.to_string()
+    } else {
+        test_case.observations.clone()
+    };
+
+    match test_case.status {
+        TestStatus::Validated => {}
+        TestStatus::Rejected => writeln!(
+            out,
+            r#"      <failure message="{}" type="Rejected"/>"#,
+            escape_xml(&message)
+        )?,
+        TestStatus::Blocked => writeln!(
+            out,
+            r#"      <error message="{}" type="Blocked"/>"#,
+            escape_xml(&message)
+        )?,
+        TestStatus::Skipped | TestStatus::Pending => writeln!(
+            out,
+            r#"      <skipped message="{}"/>"#,
+            escape_xml(&message)
+        )?,
+    }
+
+    if !test_case.observations.is_empty() || !test_case.evidence.is_empty() {
+        let mut system_out = format!("Estado: {}", test_case.status);
+        if !test_case.observations.is_empty() {
+            system_out.push_str(&format!("\nObservaciones: {}", test_case.observations));
+        }
+        if !test_case.evidence.is_empty() {
+            system_out.push_str(&format!("\nEvidencia: {}", test_case.evidence));
+        }
+
+        writeln!(
+            out,
+            "      <system-out>{}</system-out>",
+            escape_xml(&system_out)
+        )?;
+    }
+
+    writeln!(out, "    </testcase>")?;
+
+    Ok(())
+}
+
+/// Escapa los caracteres especiales de XML
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Caracteres de control no permitidos en XML 1.0
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(id: &str, status: TestStatus, observations: &str) -> TestCase {
+        let mut test_case = TestCase::new(&format!("Caso {}", id), "1.0", "QA-1");
+        test_case.id = id.to_string();
+        test_case.status = status;
+        test_case.observations = observations.to_string();
+        test_case
+    }
+
+    fn render(test_cases: &[TestCase], title: &str) -> String {
+        let mut out = Vec::new();
+        write_junit(&mut out, test_cases, title).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Bloque `<testcase>` completo de un caso
+    fn test_case_block<'a>(xml: &'a str, id: &str) -> &'a str {
+        let start = xml.find(&format!(r#"<testcase name="{}: "#, id)).unwrap();
+        let end = start + xml[start..].find("</testcase>").unwrap();
+        &xml[start..end]
+    }
+
+    #[test]
+    fn maps_each_status_to_its_junit_element() {
+        let xml = render(
+            &[
+                case("a1", TestStatus::Validated, ""),
+                case("b2", TestStatus::Rejected, "No guarda"),
+                case("c3", TestStatus::Blocked, ""),
+                case("d4", TestStatus::Skipped, ""),
+                case("e5", TestStatus::Pending, ""),
+            ],
+            "Login",
+        );
+
+        assert!(xml.contains(
+            r#"<testsuites name="Login" tests="5" failures="1" errors="1" skipped="2">"#
+        ));
+        assert!(xml.contains(r#"<property name="version" value="1.0"/>"#));
+
+        let validated = test_case_block(&xml, "a1");
+        assert!(!validated.contains("<failure"));
+        assert!(!validated.contains("<error"));
+        assert!(!validated.contains("<skipped"));
+        assert!(!validated.contains("<system-out>"));
+
+        let rejected = test_case_block(&xml, "b2");
+        assert!(rejected.contains(r#"<failure message="No guarda" type="Rejected"/>"#));
+        assert!(rejected.contains("<system-out>"));
+
+        let blocked = test_case_block(&xml, "c3");
+        assert!(blocked.contains(&format!(
+            r#"<error message="{}" type="Blocked"/>"#,
+            TestStatus::Blocked
+        )));
+
+        for id in ["d4", "e5"] {
+            let block = test_case_block(&xml, id);
+            assert!(block.contains("<skipped message="), "{}", block);
+            assert!(!block.contains("<failure"));
+        }
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        let mut test_case = case("a1", TestStatus::Rejected, r#"Falla si x < 3 && y > "2""#);
+        test_case.description = "Filtrar <script> & 'comillas'".to_string();
+        test_case.evidence = "captura\u{7}.png".to_string();
+
+        let xml = render(&[test_case], "R&D <web>");
+
+        assert!(xml.contains(r#"<testsuites name="R&amp;D &lt;web&gt;""#));
+        assert!(xml.contains(
+            r#"<testcase name="a1: Filtrar &lt;script&gt; &amp; &apos;comillas&apos;" classname="R&amp;D &lt;web&gt;">"#
+        ));
+        assert!(xml.contains(
+            r#"<failure message="Falla si x &lt; 3 &amp;&amp; y &gt; &quot;2&quot;" type="Rejected"/>"#
+        ));
+        assert!(xml.contains(
+            "Observaciones: Falla si x &lt; 3 &amp;&amp; y &gt; &quot;2&quot;\nEvidencia: captura.png</system-out>"
+        ));
+        // Sin entidades dobles
+        assert!(!xml.contains("&amp;lt;"));
+        assert!(!xml.contains("&amp;amp;"));
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::analysis::FlakyScore;
+use crate::integrations::TicketCache;
+use crate::models::TestCase;
+use crate::report::{write_flaky_section, write_tickets_section, StatusSummary};
+
+/// Guarda casos de prueba en formato Markdown
+pub fn save_to_markdown(file_path: &str, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_markdown(&mut file, test_cases, title)
+}
+
+/// Guarda el informe Markdown de una ejecución, seguido de los casos inestables
+/// de la definición y del estado de los tickets
+pub fn save_execution_markdown(
+    file_path: &str,
+    test_cases: &[TestCase],
+    title: &str,
+    flaky: &[FlakyScore],
+    tickets: &TicketCache,
+) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_execution_markdown(&mut file, test_cases, title, flaky, tickets)
+}
+
+/// Escribe el informe Markdown de una ejecución con las mismas secciones que
+/// [`save_execution_markdown`]
+pub fn write_execution_markdown<W: Write>(
+    out: &mut W,
+    test_cases: &[TestCase],
+    title: &str,
+    flaky: &[FlakyScore],
+    tickets: &TicketCache,
+) -> io::Result<()> {
+    write_markdown(out, test_cases, title)?;
+    write_flaky_section(out, flaky)?;
+    write_tickets_section(out, test_cases, tickets)
+}
+
+/// Escribe el informe Markdown de una lista de casos de prueba
+pub fn write_markdown<W: Write>(
+    out: &mut W,
+    test_cases: &[TestCase],
+    title: &str,
+) -> io::Result<()> {
+    // Escribir encabezado
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(out, "# Informe de Pruebas: {}", title)?;
+    writeln!(out, "\nFecha de ejecución: {}", timestamp)?;
+
+    // Añadir versión y tickets si hay casos de prueba disponibles
+    if let Some(first_case) = test_cases.first() {
+        writeln!(out, "Versión de prueba: {}", first_case.version)?;
+        let ticket_info = if first_case.ticket_numbers.is_empty() {
+            "N/A".to_string()
+        } else {
+            first_case.ticket_numbers.clone()
+        };
+        writeln!(out, "Ticket(s): {}\n", ticket_info)?;
+    } else {
+        writeln!(out)?;
+    }
+
+    // Calcular resumen
+    let summary = StatusSummary::from_cases(test_cases);
+
+    // Escribir resumen textual primero
+    writeln!(out, "## Resumen Numérico\n")?;
+    writeln!(out, "- Total de casos: {}", summary.total)?;
+    writeln!(out, "- ✅ Validados: {}", summary.validated)?;
+    writeln!(out, "- ❌ Rechazados: {}", summary.rejected)?;
+    writeln!(out, "- ⏳ Pendientes: {}", summary.pending)?;
+    writeln!(out, "- ⏭️ Omitidos: {}", summary.skipped)?;
+    writeln!(out, "- 🚫 Bloqueados: {}\n", summary.blocked)?;
+
+    // Crear gráfico circular con Mermaid
+    writeln!(out, "## Resumen Visual\n")?;
+    writeln!(out, "```mermaid")?;
+    writeln!(out, "pie title Distribución de Casos de Prueba")?;
+
+    // Añadir secciones al gráfico solo si tienen valores mayores que cero
+    if summary.validated > 0 {
+        writeln!(out, "    \"✅ Validados\" : {}", summary.validated)?; // Verde
+    }
+    if summary.rejected > 0 {
+        writeln!(out, "    \"❌ Rechazados\" : {}", summary.rejected)?; // Rojo
+    }
+    if summary.pending > 0 {
+        writeln!(out, "    \"⏳ Pendientes\" : {}", summary.pending)?; // Amarillo
+    }
+    if summary.skipped > 0 {
+        writeln!(out, "    \"⏭️ Omitidos\" : {}", summary.skipped)?; // Gris
+    }
+    if summary.blocked > 0 {
+        writeln!(out, "    \"🚫 Bloqueados\" : {}", summary.blocked)?; // Naranja
+    }
+
+    writeln!(out, "```\n")?;
+
+    // Escribir tabla
+    writeln!(out, "## Detalle de casos\n")?;
+    write_case_table(out, test_cases)?;
+
+    write_case_details(out, test_cases)
+}
+
+/// Escribe la tabla de casos con su estado, observaciones y evidencia
+///
+/// Las columnas de prioridad, componente y etiquetas solo se incluyen si algún
+/// caso las tiene.
+pub(crate) fn write_case_table<W: Write>(out: &mut W, test_cases: &[TestCase]) -> io::Result<()> {
+    let classified = test_cases
+        .iter()
+        .any(|tc| tc.priority.is_some() || !tc.component.is_empty() || !tc.tags.is_empty());
+
+    if classified {
+        writeln!(
+            out,
+            "| ID | Descripción | Prioridad | Componente | Etiquetas | Estado | Observaciones | Evidencia |"
+        )?;
+        writeln!(
+            out,
+            "|-----|------------|-----------|------------|-----------|--------|---------------|-----------|"
+        )?;
+    } else {
+        writeln!(
+            out,
+            "| ID | Descripción | Estado | Observaciones | Evidencia |"
+        )?;
+        writeln!(
+            out,
+            "|-----|------------|--------|---------------|-----------|"
+        )?;
+    }
+
+    for test_case in test_cases {
+        let classification = if classified {
+            format!(
+                " {} | {} | {} |",
+                test_case
+                    .priority
+                    .map(|p| p.to_string())
+                    .unwrap_or_default(),
+                table_cell(&test_case.component),
+                table_cell(&test_case.tags.join(", "))
+            )
+        } else {
+            String::new()
+        };
+
+        // La incidencia abierta para el caso se muestra junto a su estado
+        let status = if test_case.issue_key.is_empty() {
+            test_case.status.to_string()
+        } else {
+            format!(
+                "{} ({})",
+                test_case.status,
+                table_cell(&test_case.issue_key)
+            )
+        };
+
+        writeln!(
+            out,
+            "| {} | {} |{} {} | {} | {} |",
+            table_cell(&test_case.id),
+            table_cell(&test_case.description),
+            classification,
+            status,
+            table_cell(&test_case.observations),
+            table_cell(&test_case.evidence)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Escribe precondiciones, datos y pasos de los casos que los tienen
+pub(crate) fn write_case_details<W: Write>(out: &mut W, test_cases: &[TestCase]) -> io::Result<()> {
+    let detailed: Vec<&TestCase> = test_cases
+        .iter()
+        .filter(|tc| {
+            !tc.steps.is_empty() || !tc.preconditions.is_empty() || !tc.test_data.is_empty()
+        })
+        .collect();
+
+    if detailed.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "\n## Pasos de los casos\n")?;
+
+    for test_case in detailed {
+        writeln!(out, "### {} - {}\n", test_case.id, test_case.description)?;
+
+        if !test_case.preconditions.is_empty() {
+            writeln!(out, "- **Precondiciones**: {}", test_case.preconditions)?;
+        }
+        if !test_case.test_data.is_empty() {
+            writeln!(out, "- **Datos de prueba**: {}", test_case.test_data)?;
+        }
+
+        if !test_case.steps.is_empty() {
+            writeln!(out, "\n| # | Acción | Resultado esperado | Estado |")?;
+            writeln!(out, "|---|--------|--------------------|--------|")?;
+            for (i, step) in test_case.steps.iter().enumerate() {
+                writeln!(
+                    out,
+                    "| {} | {} | {} | {} |",
+                    i + 1,
+                    table_cell(&step.action),
+                    table_cell(&step.expected),
+                    step.status
+                )?;
+            }
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// Escapa el texto de una celda para que no rompa la tabla Markdown
+pub(crate) fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
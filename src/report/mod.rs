@@ -0,0 +1,134 @@
+pub mod comparison;
+pub mod external_csv;
+pub mod flaky;
+pub mod gherkin;
+pub mod history;
+pub mod html;
+pub mod junit;
+pub mod markdown;
+pub mod plan;
+pub mod tickets;
+
+pub use comparison::save_comparison_to_markdown;
+pub use external_csv::{
+    map_external_rows, save_to_testrail, save_to_xray, CaseField, ColumnMapping, ExternalCsv,
+    ExternalTool, MappedCases,
+};
+pub use flaky::{save_flaky_report, write_flaky_section};
+pub use gherkin::{load_from_feature, parse_feature, save_to_feature};
+pub use history::save_history_to_markdown;
+pub use html::save_to_html;
+pub use junit::save_to_junit;
+pub use markdown::{save_execution_markdown, save_to_markdown};
+pub use plan::save_plan_report;
+pub use tickets::write_tickets_section;
+
+use serde::Serialize;
+use std::io;
+use std::str::FromStr;
+
+use crate::models::{TestCase, TestStatus};
+
+/// Formatos de informe disponibles para exportar una ejecución
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Junit,
+    Html,
+    Gherkin,
+    TestRail,
+    Xray,
+}
+
+impl ReportFormat {
+    /// Extensión de archivo del formato
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Junit => "xml",
+            ReportFormat::Html => "html",
+            ReportFormat::Gherkin => "feature",
+            // Con prefijo, para no reemplazar el CSV de la definición
+            ReportFormat::TestRail => "testrail.csv",
+            ReportFormat::Xray => "xray.csv",
+        }
+    }
+
+    /// Guarda los casos de prueba en este formato
+    pub fn save(&self, file_path: &str, test_cases: &[TestCase], title: &str) -> io::Result<()> {
+        match self {
+            ReportFormat::Markdown => save_to_markdown(file_path, test_cases, title),
+            ReportFormat::Junit => save_to_junit(file_path, test_cases, title),
+            ReportFormat::Html => save_to_html(file_path, test_cases, title),
+            ReportFormat::Gherkin => save_to_feature(file_path, test_cases, title),
+            ReportFormat::TestRail => save_to_testrail(file_path, test_cases),
+            ReportFormat::Xray => save_to_xray(file_path, test_cases),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "junit" | "xml" => Ok(ReportFormat::Junit),
+            "html" => Ok(ReportFormat::Html),
+            "gherkin" | "feature" => Ok(ReportFormat::Gherkin),
+            "testrail" => Ok(ReportFormat::TestRail),
+            "xray" => Ok(ReportFormat::Xray),
+            _ => Err(format!(
+                "Formato inválido '{}'. Use markdown, junit, html, gherkin, testrail o xray",
+                s
+            )),
+        }
+    }
+}
+
+/// Cantidad de casos de prueba por estado
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct StatusSummary {
+    pub total: usize,
+    pub validated: usize,
+    pub rejected: usize,
+    pub pending: usize,
+    pub skipped: usize,
+    pub blocked: usize,
+}
+
+impl StatusSummary {
+    /// Calcula el resumen de una lista de casos de prueba
+    pub fn from_cases(test_cases: &[TestCase]) -> Self {
+        let mut summary = StatusSummary::default();
+
+        for test_case in test_cases {
+            summary.add(test_case.status);
+        }
+
+        summary
+    }
+
+    /// Suma un caso con el estado indicado
+    pub fn add(&mut self, status: TestStatus) {
+        self.total += 1;
+        match status {
+            TestStatus::Validated => self.validated += 1,
+            TestStatus::Rejected => self.rejected += 1,
+            TestStatus::Pending => self.pending += 1,
+            TestStatus::Skipped => self.skipped += 1,
+            TestStatus::Blocked => self.blocked += 1,
+        }
+    }
+
+    /// Cantidad de casos con el estado indicado
+    pub fn count(&self, status: TestStatus) -> usize {
+        match status {
+            TestStatus::Validated => self.validated,
+            TestStatus::Rejected => self.rejected,
+            TestStatus::Pending => self.pending,
+            TestStatus::Skipped => self.skipped,
+            TestStatus::Blocked => self.blocked,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::Local;
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::engine::{base_name_of, Execution};
+use crate::models::TestCase;
+use crate::report::markdown::{table_cell, write_case_details, write_case_table};
+use crate::report::StatusSummary;
+
+/// Guarda el informe combinado de la ejecución de un plan de pruebas
+pub fn save_plan_report(
+    file_path: &str,
+    plan_name: &str,
+    executions: &[Execution],
+) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    write_plan_report(&mut file, plan_name, executions)
+}
+
+/// Escribe el informe combinado de un plan: resumen global, resumen por
+/// definición y el detalle de los casos de cada una
+pub fn write_plan_report<W: Write>(
+    out: &mut W,
+    plan_name: &str,
+    executions: &[Execution],
+) -> io::Result<()> {
+    // Escribir encabezado
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(out, "# Informe del Plan de Pruebas: {}", plan_name)?;
+    writeln!(out, "\nFecha de ejecución: {}\n", timestamp)?;
+
+    let all_cases: Vec<TestCase> = executions
+        .iter()
+        .flat_map(|e| e.test_cases.iter().cloned())
+        .collect();
+    let summary = StatusSummary::from_cases(&all_cases);
+
+    writeln!(out, "## Resumen Numérico\n")?;
+    writeln!(out, "- Definiciones: {}", executions.len())?;
+    writeln!(out, "- Total de casos: {}", summary.total)?;
+    writeln!(out, "- ✅ Validados: {}", summary.validated)?;
+    writeln!(out, "- ❌ Rechazados: {}", summary.rejected)?;
+    writeln!(out, "- ⏳ Pendientes: {}", summary.pending)?;
+    writeln!(out, "- ⏭️ Omitidos: {}", summary.skipped)?;
+    writeln!(out, "- 🚫 Bloqueados: {}\n", summary.blocked)?;
+
+    // Resumen por definición
+    writeln!(out, "## Resumen por definición\n")?;
+    writeln!(
+        out,
+        "| Definición | Ejecución | Total | ✅ | ❌ | ⏳ | ⏭️ | 🚫 |"
+    )?;
+    writeln!(
+        out,
+        "|------------|-----------|-------|----|----|----|----|----|"
+    )?;
+    for execution in executions {
+        let summary = StatusSummary::from_cases(&execution.test_cases);
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} | {} |",
+            table_cell(&base_name_of(&execution.name)),
+            table_cell(&execution.name),
+            summary.total,
+            summary.validated,
+            summary.rejected,
+            summary.pending,
+            summary.skipped,
+            summary.blocked
+        )?;
+    }
+
+    // Detalle de cada definición
+    for execution in executions {
+        writeln!(out, "\n## {}\n", base_name_of(&execution.name))?;
+        write_case_table(out, &execution.test_cases)?;
+        write_case_details(out, &execution.test_cases)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::io::{self, Write};
+
+use crate::integrations::TicketCache;
+use crate::models::TestCase;
+use crate::report::markdown::table_cell;
+
+/// Escribe la sección con el estado de los tickets de los casos
+///
+/// Solo incluye los tickets sincronizados con `tickets sync`; no escribe nada si
+/// ningún caso tiene tickets sincronizados.
+pub fn write_tickets_section<W: Write>(
+    out: &mut W,
+    test_cases: &[TestCase],
+    tickets: &TicketCache,
+) -> io::Result<()> {
+    // Tickets en orden de aparición, con los casos que los mencionan
+    let mut rows: Vec<(&str, Vec<&str>)> = Vec::new();
+    for test_case in test_cases {
+        for ticket in tickets.tickets_of(test_case) {
+            match rows.iter_mut().find(|(key, _)| *key == ticket.key) {
+                Some((_, ids)) => ids.push(&test_case.id),
+                None => rows.push((&ticket.key, vec![&test_case.id])),
+            }
+        }
+    }
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "\n## Tickets\n")?;
+    writeln!(out, "| Ticket | Estado | Título | Casos |")?;
+    writeln!(out, "|--------|--------|--------|-------|")?;
+    for (key, ids) in &rows {
+        let Some(ticket) = tickets.get(key) else {
+            continue;
+        };
+        writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            table_cell(key),
+            ticket.status_label(),
+            table_cell(&ticket.title),
+            ids.join(", ")
+        )?;
+    }
+
+    let rerun: Vec<(&TestCase, Vec<&str>)> = test_cases
+        .iter()
+        .filter_map(|tc| {
+            let closed: Vec<&str> = tickets
+                .closed_blockers(tc)
+                .iter()
+                .map(|t| t.key.as_str())
+                .collect();
+            (!closed.is_empty()).then_some((tc, closed))
+        })
+        .collect();
+
+    if !rerun.is_empty() {
+        writeln!(out, "\n### ⚠️ Casos bloqueados con el ticket cerrado\n")?;
+        writeln!(
+            out,
+            "Estos casos se bloquearon por un ticket que ya está cerrado y pueden volver a ejecutarse.\n"
+        )?;
+        for (test_case, closed) in rerun {
+            writeln!(
+                out,
+                "- **{}** - {} ({})",
+                test_case.id,
+                test_case.description,
+                closed.join(", ")
+            )?;
+        }
+    }
+
+    Ok(())
+}
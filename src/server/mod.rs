@@ -0,0 +1,99 @@
+//! API HTTP/JSON local para consultar definiciones y registrar ejecuciones
+
+pub mod routes;
+
+pub use routes::{handle, ApiResponse, ApiState};
+
+use colored::*;
+use std::io;
+use tiny_http::{Header, Response, Server};
+
+use crate::config::Config;
+use crate::storage::open_storage_with;
+
+/// Dirección por defecto del servidor
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+/// Atiende peticiones HTTP en la dirección indicada hasta que se detenga el proceso
+///
+/// Usa el mismo almacenamiento que la CLI, así que los cambios hechos por la API
+/// se ven en `definitions/` y `executions/` (o en la base de datos SQLite).
+///
+/// Las peticiones de un navegador (con cabecera `Origin`) solo se atienden si
+/// su origen está en `allowed_origins` de la sección `[server]`; así una página
+/// cualquiera abierta en el navegador no puede modificar las ejecuciones.
+pub fn serve(address: &str) -> io::Result<()> {
+    let config = Config::load()?;
+    let allowed_origins = config.server.allowed_origins.clone();
+    let state = ApiState::new(open_storage_with(&config.storage)?, &config);
+    let server = Server::http(address).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::AddrNotAvailable,
+            format!("No se pudo escuchar en {}: {}", address, e),
+        )
+    })?;
+
+    println!(
+        "{}",
+        format!("API escuchando en http://{} (Ctrl-C para detener)", address).green()
+    );
+
+    for mut request in server.incoming_requests() {
+        let origin = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("Origin"))
+            .map(|h| h.value.to_string());
+        let allowed = origin
+            .as_ref()
+            .is_some_and(|origin| allowed_origins.contains(origin));
+
+        let mut body = String::new();
+        let response = if origin.is_some() && !allowed {
+            ApiResponse::error(
+                403,
+                &format!(
+                    "Origen no permitido: {}. Añádalo a allowed_origins en [server]",
+                    origin.as_deref().unwrap_or_default()
+                ),
+            )
+        } else {
+            match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => handle(&state, request.method().as_str(), request.url(), &body),
+                Err(e) => ApiResponse::error(400, &format!("Cuerpo inválido: {}", e)),
+            }
+        };
+
+        println!(
+            "{} {} -> {}",
+            request.method(),
+            request.url(),
+            response.status
+        );
+
+        let mut http_response = Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(header("Content-Type", response.content_type))
+            .with_header(header("Vary", "Origin"));
+
+        if let (Some(origin), true) = (&origin, allowed) {
+            http_response = http_response
+                .with_header(header("Access-Control-Allow-Origin", origin))
+                .with_header(header(
+                    "Access-Control-Allow-Methods",
+                    "GET, POST, PUT, OPTIONS",
+                ))
+                .with_header(header("Access-Control-Allow-Headers", "Content-Type"));
+        }
+
+        if let Err(e) = request.respond(http_response) {
+            eprintln!("{}", format!("Error al responder: {}", e).red());
+        }
+    }
+
+    Ok(())
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("cabecera HTTP válida")
+}
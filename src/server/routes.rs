@@ -0,0 +1,620 @@
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io;
+
+use crate::analysis::{flaky_cases, FlakyScore, History};
+use crate::config::{Config, FlakyConfig};
+use crate::engine::{base_name_of, file_stem, result_from_json, results_from_json, Execution};
+use crate::integrations::TicketCache;
+use crate::models::{Priority, TestCase, TestStatus, TestStep};
+use crate::report::html::write_html;
+use crate::report::markdown::{write_execution_markdown, write_markdown};
+use crate::report::StatusSummary;
+use crate::storage::Storage;
+
+/// Estado de la API que se conserva entre peticiones
+///
+/// La configuración del análisis de casos inestables se lee al crearlo. El
+/// historial de cada definición se carga la primera vez que se necesita y
+/// después solo se actualiza con las ejecuciones nuevas y las que modifica la
+/// API, en lugar de releer todas las ejecuciones en cada petición.
+pub struct ApiState {
+    storage: Box<dyn Storage>,
+    flaky: FlakyConfig,
+    histories: RefCell<HashMap<String, History>>,
+}
+
+impl ApiState {
+    pub fn new(storage: Box<dyn Storage>, config: &Config) -> Self {
+        ApiState {
+            storage,
+            flaky: config.flaky,
+            histories: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Almacenamiento de las definiciones y ejecuciones
+    pub fn storage(&self) -> &dyn Storage {
+        self.storage.as_ref()
+    }
+
+    /// Casos inestables de la definición de una ejecución, contando su contenido actual
+    fn flaky_cases(&self, reference: &str, test_cases: &[TestCase]) -> io::Result<Vec<FlakyScore>> {
+        let definition = base_name_of(reference);
+        let mut histories = self.histories.borrow_mut();
+        let history = histories
+            .entry(definition)
+            .or_insert_with_key(|definition| History::new(definition));
+
+        history.refresh(self.storage())?;
+        history.record(reference, test_cases);
+
+        Ok(flaky_cases(history, &self.flaky))
+    }
+
+    /// Guarda una ejecución con el mismo informe Markdown que `Execution::save`
+    fn save(&self, execution: &Execution) -> io::Result<()> {
+        let reference = execution.reference(self.storage());
+        self.storage.save(&reference, &execution.test_cases)?;
+
+        // El análisis es complementario: si falla, el informe se guarda sin él
+        let flaky = self
+            .flaky_cases(&reference, &execution.test_cases)
+            .unwrap_or_default();
+        let tickets = TicketCache::load().unwrap_or_default();
+
+        execution.save_report(&flaky, &tickets)
+    }
+}
+
+/// Respuesta de la API, independiente del servidor HTTP
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl ApiResponse {
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_string_pretty(value) {
+            Ok(body) => ApiResponse {
+                status,
+                content_type: "application/json; charset=utf-8",
+                body,
+            },
+            Err(e) => ApiResponse::error(500, &e.to_string()),
+        }
+    }
+
+    fn text(content_type: &'static str, body: Vec<u8>) -> Self {
+        ApiResponse {
+            status: 200,
+            content_type,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }
+    }
+
+    /// Respuesta de error con el cuerpo `{"error": "..."}`
+    pub fn error(status: u16, message: &str) -> Self {
+        ApiResponse::json(status, &ErrorBody { error: message })
+    }
+
+    fn from_io_error(e: io::Error) -> Self {
+        let status = match e.kind() {
+            io::ErrorKind::NotFound => 404,
+            io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => 400,
+            _ => 500,
+        };
+        ApiResponse::error(status, &e.to_string())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+/// Definición o ejecución en los listados
+#[derive(Serialize)]
+struct Entry {
+    name: String,
+    reference: String,
+    /// Definición de la que proviene una ejecución
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definition: Option<String>,
+}
+
+/// Definición o ejecución con sus casos
+#[derive(Serialize)]
+struct Document<'a> {
+    name: &'a str,
+    reference: &'a str,
+    summary: StatusSummary,
+    test_cases: Vec<CaseView<'a>>,
+}
+
+/// Caso de prueba en JSON, con los pasos y las etiquetas como listas
+#[derive(Serialize)]
+struct CaseView<'a> {
+    id: &'a str,
+    description: &'a str,
+    status: TestStatus,
+    observations: &'a str,
+    evidence: &'a str,
+    version: &'a str,
+    ticket_numbers: &'a str,
+    preconditions: &'a str,
+    test_data: &'a str,
+    steps: &'a [TestStep],
+    priority: Option<Priority>,
+    component: &'a str,
+    tags: &'a [String],
+    issue_key: &'a str,
+}
+
+impl<'a> From<&'a TestCase> for CaseView<'a> {
+    fn from(tc: &'a TestCase) -> Self {
+        CaseView {
+            id: &tc.id,
+            description: &tc.description,
+            status: tc.status,
+            observations: &tc.observations,
+            evidence: &tc.evidence,
+            version: &tc.version,
+            ticket_numbers: &tc.ticket_numbers,
+            preconditions: &tc.preconditions,
+            test_data: &tc.test_data,
+            steps: &tc.steps,
+            priority: tc.priority,
+            component: &tc.component,
+            tags: &tc.tags,
+            issue_key: &tc.issue_key,
+        }
+    }
+}
+
+/// Cuerpo de `POST /executions`
+#[derive(Deserialize)]
+struct StartExecution {
+    definition: String,
+}
+
+/// Tipo de recurso de la ruta
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Definition,
+    Execution,
+}
+
+/// Atiende una petición de la API
+///
+/// | Método | Ruta | Descripción |
+/// |--------|------|-------------|
+/// | GET | `/definitions` | Lista las definiciones |
+/// | GET | `/definitions/{nombre}` | Casos de una definición |
+/// | GET | `/executions` | Lista las ejecuciones, las más recientes primero |
+/// | POST | `/executions` | Inicia una ejecución: `{"definition": "smoke-login"}` |
+/// | GET | `/executions/{nombre}` | Casos de una ejecución |
+/// | PUT | `/executions/{nombre}/cases/{id}` | Registra el resultado de un caso |
+/// | POST | `/executions/{nombre}/results` | Registra una lista de resultados |
+/// | GET | `/{definitions,executions}/{nombre}/report.md` | Informe Markdown |
+/// | GET | `/{definitions,executions}/{nombre}/report.html` | Informe HTML |
+pub fn handle(state: &ApiState, method: &str, url: &str, body: &str) -> ApiResponse {
+    let storage = state.storage();
+    let path = url.split('?').next().unwrap_or_default();
+
+    // Los nombres e IDs llegan codificados como en una URL (`TC%201`)
+    let decoded: Result<Vec<String>, _> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| percent_decode_str(s).decode_utf8().map(|s| s.into_owned()))
+        .collect();
+    let Ok(decoded) = decoded else {
+        return ApiResponse::error(400, &format!("Ruta con caracteres inválidos: {}", path));
+    };
+    let segments: Vec<&str> = decoded.iter().map(String::as_str).collect();
+
+    let result = match (method, segments.as_slice()) {
+        ("OPTIONS", _) => Ok(ApiResponse {
+            status: 204,
+            content_type: "text/plain",
+            body: String::new(),
+        }),
+        ("GET", ["definitions"]) => list(storage, Kind::Definition),
+        ("GET", ["executions"]) => list(storage, Kind::Execution),
+        ("POST", ["executions"]) => start_execution(state, body),
+        ("GET", ["definitions", name]) => document(storage, Kind::Definition, name),
+        ("GET", ["executions", name]) => document(storage, Kind::Execution, name),
+        ("GET", ["definitions", name, report]) => {
+            rendered_report(state, Kind::Definition, name, report)
+        }
+        ("GET", ["executions", name, report]) => {
+            rendered_report(state, Kind::Execution, name, report)
+        }
+        ("PUT", ["executions", name, "cases", id]) => update_case(state, name, id, body),
+        ("POST", ["executions", name, "results"]) => update_results(state, name, body),
+        _ => Ok(ApiResponse::error(
+            404,
+            &format!("Ruta no encontrada: {} {}", method, path),
+        )),
+    };
+
+    result.unwrap_or_else(ApiResponse::from_io_error)
+}
+
+fn list(storage: &dyn Storage, kind: Kind) -> io::Result<ApiResponse> {
+    let entries: Vec<Entry> = references(storage, kind)?
+        .into_iter()
+        .map(|reference| Entry {
+            name: file_stem(&reference),
+            definition: (kind == Kind::Execution).then(|| base_name_of(&reference)),
+            reference,
+        })
+        .collect();
+
+    Ok(ApiResponse::json(200, &entries))
+}
+
+fn document(storage: &dyn Storage, kind: Kind, name: &str) -> io::Result<ApiResponse> {
+    let reference = find_reference(storage, kind, name)?;
+    let test_cases = storage.load(&reference)?;
+
+    Ok(ApiResponse::json(
+        200,
+        &document_body(name, &reference, &test_cases),
+    ))
+}
+
+/// Informe de una definición o ejecución; el Markdown de una ejecución es el
+/// mismo que se guarda junto a ella, con los casos inestables y los tickets
+fn rendered_report(
+    state: &ApiState,
+    kind: Kind,
+    name: &str,
+    report: &str,
+) -> io::Result<ApiResponse> {
+    let reference = find_reference(state.storage(), kind, name)?;
+    let test_cases = state.storage().load(&reference)?;
+    let mut out = Vec::new();
+
+    match report {
+        "report.md" if kind == Kind::Execution => {
+            let flaky = state
+                .flaky_cases(&reference, &test_cases)
+                .unwrap_or_default();
+            let tickets = TicketCache::load().unwrap_or_default();
+            write_execution_markdown(&mut out, &test_cases, name, &flaky, &tickets)?;
+            Ok(ApiResponse::text("text/markdown; charset=utf-8", out))
+        }
+        "report.md" => {
+            write_markdown(&mut out, &test_cases, name)?;
+            Ok(ApiResponse::text("text/markdown; charset=utf-8", out))
+        }
+        "report.html" => {
+            write_html(&mut out, &test_cases, name)?;
+            Ok(ApiResponse::text("text/html; charset=utf-8", out))
+        }
+        _ => Ok(ApiResponse::error(
+            404,
+            &format!(
+                "Informe desconocido '{}'. Use report.md o report.html",
+                report
+            ),
+        )),
+    }
+}
+
+/// Crea una ejecución pendiente a partir de una definición y la guarda
+fn start_execution(state: &ApiState, body: &str) -> io::Result<ApiResponse> {
+    let storage = state.storage();
+    let request: StartExecution = serde_json::from_str(body).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Cuerpo inválido: {}", e),
+        )
+    })?;
+
+    let definition = find_reference(storage, Kind::Definition, &file_stem(&request.definition))?;
+    let execution = Execution::from_definition(storage, &definition)?;
+    state.save(&execution)?;
+
+    let reference = execution.reference(storage);
+    Ok(ApiResponse::json(
+        201,
+        &document_body(&execution.name, &reference, &execution.test_cases),
+    ))
+}
+
+fn update_case(state: &ApiState, name: &str, id: &str, body: &str) -> io::Result<ApiResponse> {
+    let result = result_from_json(id, body)?;
+    let mut execution = load_execution(state.storage(), name)?;
+
+    execution.apply_results(&[result])?;
+    state.save(&execution)?;
+
+    let test_case = execution.case_mut(id)?;
+    Ok(ApiResponse::json(200, &CaseView::from(&*test_case)))
+}
+
+fn update_results(state: &ApiState, name: &str, body: &str) -> io::Result<ApiResponse> {
+    let results = results_from_json(body)?;
+    let mut execution = load_execution(state.storage(), name)?;
+
+    execution.apply_results(&results)?;
+    state.save(&execution)?;
+
+    let reference = execution.reference(state.storage());
+    Ok(ApiResponse::json(
+        200,
+        &document_body(&execution.name, &reference, &execution.test_cases),
+    ))
+}
+
+/// Carga una ejecución que se puede modificar
+///
+/// Las ejecuciones antiguas de `tests/` son de solo lectura: al guardarlas se
+/// escribirían en `executions/` y quedarían duplicadas.
+fn load_execution(storage: &dyn Storage, name: &str) -> io::Result<Execution> {
+    let reference = find_reference(storage, Kind::Execution, name)?;
+    let execution = Execution::load(storage, &reference)?;
+
+    if execution.reference(storage) != reference {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "La ejecución {} es de solo lectura; solo se modifican las de executions/",
+                reference
+            ),
+        ));
+    }
+
+    Ok(execution)
+}
+
+fn document_body<'a>(
+    name: &'a str,
+    reference: &'a str,
+    test_cases: &'a [TestCase],
+) -> Document<'a> {
+    Document {
+        name,
+        reference,
+        summary: StatusSummary::from_cases(test_cases),
+        test_cases: test_cases.iter().map(CaseView::from).collect(),
+    }
+}
+
+fn references(storage: &dyn Storage, kind: Kind) -> io::Result<Vec<String>> {
+    match kind {
+        Kind::Definition => storage.list_definitions(),
+        Kind::Execution => storage.list_executions(),
+    }
+}
+
+/// Busca la referencia de una definición o ejecución por su nombre
+fn find_reference(storage: &dyn Storage, kind: Kind, name: &str) -> io::Result<String> {
+    references(storage, kind)?
+        .into_iter()
+        .find(|reference| file_stem(reference) == name)
+        .ok_or_else(|| {
+            let what = match kind {
+                Kind::Definition => "la definición",
+                Kind::Execution => "la ejecución",
+            };
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No existe {} '{}'", what, name),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::TicketInfo;
+    use crate::storage::{ensure_directories, CsvStorage};
+    use crate::test_support::TempWorkingDir;
+    use serde_json::Value;
+    use std::fs;
+    use std::path::Path;
+
+    /// Crea la definición `smoke-login` con un caso de ID `TC 1`
+    fn setup() -> (TempWorkingDir, ApiState) {
+        let dir = TempWorkingDir::new();
+        ensure_directories().unwrap();
+
+        let storage = CsvStorage;
+        let mut test_case = TestCase::new("El login acepta credenciales", "1.0", "QA-1");
+        test_case.id = String::from("TC 1");
+        storage
+            .save(&storage.definition_ref("smoke-login"), &[test_case])
+            .unwrap();
+
+        (dir, ApiState::new(Box::new(storage), &Config::default()))
+    }
+
+    fn json(response: &ApiResponse) -> Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    fn start(state: &ApiState) -> String {
+        let response = handle(
+            state,
+            "POST",
+            "/executions",
+            r#"{"definition": "smoke-login"}"#,
+        );
+        assert_eq!(response.status, 201, "{}", response.body);
+        json(&response)["name"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn lists_definitions() {
+        let (_dir, state) = setup();
+
+        let response = handle(&state, "GET", "/definitions", "");
+
+        assert_eq!(response.status, 200);
+        let body = json(&response);
+        assert_eq!(body[0]["name"], "smoke-login");
+        assert_eq!(body[0]["reference"], "definitions/smoke-login.csv");
+    }
+
+    #[test]
+    fn starts_an_execution_from_a_definition() {
+        let (_dir, state) = setup();
+
+        let name = start(&state);
+
+        assert!(name.starts_with("smoke-login-"));
+        let listed = json(&handle(&state, "GET", "/executions", ""));
+        assert_eq!(listed[0]["name"], name.as_str());
+        assert_eq!(listed[0]["definition"], "smoke-login");
+        assert!(Path::new(&format!("executions/{}.md", name)).exists());
+    }
+
+    #[test]
+    fn updates_a_case_with_an_encoded_id() {
+        let (_dir, state) = setup();
+        let storage = state.storage();
+        let name = start(&state);
+
+        let response = handle(
+            &state,
+            "PUT",
+            &format!("/executions/{}/cases/TC%201", name),
+            r#"{"status": "Rejected", "observations": "Error 500"}"#,
+        );
+
+        assert_eq!(response.status, 200, "{}", response.body);
+        assert_eq!(json(&response)["status"], "Rejected");
+        let saved = storage
+            .load(&storage.execution_ref(&name))
+            .unwrap()
+            .remove(0);
+        assert_eq!(saved.status, TestStatus::Rejected);
+        assert_eq!(saved.observations, "Error 500");
+    }
+
+    #[test]
+    fn decodes_non_ascii_names() {
+        let (_dir, state) = setup();
+        let storage = state.storage();
+        storage
+            .save(
+                &storage.definition_ref("señal"),
+                &[TestCase::new("Caso", "1.0", "")],
+            )
+            .unwrap();
+
+        let response = handle(&state, "GET", "/definitions/se%C3%B1al", "");
+
+        assert_eq!(response.status, 200, "{}", response.body);
+        assert_eq!(json(&response)["name"], "señal");
+    }
+
+    #[test]
+    fn unknown_routes_and_names_return_404() {
+        let (_dir, state) = setup();
+
+        assert_eq!(handle(&state, "GET", "/nada", "").status, 404);
+        assert_eq!(handle(&state, "GET", "/definitions/nada", "").status, 404);
+        assert_eq!(
+            handle(&state, "GET", "/definitions/smoke-login/report.pdf", "").status,
+            404
+        );
+
+        let name = start(&state);
+        let response = handle(
+            &state,
+            "PUT",
+            &format!("/executions/{}/cases/TC-9", name),
+            r#"{"status": "Validated"}"#,
+        );
+        assert_eq!(response.status, 404);
+        assert!(json(&response)["error"].as_str().unwrap().contains("TC-9"));
+    }
+
+    #[test]
+    fn invalid_bodies_return_400() {
+        let (_dir, state) = setup();
+
+        assert_eq!(handle(&state, "POST", "/executions", "{").status, 400);
+
+        let name = start(&state);
+        let response = handle(
+            &state,
+            "PUT",
+            &format!("/executions/{}/cases/TC%201", name),
+            r#"{"status": "Quizás"}"#,
+        );
+        assert_eq!(response.status, 400);
+        assert_eq!(handle(&state, "GET", "/definitions/%FF", "").status, 400);
+    }
+
+    /// Informe sin la línea de la fecha, que cambia entre una escritura y otra
+    fn without_date(report: &str) -> String {
+        report
+            .lines()
+            .filter(|line| !line.starts_with("Fecha de ejecución"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn execution_reports_match_the_saved_markdown() {
+        let (_dir, state) = setup();
+        let storage = state.storage();
+        let definition = storage
+            .load(&storage.definition_ref("smoke-login"))
+            .unwrap();
+        for (name, status) in [
+            ("smoke-login-20240101_100000", TestStatus::Validated),
+            ("smoke-login-20240102_100000", TestStatus::Rejected),
+        ] {
+            let mut test_cases = definition.clone();
+            test_cases[0].status = status;
+            storage
+                .save(&storage.execution_ref(name), &test_cases)
+                .unwrap();
+        }
+        let mut tickets = TicketCache::default();
+        tickets.insert(TicketInfo {
+            key: "QA-1".to_string(),
+            title: "Login con SSO".to_string(),
+            state: "open".to_string(),
+            closed: false,
+            synced_at: "2024-01-02 10:00:00".to_string(),
+        });
+        tickets.save().unwrap();
+
+        let name = start(&state);
+        let url = format!("/executions/{}/report.md", name);
+        let pending = handle(&state, "GET", &url, "");
+        assert_eq!(pending.status, 200);
+        assert!(pending.body.contains("## Tickets"));
+        assert!(pending.body.contains("Login con SSO"));
+        assert!(!pending.body.contains("## Casos inestables"));
+
+        // Al validarlo de nuevo el caso alterna entre ✅ y ❌ y pasa a ser inestable
+        let response = handle(
+            &state,
+            "PUT",
+            &format!("/executions/{}/cases/TC%201", name),
+            r#"{"status": "Validated"}"#,
+        );
+        assert_eq!(response.status, 200, "{}", response.body);
+
+        let served = handle(&state, "GET", &url, "").body;
+        let saved = fs::read_to_string(format!("executions/{}.md", name)).unwrap();
+        assert!(served.contains("## Casos inestables (flaky)"));
+        assert_eq!(without_date(&served), without_date(&saved));
+
+        // Las definiciones no tienen historial ni secciones de ejecución
+        let report = handle(&state, "GET", "/definitions/smoke-login/report.md", "");
+        assert!(!report.body.contains("## Tickets"));
+    }
+}
@@ -0,0 +1,524 @@
+use csv::{ReaderBuilder, StringRecord, Writer};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::models::{generate_id, parse_tags, Priority, TestCase, TestStatus, TestStep};
+use crate::storage::{
+    definition_path, get_definition_files, get_execution_files, Storage, EXECUTIONS_DIR,
+};
+
+/// Versión actual del esquema de los archivos CSV
+///
+/// - 1: `id, description, status, observations, evidence`
+/// - 2: añade `version` y `ticket_numbers`
+/// - 3: añade `preconditions`, `test_data` y `steps`
+/// - 4: añade `priority`, `component` y `tags`
+/// - 5: añade `issue_key`
+pub const SCHEMA_VERSION: u32 = 5;
+
+/// Prefijo de la primera línea de los archivos CSV que declara la versión del esquema
+pub const SCHEMA_HEADER_PREFIX: &str = "#schema_version=";
+
+/// Almacenamiento en archivos CSV dentro de `definitions/` y `executions/`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvStorage;
+
+impl Storage for CsvStorage {
+    fn list_definitions(&self) -> io::Result<Vec<String>> {
+        get_definition_files()
+    }
+
+    fn list_executions(&self) -> io::Result<Vec<String>> {
+        get_execution_files()
+    }
+
+    fn definition_ref(&self, base_name: &str) -> String {
+        definition_path(base_name)
+    }
+
+    fn execution_ref(&self, execution_name: &str) -> String {
+        format!("{}/{}.csv", EXECUTIONS_DIR, execution_name)
+    }
+
+    fn exists(&self, reference: &str) -> io::Result<bool> {
+        Ok(Path::new(reference).exists())
+    }
+
+    fn load(&self, reference: &str) -> io::Result<Vec<TestCase>> {
+        load_from_csv(reference)
+    }
+
+    fn load_with_warnings(&self, reference: &str) -> io::Result<(Vec<TestCase>, Vec<String>)> {
+        let document = read_csv_document(reference)?;
+        Ok((document.test_cases, document.warnings))
+    }
+
+    fn save(&self, reference: &str, test_cases: &[TestCase]) -> io::Result<()> {
+        save_to_csv(reference, test_cases)
+    }
+}
+
+/// Contenido de un archivo CSV de casos de prueba junto con la información de su esquema
+#[derive(Debug, Clone)]
+pub struct CsvDocument {
+    /// Versión del esquema, declarada en la cabecera o deducida de las columnas
+    pub schema_version: u32,
+    /// Indica si el archivo declara la versión en su primera línea
+    pub declared: bool,
+    /// Columnas conocidas que no están en el archivo y se completaron con valores por defecto
+    pub missing_columns: Vec<String>,
+    pub test_cases: Vec<TestCase>,
+    /// Problemas encontrados en filas concretas
+    pub warnings: Vec<String>,
+}
+
+impl CsvDocument {
+    /// Indica si el archivo debe actualizarse al esquema actual
+    pub fn needs_migration(&self) -> bool {
+        !self.declared || self.schema_version < SCHEMA_VERSION
+    }
+}
+
+/// Valores usados para completar columnas que faltan al migrar archivos antiguos
+#[derive(Debug, Clone, Default)]
+pub struct MigrationDefaults {
+    pub version: Option<String>,
+    pub ticket_numbers: Option<String>,
+}
+
+impl MigrationDefaults {
+    /// Completa la versión y los tickets de los casos que no los tienen
+    pub fn apply(&self, test_cases: &mut [TestCase]) {
+        for test_case in test_cases.iter_mut() {
+            if let Some(version) = &self.version {
+                if test_case.version.is_empty() {
+                    test_case.version = version.clone();
+                }
+            }
+            if let Some(ticket_numbers) = &self.ticket_numbers {
+                if test_case.ticket_numbers.is_empty() {
+                    test_case.ticket_numbers = ticket_numbers.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Lee un archivo CSV de casos de prueba, tolerando columnas ausentes
+pub fn read_csv_document(file_path: &str) -> io::Result<CsvDocument> {
+    // Verificar si el archivo existe
+    if !Path::new(file_path).exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("El archivo {} no existe", file_path),
+        ));
+    }
+
+    let content = fs::read_to_string(file_path)?;
+
+    // La primera línea puede declarar la versión del esquema
+    let (first_line, rest) = content.split_once('\n').unwrap_or((&content, ""));
+
+    let declared_version = first_line
+        .trim()
+        .strip_prefix(SCHEMA_HEADER_PREFIX)
+        .map(|v| {
+            v.trim().parse::<u32>().map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Versión de esquema inválida en {}: {}", file_path, v),
+                )
+            })
+        })
+        .transpose()?;
+
+    // Si la primera línea no es la cabecera de esquema, forma parte del CSV
+    let csv_content = if declared_version.is_some() {
+        rest
+    } else {
+        content.as_str()
+    };
+
+    let repaired = repair_quotes(csv_content);
+    let mut csv_reader = ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .from_reader(repaired.as_bytes());
+
+    let headers = csv_reader.headers()?.clone();
+    let columns = Columns::new(&headers);
+
+    let missing_columns: Vec<String> = KNOWN_COLUMNS
+        .iter()
+        .filter(|c| columns.index(c).is_none())
+        .map(|c| c.to_string())
+        .collect();
+
+    if columns.index("description").is_none() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "El archivo {} no tiene la columna 'description'. Columnas encontradas: {}",
+                file_path,
+                headers.iter().collect::<Vec<_>>().join(", ")
+            ),
+        ));
+    }
+
+    let mut test_cases = Vec::new();
+    let mut warnings = Vec::new();
+
+    for (i, result) in csv_reader.records().enumerate() {
+        // La fila 1 es la cabecera de columnas
+        let row = i + 2;
+
+        match result {
+            Ok(record) => test_cases.push(columns.test_case(&record, row, &mut warnings)),
+            Err(e) => warnings.push(format!("Fila {}: {}", row, e)),
+        }
+    }
+
+    Ok(CsvDocument {
+        schema_version: declared_version.unwrap_or_else(|| detect_schema_version(&columns)),
+        declared: declared_version.is_some(),
+        missing_columns,
+        test_cases,
+        warnings,
+    })
+}
+
+/// Carga casos de prueba desde un archivo CSV
+///
+/// Los problemas de filas concretas se descartan; [`read_csv_document`] los devuelve.
+pub fn load_from_csv(file_path: &str) -> io::Result<Vec<TestCase>> {
+    Ok(read_csv_document(file_path)?.test_cases)
+}
+
+/// Guarda casos de prueba en un archivo CSV
+pub fn save_to_csv(file_path: &str, test_cases: &[TestCase]) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+    writeln!(file, "{}{}", SCHEMA_HEADER_PREFIX, SCHEMA_VERSION)?;
+
+    let mut writer = Writer::from_writer(file);
+
+    for test_case in test_cases {
+        writer.serialize(test_case)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Actualiza un archivo CSV al esquema actual, guardando una copia del original
+///
+/// Devuelve la ruta de la copia, o `None` si el archivo ya estaba actualizado.
+pub fn migrate_csv(file_path: &str, defaults: &MigrationDefaults) -> io::Result<Option<String>> {
+    let mut document = read_csv_document(file_path)?;
+
+    if !document.needs_migration() {
+        return Ok(None);
+    }
+
+    if !document.warnings.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "No se migró {} porque hay filas que no se pudieron leer:\n{}",
+                file_path,
+                document.warnings.join("\n")
+            ),
+        ));
+    }
+
+    defaults.apply(&mut document.test_cases);
+
+    let backup_path = format!("{}.v{}.bak", file_path, document.schema_version);
+    fs::copy(file_path, &backup_path)?;
+    save_to_csv(file_path, &document.test_cases)?;
+
+    Ok(Some(backup_path))
+}
+
+/// Columnas que conoce el esquema actual
+const KNOWN_COLUMNS: [&str; 14] = [
+    "id",
+    "description",
+    "status",
+    "observations",
+    "evidence",
+    "version",
+    "ticket_numbers",
+    "preconditions",
+    "test_data",
+    "steps",
+    "priority",
+    "component",
+    "tags",
+    "issue_key",
+];
+
+/// Escapa las comillas sueltas dentro de campos entrecomillados
+///
+/// Algunos archivos antiguos se escribieron a mano con comillas sin duplicar,
+/// como `"Al presionar "Recibe" ..."`. Dentro de un campo entrecomillado, una
+/// comilla que no va seguida de otra comilla, de una coma o de un fin de línea
+/// se interpreta como literal. Los archivos bien formados no cambian.
+fn repair_quotes(content: &str) -> String {
+    let mut repaired = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_quotes = false;
+    let mut at_field_start = true;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if at_field_start && !in_quotes => {
+                in_quotes = true;
+                repaired.push(c);
+            }
+            '"' if in_quotes => match chars.peek() {
+                Some('"') => {
+                    repaired.push_str("\"\"");
+                    chars.next();
+                }
+                Some(',') | Some('\r') | Some('\n') | None => {
+                    in_quotes = false;
+                    repaired.push(c);
+                }
+                Some(_) => repaired.push_str("\"\""),
+            },
+            _ => repaired.push(c),
+        }
+
+        at_field_start = !in_quotes && matches!(c, ',' | '\n');
+    }
+
+    repaired
+}
+
+/// Deduce la versión del esquema de un archivo sin cabecera de versión
+fn detect_schema_version(columns: &Columns) -> u32 {
+    if columns.index("issue_key").is_some() {
+        5
+    } else if columns.index("priority").is_some() || columns.index("tags").is_some() {
+        4
+    } else if columns.index("steps").is_some() {
+        3
+    } else if columns.index("version").is_some() && columns.index("ticket_numbers").is_some() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Posición de las columnas en un archivo concreto
+struct Columns {
+    names: Vec<String>,
+}
+
+impl Columns {
+    fn new(headers: &StringRecord) -> Self {
+        Columns {
+            names: headers.iter().map(|h| h.trim().to_lowercase()).collect(),
+        }
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn get(&self, record: &StringRecord, name: &str) -> String {
+        self.index(name)
+            .and_then(|i| record.get(i))
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Construye un caso de prueba a partir de una fila, usando valores por defecto
+    /// para las columnas ausentes o inválidas
+    fn test_case(&self, record: &StringRecord, row: usize, warnings: &mut Vec<String>) -> TestCase {
+        let mut id = self.get(record, "id");
+        if id.trim().is_empty() {
+            id = generate_id();
+            // Solo es un problema si la columna existe pero la celda está vacía
+            if self.index("id").is_some() {
+                warnings.push(format!("Fila {}: sin ID, se generó '{}'", row, id));
+            }
+        }
+
+        let raw_status = self.get(record, "status");
+        let status = if raw_status.trim().is_empty() {
+            TestStatus::Pending
+        } else {
+            raw_status.parse::<TestStatus>().unwrap_or_else(|e| {
+                warnings.push(format!("Fila {}: {}. Se usa Pending", row, e));
+                TestStatus::Pending
+            })
+        };
+
+        let raw_steps = self.get(record, "steps");
+        let steps = if raw_steps.trim().is_empty() {
+            Vec::new()
+        } else {
+            serde_json::from_str::<Vec<TestStep>>(&raw_steps).unwrap_or_else(|e| {
+                warnings.push(format!("Fila {}: pasos inválidos ({}). Se ignoran", row, e));
+                Vec::new()
+            })
+        };
+
+        let raw_priority = self.get(record, "priority");
+        let priority = if raw_priority.trim().is_empty() {
+            None
+        } else {
+            raw_priority
+                .parse::<Priority>()
+                .map(Some)
+                .unwrap_or_else(|e| {
+                    warnings.push(format!("Fila {}: {}. Se deja sin prioridad", row, e));
+                    None
+                })
+        };
+
+        TestCase {
+            id,
+            description: self.get(record, "description"),
+            status,
+            observations: self.get(record, "observations"),
+            evidence: self.get(record, "evidence"),
+            version: self.get(record, "version"),
+            ticket_numbers: self.get(record, "ticket_numbers"),
+            preconditions: self.get(record, "preconditions"),
+            test_data: self.get(record, "test_data"),
+            steps,
+            priority,
+            component: self.get(record, "component"),
+            tags: parse_tags(&self.get(record, "tags")),
+            issue_key: self.get(record, "issue_key"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const UNESCAPED_QUOTES: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/csv/unescaped_quotes_v1.csv"
+    );
+
+    #[test]
+    fn repairs_unescaped_quotes_inside_quoted_fields() {
+        let line =
+            "2,\"Verificar que al presionar \"Recibe\" hay una funcionalidad\",Pending,\"\",\"\"\n";
+
+        assert_eq!(
+            repair_quotes(line),
+            "2,\"Verificar que al presionar \"\"Recibe\"\" hay una funcionalidad\",Pending,\"\",\"\"\n"
+        );
+    }
+
+    #[test]
+    fn leaves_well_formed_csv_unchanged() {
+        let content = concat!(
+            "id,description,observations\r\n",
+            "1,\"Dice \"\"hola\"\", luego sigue\",\"\"\r\n",
+            "2,sin comillas,\"varias\nlíneas, con coma\"\n",
+            "3,\"\"\"Entre comillas\"\"\",\n",
+        );
+
+        assert_eq!(repair_quotes(content), content);
+    }
+
+    #[test]
+    fn reads_legacy_file_with_unescaped_quotes() {
+        let document = read_csv_document(UNESCAPED_QUOTES).unwrap();
+
+        assert_eq!(document.schema_version, 1);
+        assert!(!document.declared);
+        assert!(document.needs_migration());
+        assert!(document.warnings.is_empty());
+        assert!(document.missing_columns.contains(&"version".to_string()));
+        assert_eq!(document.test_cases.len(), 5);
+        assert_eq!(
+            document.test_cases[1].description,
+            "Verificar que al presionar \"Recibe\" hay una funcionalidad"
+        );
+        assert_eq!(document.test_cases[1].status, TestStatus::Pending);
+        assert_eq!(
+            document.test_cases[3].description,
+            "Se muestra la región y dirección del usuario"
+        );
+    }
+
+    #[test]
+    fn saved_fields_with_quotes_commas_and_newlines_are_read_back_unchanged() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("casos.csv");
+        let path = path.to_str().unwrap();
+
+        let mut test_case = TestCase::new("Pulsar \"Aceptar\", luego \"Salir\"", "1.0", "QA-1");
+        test_case.observations = "\"Primera\" línea\nsegunda, con \"comillas\"".to_string();
+        test_case.evidence = "\"".to_string();
+        save_to_csv(path, &[test_case.clone()]).unwrap();
+
+        let document = read_csv_document(path).unwrap();
+
+        assert!(document.declared);
+        assert!(document.warnings.is_empty());
+        assert_eq!(document.test_cases[0].description, test_case.description);
+        assert_eq!(document.test_cases[0].observations, test_case.observations);
+        assert_eq!(document.test_cases[0].evidence, test_case.evidence);
+    }
+
+    #[test]
+    fn returns_row_warnings_instead_of_printing_them() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("casos.csv");
+        let path = path.to_str().unwrap();
+        fs::write(
+            path,
+            "id,description,status\n,Sin ID,Pending\nTC-2,Estado raro,Quizás\n",
+        )
+        .unwrap();
+
+        let (test_cases, warnings) = CsvStorage.load_with_warnings(path).unwrap();
+
+        assert_eq!(test_cases.len(), 2);
+        assert!(!test_cases[0].id.is_empty());
+        assert_eq!(test_cases[1].status, TestStatus::Pending);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("Fila 2: sin ID"));
+        assert!(warnings[1].starts_with("Fila 3:"));
+    }
+
+    #[test]
+    fn migration_keeps_repaired_text_and_backs_up_the_original() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("legacy.csv");
+        let path = path.to_str().unwrap();
+        fs::copy(UNESCAPED_QUOTES, path).unwrap();
+
+        let defaults = MigrationDefaults {
+            version: Some("0.1.0".to_string()),
+            ticket_numbers: None,
+        };
+        let backup = migrate_csv(path, &defaults).unwrap().unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&backup).unwrap(),
+            fs::read_to_string(UNESCAPED_QUOTES).unwrap()
+        );
+        let document = read_csv_document(path).unwrap();
+        assert_eq!(document.schema_version, SCHEMA_VERSION);
+        assert!(!document.needs_migration());
+        assert_eq!(document.test_cases[1].version, "0.1.0");
+        assert_eq!(
+            document.test_cases[1].description,
+            "Verificar que al presionar \"Recibe\" hay una funcionalidad"
+        );
+        assert_eq!(migrate_csv(path, &defaults).unwrap(), None);
+    }
+}
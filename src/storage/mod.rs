@@ -0,0 +1,156 @@
+pub mod csv_storage;
+pub mod sqlite_storage;
+
+pub use csv_storage::*;
+pub use sqlite_storage::SqliteStorage;
+
+use std::fs::{create_dir_all, read_dir};
+use std::io;
+use std::path::Path;
+
+use crate::config::{Config, StorageBackend, StorageConfig};
+use crate::models::TestCase;
+
+/// Directorio donde se guardan las definiciones de casos de prueba
+pub const DEFINITIONS_DIR: &str = "definitions";
+/// Directorio donde se guardan los resultados de las ejecuciones
+pub const EXECUTIONS_DIR: &str = "executions";
+/// Directorio donde se guardan los planes de pruebas
+pub const PLANS_DIR: &str = "plans";
+/// Directorio usado por versiones anteriores para guardar las ejecuciones
+pub const LEGACY_TESTS_DIR: &str = "tests";
+
+/// Almacenamiento de definiciones y ejecuciones
+///
+/// Cada definición o ejecución se identifica por una referencia: la ruta del
+/// archivo CSV en [`CsvStorage`], o `definitions/<nombre>` y `executions/<nombre>`
+/// en [`SqliteStorage`]. En ambos casos las referencias de definiciones empiezan
+/// por `definitions/`.
+pub trait Storage {
+    /// Referencias de las definiciones disponibles, ordenadas por nombre
+    fn list_definitions(&self) -> io::Result<Vec<String>>;
+
+    /// Referencias de las ejecuciones disponibles, las más recientes primero
+    fn list_executions(&self) -> io::Result<Vec<String>>;
+
+    /// Referencia de la definición con el nombre base indicado
+    fn definition_ref(&self, base_name: &str) -> String;
+
+    /// Referencia de la ejecución con el nombre indicado
+    fn execution_ref(&self, execution_name: &str) -> String;
+
+    /// Indica si existe la definición o ejecución
+    fn exists(&self, reference: &str) -> io::Result<bool>;
+
+    /// Carga los casos de prueba de una definición o ejecución
+    fn load(&self, reference: &str) -> io::Result<Vec<TestCase>>;
+
+    /// Carga los casos de prueba junto con los problemas encontrados en filas concretas
+    ///
+    /// Las filas con problemas se cargan igualmente con valores por defecto.
+    fn load_with_warnings(&self, reference: &str) -> io::Result<(Vec<TestCase>, Vec<String>)> {
+        Ok((self.load(reference)?, Vec::new()))
+    }
+
+    /// Guarda los casos de prueba de una definición o ejecución, reemplazando los anteriores
+    fn save(&self, reference: &str, test_cases: &[TestCase]) -> io::Result<()>;
+}
+
+/// Abre el almacenamiento indicado en el archivo de configuración
+pub fn open_storage() -> io::Result<Box<dyn Storage>> {
+    open_storage_with(&Config::load()?.storage)
+}
+
+/// Abre el almacenamiento indicado en una configuración concreta
+pub fn open_storage_with(config: &StorageConfig) -> io::Result<Box<dyn Storage>> {
+    match config.backend {
+        StorageBackend::Csv => Ok(Box::new(CsvStorage)),
+        StorageBackend::Sqlite => Ok(Box::new(SqliteStorage::open(&config.database)?)),
+    }
+}
+
+/// Ruta del informe Markdown que acompaña a una definición o ejecución
+pub fn markdown_path(reference: &str) -> String {
+    report_path(reference, "md")
+}
+
+/// Ruta de un informe con la extensión indicada junto a una definición o ejecución
+pub fn report_path(reference: &str, extension: &str) -> String {
+    format!(
+        "{}.{}",
+        reference.strip_suffix(".csv").unwrap_or(reference),
+        extension
+    )
+}
+
+/// Crea los directorios de trabajo si no existen
+pub fn ensure_directories() -> io::Result<()> {
+    for dir in [LEGACY_TESTS_DIR, DEFINITIONS_DIR, EXECUTIONS_DIR, PLANS_DIR] {
+        create_dir_all(dir)?;
+    }
+
+    Ok(())
+}
+
+/// Ruta del archivo CSV de una definición a partir de su nombre base
+pub fn definition_path(base_name: &str) -> String {
+    format!("{}/{}.csv", DEFINITIONS_DIR, base_name)
+}
+
+/// Obtiene la lista de archivos de definición disponibles
+pub fn get_definition_files() -> io::Result<Vec<String>> {
+    let mut definition_files = csv_files_in(DEFINITIONS_DIR)?;
+
+    // Ordenar por nombre
+    definition_files.sort();
+
+    Ok(definition_files)
+}
+
+/// Obtiene la lista de archivos de ejecución disponibles
+pub fn get_execution_files() -> io::Result<Vec<String>> {
+    let mut execution_files = csv_files_in(EXECUTIONS_DIR)?;
+
+    // Verificar también en el directorio tests (para compatibilidad con versiones anteriores)
+    execution_files.extend(csv_files_in(LEGACY_TESTS_DIR)?);
+
+    // Ordenar por fecha (más recientes primero)
+    execution_files.sort_by(|a, b| b.cmp(a));
+
+    Ok(execution_files)
+}
+
+/// Obtiene la lista de planes de pruebas disponibles, ordenados por nombre
+pub fn get_plan_files() -> io::Result<Vec<String>> {
+    let mut plan_files = files_in(PLANS_DIR, "toml")?;
+    plan_files.sort();
+
+    Ok(plan_files)
+}
+
+/// Lista los archivos CSV de un directorio, o ninguno si el directorio no existe
+pub(crate) fn csv_files_in(dir: &str) -> io::Result<Vec<String>> {
+    files_in(dir, "csv")
+}
+
+/// Lista los archivos con la extensión indicada de un directorio, o ninguno si
+/// el directorio no existe
+fn files_in(dir: &str, extension: &str) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+
+    if !Path::new(dir).exists() {
+        return Ok(files);
+    }
+
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some(extension) {
+            if let Some(path_str) = path.to_str() {
+                files.push(path_str.to_string());
+            }
+        }
+    }
+
+    Ok(files)
+}
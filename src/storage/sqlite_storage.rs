@@ -0,0 +1,592 @@
+use chrono::Local;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use std::io;
+
+use crate::engine::parse_timestamp;
+use crate::models::{parse_tags, Priority, TestCase, TestStatus, TestStep};
+use crate::storage::{Storage, DEFINITIONS_DIR, EXECUTIONS_DIR};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS definitions (
+    name TEXT PRIMARY KEY,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS definition_cases (
+    definition TEXT NOT NULL REFERENCES definitions(name) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    id TEXT NOT NULL,
+    description TEXT NOT NULL,
+    status TEXT NOT NULL,
+    observations TEXT NOT NULL,
+    evidence TEXT NOT NULL,
+    version TEXT NOT NULL,
+    ticket_numbers TEXT NOT NULL,
+    preconditions TEXT NOT NULL,
+    test_data TEXT NOT NULL,
+    steps TEXT NOT NULL,
+    priority TEXT NOT NULL DEFAULT '',
+    component TEXT NOT NULL DEFAULT '',
+    tags TEXT NOT NULL DEFAULT '',
+    issue_key TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (definition, position)
+);
+
+CREATE TABLE IF NOT EXISTS executions (
+    name TEXT PRIMARY KEY,
+    definition TEXT NOT NULL,
+    executed_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS execution_results (
+    execution TEXT NOT NULL REFERENCES executions(name) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    id TEXT NOT NULL,
+    description TEXT NOT NULL,
+    status TEXT NOT NULL,
+    observations TEXT NOT NULL,
+    evidence TEXT NOT NULL,
+    version TEXT NOT NULL,
+    ticket_numbers TEXT NOT NULL,
+    preconditions TEXT NOT NULL,
+    test_data TEXT NOT NULL,
+    steps TEXT NOT NULL,
+    priority TEXT NOT NULL DEFAULT '',
+    component TEXT NOT NULL DEFAULT '',
+    tags TEXT NOT NULL DEFAULT '',
+    issue_key TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (execution, position)
+);
+
+CREATE INDEX IF NOT EXISTS execution_results_case ON execution_results(id);
+CREATE INDEX IF NOT EXISTS executions_definition ON executions(definition);
+";
+
+/// Columnas añadidas después de la primera versión de la base de datos, que se
+/// agregan al abrir bases de datos creadas antes
+const ADDED_COLUMNS: [&str; 4] = ["priority", "component", "tags", "issue_key"];
+
+/// Almacenamiento en una base de datos SQLite embebida
+///
+/// Las definiciones se identifican como `definitions/<nombre>` y las ejecuciones
+/// como `executions/<nombre>`, con los mismos nombres que usan los archivos CSV.
+pub struct SqliteStorage {
+    connection: Connection,
+}
+
+/// Tipo de entrada a la que hace referencia una ruta
+enum Entry<'a> {
+    Definition(&'a str),
+    Execution(&'a str),
+}
+
+impl SqliteStorage {
+    /// Abre (o crea) la base de datos
+    pub fn open(database_path: &str) -> io::Result<Self> {
+        let connection = Connection::open(database_path).map_err(to_io_error)?;
+        connection
+            .execute_batch("PRAGMA foreign_keys = ON;")
+            .and_then(|_| connection.execute_batch(SCHEMA))
+            .map_err(to_io_error)?;
+
+        for table in ["definition_cases", "execution_results"] {
+            add_missing_columns(&connection, table)?;
+        }
+
+        Ok(SqliteStorage { connection })
+    }
+
+    /// Conexión a la base de datos, para consultas que no cubre [`Storage`]
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    fn entry<'a>(&self, reference: &'a str) -> io::Result<Entry<'a>> {
+        // Se aceptan también rutas de archivos CSV para facilitar la importación
+        let reference = reference.strip_suffix(".csv").unwrap_or(reference);
+
+        if let Some(name) = reference.strip_prefix(&format!("{}/", DEFINITIONS_DIR)) {
+            Ok(Entry::Definition(name))
+        } else if let Some(name) = reference.strip_prefix(&format!("{}/", EXECUTIONS_DIR)) {
+            Ok(Entry::Execution(name))
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Referencia inválida '{}'. Use {}/<nombre> o {}/<nombre>",
+                    reference, DEFINITIONS_DIR, EXECUTIONS_DIR
+                ),
+            ))
+        }
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn list_definitions(&self) -> io::Result<Vec<String>> {
+        let names = query_names(
+            &self.connection,
+            "SELECT name FROM definitions ORDER BY name",
+        )?;
+
+        Ok(names.iter().map(|name| self.definition_ref(name)).collect())
+    }
+
+    fn list_executions(&self) -> io::Result<Vec<String>> {
+        let names = query_names(
+            &self.connection,
+            "SELECT name FROM executions ORDER BY executed_at DESC, name DESC",
+        )?;
+
+        Ok(names.iter().map(|name| self.execution_ref(name)).collect())
+    }
+
+    fn definition_ref(&self, base_name: &str) -> String {
+        format!("{}/{}", DEFINITIONS_DIR, base_name)
+    }
+
+    fn execution_ref(&self, execution_name: &str) -> String {
+        format!("{}/{}", EXECUTIONS_DIR, execution_name)
+    }
+
+    fn exists(&self, reference: &str) -> io::Result<bool> {
+        let (sql, name) = match self.entry(reference)? {
+            Entry::Definition(name) => ("SELECT 1 FROM definitions WHERE name = ?1", name),
+            Entry::Execution(name) => ("SELECT 1 FROM executions WHERE name = ?1", name),
+        };
+
+        self.connection
+            .query_row(sql, params![name], |_| Ok(()))
+            .optional()
+            .map(|row| row.is_some())
+            .map_err(to_io_error)
+    }
+
+    fn load(&self, reference: &str) -> io::Result<Vec<TestCase>> {
+        if !self.exists(reference)? {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No existe {} en la base de datos", reference),
+            ));
+        }
+
+        let (table, owner_column, name) = match self.entry(reference)? {
+            Entry::Definition(name) => ("definition_cases", "definition", name),
+            Entry::Execution(name) => ("execution_results", "execution", name),
+        };
+
+        let sql = format!(
+            "SELECT id, description, status, observations, evidence, version, ticket_numbers,
+                    preconditions, test_data, steps, priority, component, tags, issue_key
+             FROM {} WHERE {} = ?1 ORDER BY position",
+            table, owner_column
+        );
+
+        let mut statement = self.connection.prepare(&sql).map_err(to_io_error)?;
+        let rows = statement
+            .query_map(params![name], |row| {
+                Ok((
+                    TestCase {
+                        id: row.get(0)?,
+                        description: row.get(1)?,
+                        status: TestStatus::Pending,
+                        observations: row.get(3)?,
+                        evidence: row.get(4)?,
+                        version: row.get(5)?,
+                        ticket_numbers: row.get(6)?,
+                        preconditions: row.get(7)?,
+                        test_data: row.get(8)?,
+                        steps: Vec::new(),
+                        priority: None,
+                        component: row.get(11)?,
+                        tags: parse_tags(&row.get::<_, String>(12)?),
+                        issue_key: row.get(13)?,
+                    },
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(9)?,
+                    row.get::<_, String>(10)?,
+                ))
+            })
+            .map_err(to_io_error)?;
+
+        let mut test_cases = Vec::new();
+        for row in rows {
+            let (mut test_case, status, steps, priority) = row.map_err(to_io_error)?;
+            test_case.status = status.parse().map_err(invalid_data)?;
+            if !priority.is_empty() {
+                test_case.priority = Some(priority.parse::<Priority>().map_err(invalid_data)?);
+            }
+            if !steps.is_empty() {
+                test_case.steps = serde_json::from_str::<Vec<TestStep>>(&steps)
+                    .map_err(|e| invalid_data(e.to_string()))?;
+            }
+            test_cases.push(test_case);
+        }
+
+        Ok(test_cases)
+    }
+
+    fn save(&self, reference: &str, test_cases: &[TestCase]) -> io::Result<()> {
+        let entry = self.entry(reference)?;
+        let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        // `Storage::save` recibe `&self`; la transacción se abre sin exigir `&mut`
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(to_io_error)?;
+
+        let (table, owner_column, name) = match entry {
+            Entry::Definition(name) => {
+                transaction
+                    .execute(
+                        "INSERT INTO definitions (name, updated_at) VALUES (?1, ?2)
+                         ON CONFLICT(name) DO UPDATE SET updated_at = excluded.updated_at",
+                        params![name, now],
+                    )
+                    .map_err(to_io_error)?;
+                ("definition_cases", "definition", name)
+            }
+            Entry::Execution(name) => {
+                let (definition, executed_at) = split_execution_name(name);
+                transaction
+                    .execute(
+                        "INSERT INTO executions (name, definition, executed_at) VALUES (?1, ?2, ?3)
+                         ON CONFLICT(name) DO NOTHING",
+                        params![name, definition, executed_at.unwrap_or(now)],
+                    )
+                    .map_err(to_io_error)?;
+                ("execution_results", "execution", name)
+            }
+        };
+
+        replace_cases(&transaction, table, owner_column, name, test_cases)?;
+        transaction.commit().map_err(to_io_error)
+    }
+}
+
+/// Reemplaza los casos de una definición o ejecución
+fn replace_cases(
+    transaction: &Transaction,
+    table: &str,
+    owner_column: &str,
+    owner: &str,
+    test_cases: &[TestCase],
+) -> io::Result<()> {
+    transaction
+        .execute(
+            &format!("DELETE FROM {} WHERE {} = ?1", table, owner_column),
+            params![owner],
+        )
+        .map_err(to_io_error)?;
+
+    let mut statement = transaction
+        .prepare(&format!(
+            "INSERT INTO {} ({}, position, id, description, status, observations, evidence,
+                             version, ticket_numbers, preconditions, test_data, steps,
+                             priority, component, tags, issue_key)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+            table, owner_column
+        ))
+        .map_err(to_io_error)?;
+
+    for (position, test_case) in test_cases.iter().enumerate() {
+        let steps = if test_case.steps.is_empty() {
+            String::new()
+        } else {
+            serde_json::to_string(&test_case.steps).map_err(io::Error::other)?
+        };
+
+        statement
+            .execute(params![
+                owner,
+                position as i64,
+                test_case.id,
+                test_case.description,
+                format!("{:?}", test_case.status),
+                test_case.observations,
+                test_case.evidence,
+                test_case.version,
+                test_case.ticket_numbers,
+                test_case.preconditions,
+                test_case.test_data,
+                steps,
+                test_case
+                    .priority
+                    .map(|p| p.to_string())
+                    .unwrap_or_default(),
+                test_case.component,
+                test_case.tags.join(", "),
+                test_case.issue_key,
+            ])
+            .map_err(to_io_error)?;
+    }
+
+    Ok(())
+}
+
+/// Separa el nombre de una ejecución en definición y fecha de ejecución
+fn split_execution_name(name: &str) -> (&str, Option<String>) {
+    // Los nombres tienen la forma <definición>-<AAAAMMDD_HHMMSS>[_N]
+    if let Some((definition, timestamp)) = name.rsplit_once('-') {
+        if let Some(executed_at) = parse_timestamp(timestamp) {
+            return (
+                definition,
+                Some(executed_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+            );
+        }
+    }
+
+    (name, None)
+}
+
+/// Agrega a una tabla de casos las columnas de [`ADDED_COLUMNS`] que le falten
+fn add_missing_columns(connection: &Connection, table: &str) -> io::Result<()> {
+    let existing = query_names(
+        connection,
+        &format!("SELECT name FROM pragma_table_info('{}')", table),
+    )?;
+
+    for column in ADDED_COLUMNS {
+        if !existing.iter().any(|name| name == column) {
+            connection
+                .execute_batch(&format!(
+                    "ALTER TABLE {} ADD COLUMN {} TEXT NOT NULL DEFAULT ''",
+                    table, column
+                ))
+                .map_err(to_io_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn query_names(connection: &Connection, sql: &str) -> io::Result<Vec<String>> {
+    let mut statement = connection.prepare(sql).map_err(to_io_error)?;
+    let rows = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(to_io_error)?;
+
+    rows.collect::<Result<_, _>>().map_err(to_io_error)
+}
+
+fn to_io_error(e: rusqlite::Error) -> io::Error {
+    io::Error::other(format!("Error de SQLite: {}", e))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::import_csv_tree;
+    use crate::storage::{save_to_csv, CsvStorage};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn sample_case(id: &str) -> TestCase {
+        let mut test_case = TestCase::new(&format!("Caso {}", id), "1.2.0", "QA-1, QA-2");
+        test_case.id = id.to_string();
+        test_case.status = TestStatus::Rejected;
+        test_case.observations = "Falla el botón".to_string();
+        test_case.priority = Some(Priority::P1);
+        test_case.component = "Login".to_string();
+        test_case.tags = vec!["smoke".to_string(), "web".to_string()];
+        test_case.issue_key = "QA-9".to_string();
+        test_case.steps = vec![
+            TestStep::new("Abrir", "Se abre"),
+            TestStep::new("Guardar", ""),
+        ];
+        test_case
+    }
+
+    fn assert_same_cases(loaded: &[TestCase], saved: &[TestCase]) {
+        assert_eq!(loaded.len(), saved.len());
+        for (loaded, saved) in loaded.iter().zip(saved) {
+            assert_eq!(loaded.id, saved.id);
+            assert_eq!(loaded.description, saved.description);
+            assert_eq!(loaded.status, saved.status);
+            assert_eq!(loaded.observations, saved.observations);
+            assert_eq!(loaded.version, saved.version);
+            assert_eq!(loaded.ticket_numbers, saved.ticket_numbers);
+            assert_eq!(loaded.steps, saved.steps);
+            assert_eq!(loaded.priority, saved.priority);
+            assert_eq!(loaded.component, saved.component);
+            assert_eq!(loaded.tags, saved.tags);
+            assert_eq!(loaded.issue_key, saved.issue_key);
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_definitions_and_executions() {
+        let storage = SqliteStorage::open(":memory:").unwrap();
+        let mut cases = vec![sample_case("TC-1"), TestCase::new("Sin pasos", "", "")];
+        cases[1].id = "TC-2".to_string();
+
+        let definition = storage.definition_ref("smoke");
+        let execution = storage.execution_ref("smoke-20250311_123200");
+        assert!(!storage.exists(&definition).unwrap());
+        storage.save(&definition, &cases).unwrap();
+        storage.save(&execution, &cases).unwrap();
+
+        assert!(storage.exists(&definition).unwrap());
+        assert_same_cases(&storage.load(&definition).unwrap(), &cases);
+        assert_same_cases(&storage.load(&execution).unwrap(), &cases);
+        // Las rutas CSV se aceptan como referencias
+        assert_same_cases(&storage.load("definitions/smoke.csv").unwrap(), &cases);
+
+        // Guardar de nuevo reemplaza los casos anteriores
+        storage.save(&definition, &cases[..1]).unwrap();
+        assert_eq!(storage.load(&definition).unwrap().len(), 1);
+
+        let error = storage.load("definitions/otra").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        let error = storage.load("otra").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn splits_execution_names_into_definition_and_date() {
+        assert_eq!(
+            split_execution_name("smoke-login-20250311_123200"),
+            ("smoke-login", Some("2025-03-11 12:32:00".to_string()))
+        );
+        assert_eq!(
+            split_execution_name("smoke-20250311_123200_2"),
+            ("smoke", Some("2025-03-11 12:32:00".to_string()))
+        );
+        assert_eq!(split_execution_name("login-form"), ("login-form", None));
+        assert_eq!(split_execution_name("manual"), ("manual", None));
+    }
+
+    #[test]
+    fn lists_definitions_by_name_and_executions_newest_first() {
+        let storage = SqliteStorage::open(":memory:").unwrap();
+        for name in ["b", "a", "c"] {
+            storage.save(&storage.definition_ref(name), &[]).unwrap();
+        }
+        for name in [
+            "a-20250101_090000",
+            "b-20250301_090000",
+            "a-20250201_090000",
+        ] {
+            storage.save(&storage.execution_ref(name), &[]).unwrap();
+        }
+
+        assert_eq!(
+            storage.list_definitions().unwrap(),
+            vec!["definitions/a", "definitions/b", "definitions/c"]
+        );
+        assert_eq!(
+            storage.list_executions().unwrap(),
+            vec![
+                "executions/b-20250301_090000",
+                "executions/a-20250201_090000",
+                "executions/a-20250101_090000",
+            ]
+        );
+    }
+
+    #[test]
+    fn adds_missing_columns_to_databases_with_the_first_schema() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("old.db");
+        let path = path.to_str().unwrap();
+
+        let old_columns = "id TEXT NOT NULL, description TEXT NOT NULL, status TEXT NOT NULL,
+            observations TEXT NOT NULL, evidence TEXT NOT NULL, version TEXT NOT NULL,
+            ticket_numbers TEXT NOT NULL, preconditions TEXT NOT NULL,
+            test_data TEXT NOT NULL, steps TEXT NOT NULL";
+        let connection = Connection::open(path).unwrap();
+        connection
+            .execute_batch(&format!(
+                "CREATE TABLE definitions (name TEXT PRIMARY KEY, updated_at TEXT NOT NULL);
+                 CREATE TABLE definition_cases (definition TEXT NOT NULL, position INTEGER NOT NULL,
+                     {columns}, PRIMARY KEY (definition, position));
+                 CREATE TABLE executions (name TEXT PRIMARY KEY, definition TEXT NOT NULL,
+                     executed_at TEXT NOT NULL);
+                 CREATE TABLE execution_results (execution TEXT NOT NULL, position INTEGER NOT NULL,
+                     {columns}, PRIMARY KEY (execution, position));
+                 INSERT INTO definitions VALUES ('smoke', '2024-01-01 00:00:00');
+                 INSERT INTO definition_cases VALUES ('smoke', 0, 'TC-1', 'Caso antiguo',
+                     'Validated', '', '', '1.0', 'QA-1', '', '', '');",
+                columns = old_columns
+            ))
+            .unwrap();
+        drop(connection);
+
+        let storage = SqliteStorage::open(path).unwrap();
+        for table in ["definition_cases", "execution_results"] {
+            let columns = query_names(
+                storage.connection(),
+                &format!("SELECT name FROM pragma_table_info('{}')", table),
+            )
+            .unwrap();
+            for column in ADDED_COLUMNS {
+                assert!(columns.iter().any(|c| c == column), "{}.{}", table, column);
+            }
+        }
+
+        let loaded = storage.load("definitions/smoke").unwrap();
+        assert_eq!(loaded[0].description, "Caso antiguo");
+        assert_eq!(loaded[0].status, TestStatus::Validated);
+        assert_eq!(loaded[0].priority, None);
+        assert!(loaded[0].tags.is_empty());
+
+        // Las columnas nuevas se pueden usar después de la migración
+        storage
+            .save("definitions/smoke", &[sample_case("TC-1")])
+            .unwrap();
+        drop(storage);
+        let reopened = SqliteStorage::open(path).unwrap();
+        assert_same_cases(
+            &reopened.load("definitions/smoke").unwrap(),
+            &[sample_case("TC-1")],
+        );
+    }
+
+    #[test]
+    fn imports_a_csv_tree() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for subdir in ["definitions", "executions", "tests"] {
+            fs::create_dir(root.join(subdir)).unwrap();
+        }
+        let csv_path = |subdir: &str, name: &str| {
+            root.join(subdir)
+                .join(format!("{}.csv", name))
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        let cases = vec![sample_case("TC-1")];
+        save_to_csv(&csv_path("definitions", "smoke"), &cases).unwrap();
+        save_to_csv(&csv_path("executions", "smoke-20250311_123200"), &cases).unwrap();
+        save_to_csv(&csv_path("tests", "smoke-20240101_080000"), &cases).unwrap();
+        fs::write(csv_path("definitions", "rota"), "sin,columnas\n1,2\n").unwrap();
+
+        let database = root.join("casos.db");
+        let database = database.to_str().unwrap();
+        import_csv_tree(root.to_str().unwrap(), Some(database)).unwrap();
+
+        let storage = SqliteStorage::open(database).unwrap();
+        assert_eq!(
+            storage.list_definitions().unwrap(),
+            vec!["definitions/smoke"]
+        );
+        assert_eq!(
+            storage.list_executions().unwrap(),
+            vec![
+                "executions/smoke-20250311_123200",
+                "executions/smoke-20240101_080000",
+            ]
+        );
+        assert_same_cases(
+            &storage.load("executions/smoke-20240101_080000").unwrap(),
+            &CsvStorage
+                .load(&csv_path("tests", "smoke-20240101_080000"))
+                .unwrap(),
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Utilidades compartidas por las pruebas
+
+use std::env;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use tempfile::TempDir;
+use tiny_http::{Header, Response, Server};
+
+/// El directorio de trabajo es global al proceso: las pruebas que lo cambian se
+/// ejecutan de una en una
+static WORKING_DIR: Mutex<()> = Mutex::new(());
+
+/// Directorio temporal que es el directorio de trabajo mientras existe
+pub struct TempWorkingDir {
+    _dir: TempDir,
+    previous: PathBuf,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TempWorkingDir {
+    pub fn new() -> Self {
+        let lock = WORKING_DIR.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new().expect("directorio temporal");
+        let previous = env::current_dir().expect("directorio de trabajo");
+        env::set_current_dir(dir.path()).expect("cambiar al directorio temporal");
+
+        TempWorkingDir {
+            _dir: dir,
+            previous,
+            _lock: lock,
+        }
+    }
+}
+
+impl Drop for TempWorkingDir {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.previous);
+    }
+}
+
+/// Respuesta que devuelve el servidor de pruebas
+pub struct MockResponse {
+    status: u16,
+    body: String,
+    headers: Vec<(String, String)>,
+}
+
+impl MockResponse {
+    pub fn json(status: u16, body: &str) -> Self {
+        MockResponse {
+            status,
+            body: body.to_string(),
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Petición recibida por el servidor de pruebas
+#[derive(Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(&self.body).expect("cuerpo JSON")
+    }
+}
+
+/// Servidor HTTP local que contesta en orden las respuestas indicadas y
+/// guarda las peticiones recibidas
+pub struct MockServer {
+    pub url: String,
+    requests: Receiver<RecordedRequest>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<MockResponse>) -> Self {
+        let server = Server::http("127.0.0.1:0").expect("servidor de pruebas");
+        let url = format!("http://{}", server.server_addr());
+        let (sender, requests) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            for response in responses {
+                // Si el cliente deja de enviar peticiones, el servidor termina
+                let Ok(Some(mut request)) = server.recv_timeout(Duration::from_secs(10)) else {
+                    return;
+                };
+
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let _ = sender.send(RecordedRequest {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    headers: request
+                        .headers()
+                        .iter()
+                        .map(|h| (h.field.to_string(), h.value.to_string()))
+                        .collect(),
+                    body,
+                });
+
+                let mut reply =
+                    Response::from_string(response.body).with_status_code(response.status);
+                for (name, value) in &response.headers {
+                    reply.add_header(
+                        Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("cabecera"),
+                    );
+                }
+                let _ = request.respond(reply);
+            }
+        });
+
+        MockServer {
+            url,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    /// Espera a que se contesten todas las respuestas y devuelve las peticiones recibidas
+    pub fn requests(mut self) -> Vec<RecordedRequest> {
+        if let Some(handle) = self.handle.take() {
+            handle.join().expect("hilo del servidor de pruebas");
+        }
+        self.requests.try_iter().collect()
+    }
+}
@@ -0,0 +1,413 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::ListState;
+use std::io;
+
+use crate::engine::Draft;
+use crate::integrations::TicketCache;
+use crate::models::{TestCase, TestStatus};
+
+/// Campo de texto que se está editando
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Observations,
+    Evidence,
+}
+
+impl Field {
+    /// Nombre del campo para la interfaz
+    pub fn label(&self) -> &'static str {
+        match self {
+            Field::Observations => "Observaciones",
+            Field::Evidence => "Evidencia",
+        }
+    }
+}
+
+/// Modo de la interfaz
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Navegación entre casos y atajos de estado
+    Normal,
+    /// Edición de un campo del caso seleccionado
+    Editing { field: Field, buffer: String },
+}
+
+/// Estado de la ejecución a pantalla completa
+pub struct App<'a> {
+    draft: &'a mut Draft,
+    /// Casos con resultado registrado en esta ejecución
+    answered: Vec<bool>,
+    pub list_state: ListState,
+    pub mode: Mode,
+    /// Mensaje para la barra inferior
+    pub message: Option<String>,
+    /// Se pidió guardar con casos sin responder y falta confirmarlo
+    confirm_finish: bool,
+    exit: Option<bool>,
+    /// Estado de los tickets de la última sincronización
+    pub tickets: TicketCache,
+}
+
+impl<'a> App<'a> {
+    /// Prepara la interfaz para un borrador; los casos ya respondidos quedan marcados
+    pub fn new(draft: &'a mut Draft) -> Self {
+        let total = draft.execution.test_cases.len();
+        let answered: Vec<bool> = (0..total).map(|i| i < draft.answered).collect();
+
+        // Empezar por el primer caso sin responder
+        let first_pending = answered.iter().position(|a| !a).unwrap_or(0);
+
+        App {
+            draft,
+            answered,
+            list_state: ListState::default().with_selected(Some(first_pending)),
+            mode: Mode::Normal,
+            message: None,
+            confirm_finish: false,
+            exit: None,
+            tickets: TicketCache::load().unwrap_or_default(),
+        }
+    }
+
+    /// Nombre de la ejecución
+    pub fn execution_name(&self) -> &str {
+        &self.draft.execution.name
+    }
+
+    /// Casos de la ejecución
+    pub fn test_cases(&self) -> &[TestCase] {
+        &self.draft.execution.test_cases
+    }
+
+    /// Índice del caso seleccionado
+    pub fn selected(&self) -> usize {
+        self.list_state.selected().unwrap_or(0)
+    }
+
+    /// Caso seleccionado
+    pub fn selected_case(&self) -> Option<&TestCase> {
+        self.test_cases().get(self.selected())
+    }
+
+    /// Indica si el caso ya tiene resultado
+    pub fn is_answered(&self, index: usize) -> bool {
+        self.answered.get(index).copied().unwrap_or(false)
+    }
+
+    /// Cantidad de casos con resultado
+    pub fn answered_count(&self) -> usize {
+        self.answered.iter().filter(|a| **a).count()
+    }
+
+    /// `Some(true)` si se terminó la ejecución, `Some(false)` si se salió sin terminar
+    pub fn exit(&self) -> Option<bool> {
+        self.exit
+    }
+
+    /// Procesa una tecla
+    pub fn handle_key(&mut self, key: KeyEvent) -> io::Result<()> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.exit = Some(false);
+            return Ok(());
+        }
+
+        match &mut self.mode {
+            Mode::Editing { field, buffer } => match key.code {
+                KeyCode::Enter => {
+                    let (field, value) = (*field, buffer.clone());
+                    self.mode = Mode::Normal;
+                    self.set_field(field, value)?;
+                }
+                KeyCode::Esc => {
+                    self.mode = Mode::Normal;
+                    self.message = Some("Edición cancelada".to_string());
+                }
+                KeyCode::Backspace => {
+                    buffer.pop();
+                }
+                KeyCode::Char(c) => buffer.push(c),
+                _ => {}
+            },
+            Mode::Normal => self.handle_normal_key(key.code)?,
+        }
+
+        Ok(())
+    }
+
+    fn handle_normal_key(&mut self, code: KeyCode) -> io::Result<()> {
+        if code != KeyCode::Char('w') {
+            self.confirm_finish = false;
+        }
+
+        match code {
+            KeyCode::Up | KeyCode::Left | KeyCode::Char('k') => self.select_previous(),
+            KeyCode::Down | KeyCode::Right | KeyCode::Char('j') => self.select_next(),
+            KeyCode::Home => self.list_state.select(Some(0)),
+            KeyCode::End => self
+                .list_state
+                .select(Some(self.test_cases().len().saturating_sub(1))),
+            KeyCode::Char('v') => self.set_status(TestStatus::Validated)?,
+            KeyCode::Char('r') => self.set_status(TestStatus::Rejected)?,
+            KeyCode::Char('s') => self.set_status(TestStatus::Skipped)?,
+            KeyCode::Char('b') => self.set_status(TestStatus::Blocked)?,
+            KeyCode::Char('p') => self.set_status(TestStatus::Pending)?,
+            KeyCode::Char('o') => self.start_editing(Field::Observations),
+            KeyCode::Char('e') => self.start_editing(Field::Evidence),
+            KeyCode::Char('w') => self.finish(),
+            KeyCode::Char('q') | KeyCode::Esc => {
+                // Con todo respondido, salir equivale a terminar
+                self.exit = Some(self.answered_count() == self.answered.len());
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn select_previous(&mut self) {
+        let selected = self.selected();
+        self.list_state.select(Some(selected.saturating_sub(1)));
+    }
+
+    fn select_next(&mut self) {
+        let last = self.test_cases().len().saturating_sub(1);
+        self.list_state
+            .select(Some((self.selected() + 1).min(last)));
+    }
+
+    /// Registra el estado del caso seleccionado y pasa al siguiente
+    fn set_status(&mut self, status: TestStatus) -> io::Result<()> {
+        let index = self.selected();
+        let Some(test_case) = self.draft.execution.test_cases.get_mut(index) else {
+            return Ok(());
+        };
+
+        test_case.status = status;
+        self.answered[index] = true;
+        self.message = Some(format!("Caso #{}: {}", index + 1, status));
+        self.save_draft()?;
+        self.select_next();
+
+        Ok(())
+    }
+
+    fn start_editing(&mut self, field: Field) {
+        let Some(test_case) = self.selected_case() else {
+            return;
+        };
+
+        let buffer = match field {
+            Field::Observations => test_case.observations.clone(),
+            Field::Evidence => test_case.evidence.clone(),
+        };
+        self.mode = Mode::Editing { field, buffer };
+    }
+
+    fn set_field(&mut self, field: Field, value: String) -> io::Result<()> {
+        let index = self.selected();
+        let Some(test_case) = self.draft.execution.test_cases.get_mut(index) else {
+            return Ok(());
+        };
+
+        match field {
+            Field::Observations => test_case.observations = value,
+            Field::Evidence => test_case.evidence = value,
+        }
+        self.message = Some(format!(
+            "Se guardó el campo {} del caso #{}",
+            field.label(),
+            index + 1
+        ));
+        self.save_draft()
+    }
+
+    /// Termina la ejecución; con casos sin responder pide confirmación
+    fn finish(&mut self) {
+        let pending = self.answered.len() - self.answered_count();
+
+        if pending == 0 || self.confirm_finish {
+            self.exit = Some(true);
+        } else {
+            self.confirm_finish = true;
+            self.message = Some(format!(
+                "Quedan {} casos sin responder. Pulsa w otra vez para guardar igualmente",
+                pending
+            ));
+        }
+    }
+
+    /// Guarda el borrador; `answered` es la cantidad de casos respondidos sin huecos
+    /// desde el principio, que es desde donde reanuda `execute --resume`
+    fn save_draft(&mut self) -> io::Result<()> {
+        self.draft.answered = self
+            .answered
+            .iter()
+            .position(|a| !a)
+            .unwrap_or(self.answered.len());
+        self.draft.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::Execution;
+    use crate::test_support::TempWorkingDir;
+
+    fn draft_with(total: usize) -> Draft {
+        let test_cases = (1..=total)
+            .map(|i| TestCase::new(&format!("Caso {}", i), "1.0", ""))
+            .collect();
+        Draft::new("login.csv", Execution::new("login", test_cases))
+    }
+
+    fn press(app: &mut App, codes: &[KeyCode]) {
+        for code in codes {
+            app.handle_key(KeyEvent::from(*code)).unwrap();
+        }
+    }
+
+    fn statuses(app: &App) -> Vec<TestStatus> {
+        app.test_cases().iter().map(|tc| tc.status).collect()
+    }
+
+    #[test]
+    fn hotkeys_set_the_status_and_move_to_the_next_case() {
+        let _dir = TempWorkingDir::new();
+        let mut draft = draft_with(4);
+        let mut app = App::new(&mut draft);
+
+        press(&mut app, &[KeyCode::Char('v'), KeyCode::Char('r')]);
+        assert_eq!(app.selected(), 2);
+        press(&mut app, &[KeyCode::Char('b'), KeyCode::Char('s')]);
+        // En el último caso la selección no avanza
+        assert_eq!(app.selected(), 3);
+        press(&mut app, &[KeyCode::Char('k'), KeyCode::Char('p')]);
+
+        assert_eq!(
+            statuses(&app),
+            vec![
+                TestStatus::Validated,
+                TestStatus::Rejected,
+                TestStatus::Pending,
+                TestStatus::Skipped
+            ]
+        );
+        assert_eq!(app.answered_count(), 4);
+        assert_eq!(app.message.as_deref(), Some("Caso #3: ⏳ Pendiente"));
+        assert_eq!(app.exit(), None);
+    }
+
+    #[test]
+    fn saves_only_the_answered_prefix_when_answering_out_of_order() {
+        let _dir = TempWorkingDir::new();
+        let mut draft = draft_with(4);
+        let mut app = App::new(&mut draft);
+
+        press(
+            &mut app,
+            &[KeyCode::Down, KeyCode::Down, KeyCode::Char('v')],
+        );
+        assert!(app.is_answered(2));
+        assert_eq!(app.selected(), 3);
+        assert_eq!(Draft::load(&app.draft.path()).unwrap().answered, 0);
+
+        press(&mut app, &[KeyCode::Home, KeyCode::Char('r')]);
+        assert_eq!(Draft::load(&app.draft.path()).unwrap().answered, 1);
+
+        // Al responder el hueco el prefijo incluye el caso respondido antes
+        press(&mut app, &[KeyCode::Char('v')]);
+        let saved = Draft::load(&app.draft.path()).unwrap();
+        assert_eq!(saved.answered, 3);
+        assert_eq!(saved.execution.test_cases[2].status, TestStatus::Validated);
+
+        drop(app);
+        assert_eq!(draft.answered, 3);
+
+        // Al reanudar se empieza por el primer caso sin responder
+        let app = App::new(&mut draft);
+        assert_eq!(app.selected(), 3);
+    }
+
+    #[test]
+    fn finishing_with_pending_cases_needs_a_second_w() {
+        let _dir = TempWorkingDir::new();
+        let mut draft = draft_with(3);
+        let mut app = App::new(&mut draft);
+
+        press(&mut app, &[KeyCode::Char('v'), KeyCode::Char('w')]);
+        assert_eq!(app.exit(), None);
+        assert_eq!(
+            app.message.as_deref(),
+            Some("Quedan 2 casos sin responder. Pulsa w otra vez para guardar igualmente")
+        );
+
+        // Cualquier otra tecla anula la confirmación
+        press(&mut app, &[KeyCode::Down, KeyCode::Char('w')]);
+        assert_eq!(app.exit(), None);
+
+        press(&mut app, &[KeyCode::Char('w')]);
+        assert_eq!(app.exit(), Some(true));
+    }
+
+    #[test]
+    fn finishing_and_quitting_without_confirmation() {
+        let _dir = TempWorkingDir::new();
+        let mut draft = draft_with(2);
+
+        let mut app = App::new(&mut draft);
+        press(&mut app, &[KeyCode::Char('v'), KeyCode::Char('q')]);
+        assert_eq!(app.exit(), Some(false));
+
+        let mut app = App::new(&mut draft);
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(app.exit(), Some(false));
+
+        let mut app = App::new(&mut draft);
+        press(&mut app, &[KeyCode::Char('s'), KeyCode::Char('w')]);
+        assert_eq!(app.exit(), Some(true));
+    }
+
+    #[test]
+    fn edits_fields_and_esc_cancels_the_edit() {
+        let _dir = TempWorkingDir::new();
+        let mut draft = draft_with(2);
+        let mut app = App::new(&mut draft);
+
+        press(
+            &mut app,
+            &[
+                KeyCode::Char('o'),
+                KeyCode::Char('N'),
+                KeyCode::Char('o'),
+                KeyCode::Char('k'),
+                KeyCode::Backspace,
+                KeyCode::Enter,
+            ],
+        );
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.test_cases()[0].observations, "No");
+
+        // Al editar, las teclas de atajo se escriben en el campo
+        press(&mut app, &[KeyCode::Char('e'), KeyCode::Char('v')]);
+        assert_eq!(
+            app.mode,
+            Mode::Editing {
+                field: Field::Evidence,
+                buffer: "v".to_string()
+            }
+        );
+
+        press(&mut app, &[KeyCode::Esc]);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.message.as_deref(), Some("Edición cancelada"));
+        assert_eq!(app.exit(), None);
+        assert!(app.test_cases()[0].evidence.is_empty());
+        assert_eq!(app.test_cases()[0].status, TestStatus::Pending);
+        assert!(!app.is_answered(0));
+
+        drop(app);
+        assert_eq!(draft.execution.test_cases[0].observations, "No");
+    }
+}
@@ -0,0 +1,40 @@
+//! Ejecución de casos de prueba a pantalla completa
+
+pub mod app;
+pub mod ui;
+
+pub use app::App;
+
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use std::io;
+
+use crate::engine::Draft;
+
+/// Responde los casos de un borrador en la interfaz a pantalla completa
+///
+/// El borrador se guarda después de cada cambio. Devuelve `true` si se terminó
+/// la ejecución, o `false` si se salió antes y el borrador queda para reanudarse.
+pub fn run_draft(draft: &mut Draft) -> io::Result<bool> {
+    let mut terminal = ratatui::init();
+    let result = run_app(&mut terminal, App::new(draft));
+    ratatui::restore();
+
+    result
+}
+
+fn run_app(terminal: &mut ratatui::DefaultTerminal, mut app: App) -> io::Result<bool> {
+    loop {
+        terminal.draw(|frame| ui::draw(frame, &mut app))?;
+
+        if let Event::Key(key) = event::read()? {
+            // En Windows también llegan los eventos al soltar la tecla
+            if key.kind == KeyEventKind::Press {
+                app.handle_key(key)?;
+            }
+        }
+
+        if let Some(finished) = app.exit() {
+            return Ok(finished);
+        }
+    }
+}
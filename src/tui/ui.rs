@@ -0,0 +1,263 @@
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::models::{TestCase, TestStatus};
+use crate::tui::app::{App, Mode};
+
+/// Dibuja la interfaz completa: lista de casos, detalle, progreso y ayuda
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [main, progress, footer] = Layout::vertical([
+        Constraint::Min(5),
+        Constraint::Length(3),
+        Constraint::Length(2),
+    ])
+    .areas(frame.area());
+
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(main);
+
+    draw_case_list(frame, app, list_area);
+    draw_detail(frame, app, detail_area);
+    draw_progress(frame, app, progress);
+    draw_footer(frame, app, footer);
+}
+
+fn draw_case_list(frame: &mut Frame, app: &mut App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .test_cases()
+        .iter()
+        .enumerate()
+        .map(|(i, test_case)| {
+            let marker = if app.is_answered(i) { "●" } else { "○" };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", marker)),
+                Span::raw(format!("{} ", test_case.status.emoji())),
+                Span::raw(format!("{:>3}. ", i + 1)),
+                Span::styled(
+                    test_case.description.clone(),
+                    Style::default().fg(status_color(test_case.status)),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", app.execution_name())),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut app.list_state);
+}
+
+fn draw_detail(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(test_case) = app.selected_case() else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(test_case.description.clone().bold()),
+        Line::from(format!("ID: {}", test_case.id)),
+        Line::from(vec![
+            Span::raw("Estado: "),
+            Span::styled(
+                test_case.status.to_string(),
+                Style::default().fg(status_color(test_case.status)),
+            ),
+        ]),
+    ];
+
+    lines.extend(classification_lines(test_case));
+    lines.extend(ticket_lines(app, test_case));
+
+    if !test_case.preconditions.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from("Precondiciones".bold()));
+        lines.push(Line::from(test_case.preconditions.clone()));
+    }
+    if !test_case.test_data.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from("Datos de prueba".bold()));
+        lines.push(Line::from(test_case.test_data.clone()));
+    }
+
+    if !test_case.steps.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from("Pasos".bold()));
+        for (i, step) in test_case.steps.iter().enumerate() {
+            lines.push(Line::from(format!(
+                "{} {}. {}",
+                step.status.emoji(),
+                i + 1,
+                step.action
+            )));
+            if !step.expected.is_empty() {
+                lines.push(Line::from(format!("     → {}", step.expected)).italic());
+            }
+        }
+    }
+
+    lines.push(Line::default());
+    lines.extend(field_lines(
+        app,
+        "Observaciones",
+        &test_case.observations,
+        'o',
+    ));
+    lines.extend(field_lines(app, "Evidencia", &test_case.evidence, 'e'));
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Caso #{} ", app.selected() + 1)),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(detail, area);
+}
+
+/// Prioridad, componente, etiquetas e incidencia del caso, si las tiene
+fn classification_lines(test_case: &TestCase) -> Vec<Line<'static>> {
+    let mut parts = Vec::new();
+
+    if let Some(priority) = test_case.priority {
+        parts.push(format!("Prioridad: {}", priority));
+    }
+    if !test_case.component.is_empty() {
+        parts.push(format!("Componente: {}", test_case.component));
+    }
+    if !test_case.tags.is_empty() {
+        parts.push(format!("Etiquetas: {}", test_case.tags.join(", ")));
+    }
+    if !test_case.issue_key.is_empty() {
+        parts.push(format!("Incidencia: {}", test_case.issue_key));
+    }
+
+    if parts.is_empty() {
+        Vec::new()
+    } else {
+        vec![Line::from(parts.join("  ·  "))]
+    }
+}
+
+/// Estado de los tickets sincronizados del caso
+///
+/// Si el caso está bloqueado y algún ticket ya se cerró, se resalta para volver a ejecutarlo.
+fn ticket_lines(app: &App, test_case: &TestCase) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = app
+        .tickets
+        .tickets_of(test_case)
+        .iter()
+        .map(|t| {
+            Line::from(format!(
+                "Ticket {}: {} - {}",
+                t.key,
+                t.status_label(),
+                t.title
+            ))
+        })
+        .collect();
+
+    if !app.tickets.closed_blockers(test_case).is_empty() {
+        lines.push(Line::styled(
+            "⚠️ Bloqueado por un ticket cerrado: vuelve a ejecutarlo",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    lines
+}
+
+/// Líneas de un campo editable, con el cursor si se está editando
+fn field_lines(app: &App, label: &str, value: &str, key: char) -> Vec<Line<'static>> {
+    match &app.mode {
+        Mode::Editing { field, buffer } if field.label() == label => vec![
+            Line::from(
+                format!("{} (Enter guarda, Esc cancela)", label)
+                    .bold()
+                    .yellow(),
+            ),
+            Line::from(vec![
+                Span::raw(buffer.clone()),
+                Span::styled("█", Style::default().fg(Color::Yellow)),
+            ]),
+        ],
+        _ => vec![
+            Line::from(format!("{} [{}]", label, key).bold()),
+            Line::from(if value.is_empty() {
+                "—".to_string()
+            } else {
+                value.to_string()
+            }),
+        ],
+    }
+}
+
+fn draw_progress(frame: &mut Frame, app: &App, area: Rect) {
+    let total = app.test_cases().len();
+    let answered = app.answered_count();
+    let ratio = if total == 0 {
+        0.0
+    } else {
+        answered as f64 / total as f64
+    };
+
+    let counts: Vec<String> = TestStatus::ALL
+        .iter()
+        .map(|status| {
+            let count = app
+                .test_cases()
+                .iter()
+                .filter(|tc| tc.status == *status)
+                .count();
+            format!("{} {}", status.emoji(), count)
+        })
+        .collect();
+
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title(" Progreso "))
+        .gauge_style(Style::default().fg(Color::Green))
+        .ratio(ratio)
+        .label(format!(
+            "{}/{} casos   {}",
+            answered,
+            total,
+            counts.join("  ")
+        ));
+
+    frame.render_widget(gauge, area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let help = match app.mode {
+        Mode::Normal => {
+            "↑/↓ mover · v validado · r rechazado · s omitido · b bloqueado · p pendiente · o observaciones · e evidencia · w guardar y terminar · q salir"
+        }
+        Mode::Editing { .. } => "Escribe el texto · Enter guarda · Esc cancela",
+    };
+
+    let mut lines = vec![Line::from(help.dark_gray())];
+    if let Some(message) = &app.message {
+        lines.push(Line::from(message.clone().cyan()));
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Color de cada estado en la lista y el detalle
+fn status_color(status: TestStatus) -> Color {
+    match status {
+        TestStatus::Pending => Color::Yellow,
+        TestStatus::Validated => Color::Green,
+        TestStatus::Rejected => Color::Red,
+        TestStatus::Skipped => Color::Gray,
+        TestStatus::Blocked => Color::Magenta,
+    }
+}
@@ -0,0 +1,87 @@
+/// Fragmento de una comparación palabra a palabra
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordDiff<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Compara dos textos palabra a palabra con la subsecuencia común más larga
+pub fn word_diff<'a>(before: &'a str, after: &'a str) -> Vec<WordDiff<'a>> {
+    let old: Vec<&str> = before.split_whitespace().collect();
+    let new: Vec<&str> = after.split_whitespace().collect();
+
+    // lcs[i][j]: longitud de la subsecuencia común de old[i..] y new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(WordDiff::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(WordDiff::Removed(old[i]));
+            i += 1;
+        } else {
+            diff.push(WordDiff::Added(new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|w| WordDiff::Removed(w)));
+    diff.extend(new[j..].iter().map(|w| WordDiff::Added(w)));
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use WordDiff::*;
+
+    #[test]
+    fn marks_replaced_added_and_removed_words() {
+        assert_eq!(
+            word_diff("Abrir el menu  principal", "Abrir el menú principal"),
+            vec![
+                Same("Abrir"),
+                Same("el"),
+                Removed("menu"),
+                Added("menú"),
+                Same("principal"),
+            ]
+        );
+        assert_eq!(
+            word_diff("Pulsar Guardar", "Pulsar el botón Guardar"),
+            vec![Same("Pulsar"), Added("el"), Added("botón"), Same("Guardar")]
+        );
+        assert_eq!(
+            word_diff("Pulsar el botón Guardar ahora", "Pulsar Guardar"),
+            vec![
+                Same("Pulsar"),
+                Removed("el"),
+                Removed("botón"),
+                Same("Guardar"),
+                Removed("ahora"),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_empty_and_identical_texts() {
+        assert!(word_diff("", "  ").is_empty());
+        assert_eq!(word_diff("", "Nuevo"), vec![Added("Nuevo")]);
+        assert_eq!(word_diff("Viejo", ""), vec![Removed("Viejo")]);
+        assert_eq!(word_diff("a b", "a\nb"), vec![Same("a"), Same("b")]);
+    }
+}
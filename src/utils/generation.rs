@@ -0,0 +1,223 @@
+use serde_json::Value;
+use std::io;
+
+use crate::models::{parse_tags, TestCase, TestStep};
+use crate::utils::{strip_code_fence, LlmProvider};
+
+/// Formato de respuesta que se pide al modelo, añadido a las instrucciones configuradas
+const GENERATION_FORMAT: &str = r#"Responde solo con un objeto JSON de la forma {"cases": [{"description": "...", "preconditions": "...", "test_data": "...", "priority": "P0|P1|P2|P3", "component": "...", "tags": ["..."], "steps": [{"action": "...", "expected": "..."}]}]}. Usa cadenas vacías o listas vacías para lo que no aplique."#;
+
+/// Propone casos de prueba para un requisito o historia de usuario
+///
+/// Los casos se crean con la versión y los tickets indicados; las propuestas
+/// sin descripción se descartan.
+pub fn propose_test_cases(
+    provider: &LlmProvider,
+    requirement: &str,
+    version: &str,
+    ticket_numbers: &str,
+) -> io::Result<Vec<TestCase>> {
+    let system = format!("{}\n\n{}", provider.generation_prompt, GENERATION_FORMAT);
+    let content = provider.chat_json(&system, requirement)?;
+
+    let json: Value = serde_json::from_str(strip_code_fence(&content)).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("El proveedor LLM no devolvió un JSON válido: {}", e),
+        )
+    })?;
+
+    let proposals = match &json {
+        Value::Array(cases) => cases,
+        _ => json.get("cases").and_then(Value::as_array).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "La respuesta del proveedor LLM no incluye la lista 'cases'",
+            )
+        })?,
+    };
+
+    Ok(proposals
+        .iter()
+        .filter_map(|proposal| test_case_from_json(proposal, version, ticket_numbers))
+        .collect())
+}
+
+/// Convierte una propuesta del modelo en un caso, tolerando campos ausentes
+fn test_case_from_json(proposal: &Value, version: &str, ticket_numbers: &str) -> Option<TestCase> {
+    let description = text_field(proposal, "description");
+    if description.is_empty() {
+        return None;
+    }
+
+    let mut test_case = TestCase::new(&description, version, ticket_numbers);
+    test_case.preconditions = text_field(proposal, "preconditions");
+    test_case.test_data = text_field(proposal, "test_data");
+    test_case.priority = text_field(proposal, "priority").parse().ok();
+    test_case.component = text_field(proposal, "component");
+
+    test_case.tags = match proposal.get("tags") {
+        Some(Value::Array(tags)) => tags
+            .iter()
+            .filter_map(Value::as_str)
+            .flat_map(parse_tags)
+            .collect(),
+        Some(Value::String(tags)) => parse_tags(tags),
+        _ => Vec::new(),
+    };
+
+    test_case.steps = proposal
+        .get("steps")
+        .and_then(Value::as_array)
+        .map(|steps| {
+            steps
+                .iter()
+                .filter_map(|step| match step {
+                    Value::String(action) => Some(TestStep::new(action.trim(), "")),
+                    _ => {
+                        let action = text_field(step, "action");
+                        let expected = text_field(step, "expected");
+                        (!action.is_empty()).then(|| TestStep::new(&action, &expected))
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(test_case)
+}
+
+/// Valor de texto de un campo, sin espacios sobrantes, o vacío si no es texto
+fn text_field(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(|text| text.trim().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LlmConfig;
+    use crate::models::Priority;
+    use crate::test_support::{MockResponse, MockServer};
+    use serde_json::json;
+
+    fn stub_provider(server: &MockServer) -> LlmProvider {
+        LlmProvider::from_config(&LlmConfig {
+            base_url: server.url.clone(),
+            model: "llama3".to_string(),
+            api_key_env: String::new(),
+            generation_prompt: "Propón casos de prueba".to_string(),
+            max_retries: 0,
+            ..LlmConfig::default()
+        })
+        .unwrap()
+    }
+
+    fn reply(content: &str) -> MockResponse {
+        MockResponse::json(
+            200,
+            &json!({ "choices": [{ "message": { "content": content } }] }).to_string(),
+        )
+    }
+
+    #[test]
+    fn proposes_cases_from_code_fenced_json() {
+        let content = json!({
+            "cases": [{
+                "description": " Login con credenciales válidas ",
+                "preconditions": "Usuario registrado",
+                "test_data": "user@example.com",
+                "priority": "P1",
+                "component": "auth",
+                "tags": ["smoke, login", "regresion"],
+                "steps": [
+                    {"action": "Abrir el login", "expected": "Se muestra el formulario"},
+                    "Pulsar Entrar",
+                    {"expected": "Sin acción"}
+                ]
+            }]
+        });
+        let server = MockServer::start(vec![reply(&format!("```json\n{}\n```", content))]);
+
+        let cases = propose_test_cases(
+            &stub_provider(&server),
+            "Como usuario quiero entrar",
+            "1.2",
+            "QA-7",
+        )
+        .unwrap();
+
+        assert_eq!(cases.len(), 1);
+        let case = &cases[0];
+        assert_eq!(case.description, "Login con credenciales válidas");
+        assert_eq!(case.version, "1.2");
+        assert_eq!(case.ticket_numbers, "QA-7");
+        assert_eq!(case.preconditions, "Usuario registrado");
+        assert_eq!(case.test_data, "user@example.com");
+        assert_eq!(case.priority, Some(Priority::P1));
+        assert_eq!(case.component, "auth");
+        assert_eq!(case.tags, vec!["smoke", "login", "regresion"]);
+        let steps: Vec<(&str, &str)> = case
+            .steps
+            .iter()
+            .map(|step| (step.action.as_str(), step.expected.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("Abrir el login", "Se muestra el formulario"),
+                ("Pulsar Entrar", "")
+            ]
+        );
+
+        let request = server.requests()[0].json();
+        let system = request["messages"][0]["content"].as_str().unwrap();
+        assert!(system.starts_with("Propón casos de prueba"));
+        assert!(system.contains(GENERATION_FORMAT));
+        assert_eq!(
+            request["messages"][1]["content"],
+            "Como usuario quiero entrar"
+        );
+    }
+
+    #[test]
+    fn tolerates_missing_fields_and_drops_cases_without_description() {
+        let server = MockServer::start(vec![reply(
+            r#"[{"description": "Cerrar sesión", "priority": "urgente", "tags": "web, móvil"}, {"steps": ["Abrir"]}, {"description": "  "}]"#,
+        )]);
+
+        let cases = propose_test_cases(&stub_provider(&server), "Requisito", "", "").unwrap();
+
+        assert_eq!(cases.len(), 1);
+        let case = &cases[0];
+        assert_eq!(case.description, "Cerrar sesión");
+        assert_eq!(case.priority, None);
+        assert_eq!(case.tags, vec!["web", "móvil"]);
+        assert!(case.preconditions.is_empty());
+        assert!(case.component.is_empty());
+        assert!(case.steps.is_empty());
+    }
+
+    #[test]
+    fn rejects_responses_without_a_case_list() {
+        let server = MockServer::start(vec![
+            reply(r#"{"cases": "Login con credenciales válidas"}"#),
+            reply(r#"{"casos": []}"#),
+            reply("Aquí tienes los casos: ninguno"),
+        ]);
+        let provider = stub_provider(&server);
+
+        for _ in 0..2 {
+            let error = propose_test_cases(&provider, "Requisito", "", "").unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+            assert!(error.to_string().contains("'cases'"));
+        }
+        let error = propose_test_cases(&provider, "Requisito", "", "").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("JSON válido"));
+        assert_eq!(server.requests().len(), 3);
+    }
+}
@@ -0,0 +1,674 @@
+use encoding_rs::{Encoding, UTF_8};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directorios donde se buscan diccionarios Hunspell si no se indica uno
+pub const DICTIONARY_DIRS: [&str; 5] = [
+    "dictionaries",
+    "/usr/share/hunspell",
+    "/usr/share/myspell",
+    "/usr/share/myspell/dicts",
+    "/Library/Spelling",
+];
+
+/// Cómo conseguir los diccionarios, que no se distribuyen con la herramienta
+const INSTALL_INSTRUCTIONS: &str = "La herramienta no incluye diccionarios. Para instalarlos:
+  - Debian/Ubuntu: apt install hunspell-es hunspell-en-us
+  - Fedora: dnf install hunspell-es hunspell-en-US
+  - Otros sistemas: descargue los archivos .dic y .aff de https://github.com/LibreOffice/dictionaries (es/es_ES, en/en_US) y cópielos en dictionaries/ o indique dictionary_dir en la sección [spelling]";
+
+/// Cantidad máxima de sugerencias por palabra
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Diccionario Hunspell (`.dic` + `.aff`) para revisar la ortografía sin conexión
+///
+/// Implementa la parte de Hunspell necesaria para los diccionarios habituales:
+/// prefijos y sufijos con sus condiciones, combinación de ambos, alias de
+/// marcas (`AF`), `NEEDAFFIX`, `FORBIDDENWORD` y sugerencias con `REP` y `TRY`.
+/// No admite palabras compuestas.
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    pub language: String,
+    words: HashMap<String, HashSet<String>>,
+    prefixes: HashMap<String, Vec<Affix>>,
+    suffixes: HashMap<String, Vec<Affix>>,
+    try_chars: Vec<char>,
+    replacements: Vec<(String, String)>,
+    need_affix: Option<String>,
+    forbidden: Option<String>,
+}
+
+/// Regla de un prefijo o sufijo
+#[derive(Debug, Clone)]
+struct Affix {
+    flag: String,
+    cross_product: bool,
+    strip: String,
+    condition: Vec<ConditionPart>,
+}
+
+/// Elemento de la condición de una regla: `.`, un carácter o `[...]`/`[^...]`
+#[derive(Debug, Clone)]
+enum ConditionPart {
+    Any,
+    Char(char),
+    Set { chars: Vec<char>, negated: bool },
+}
+
+/// Formato de las marcas de afijos (`FLAG` en el `.aff`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagMode {
+    Char,
+    Long,
+    Num,
+}
+
+impl Dictionary {
+    /// Busca y carga el diccionario de un idioma, como `es_ES` o `en_US`
+    ///
+    /// Se busca primero en `dir`, si se indica, y después en [`DICTIONARY_DIRS`].
+    pub fn find(language: &str, dir: Option<&str>) -> io::Result<Self> {
+        let dirs: Vec<PathBuf> = dir
+            .into_iter()
+            .chain(DICTIONARY_DIRS)
+            .map(PathBuf::from)
+            .collect();
+
+        for dir in &dirs {
+            let dic = dir.join(format!("{}.dic", language));
+            let aff = dir.join(format!("{}.aff", language));
+            if dic.exists() && aff.exists() {
+                return Dictionary::load(&dic, &aff, language);
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No se encontró el diccionario {}.dic/{}.aff en: {}\n{}",
+                language,
+                language,
+                dirs.iter()
+                    .map(|d| d.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                INSTALL_INSTRUCTIONS
+            ),
+        ))
+    }
+
+    /// Carga un diccionario a partir de sus archivos `.dic` y `.aff`
+    pub fn load(dic_path: &Path, aff_path: &Path, language: &str) -> io::Result<Self> {
+        let aff = read_text(aff_path, None)?;
+        let encoding = aff.lines().find_map(|line| {
+            let mut parts = line.split_whitespace();
+            (parts.next() == Some("SET")).then(|| parts.next().unwrap_or("").to_string())
+        });
+        // Las marcas se leen de nuevo con la codificación declarada
+        let aff = read_text(aff_path, encoding.as_deref())?;
+        let dic = read_text(dic_path, encoding.as_deref())?;
+
+        let mut dictionary = Dictionary {
+            language: language.to_string(),
+            words: HashMap::new(),
+            prefixes: HashMap::new(),
+            suffixes: HashMap::new(),
+            try_chars: Vec::new(),
+            replacements: Vec::new(),
+            need_affix: None,
+            forbidden: None,
+        };
+
+        let mut flag_mode = FlagMode::Char;
+        let mut aliases: Vec<HashSet<String>> = Vec::new();
+        // Grupos de afijos que admiten combinarse con el otro tipo de afijo
+        let mut cross_products: HashSet<(String, String)> = HashSet::new();
+
+        for line in aff.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.as_slice() {
+                ["FLAG", "long", ..] => flag_mode = FlagMode::Long,
+                ["FLAG", "num", ..] => flag_mode = FlagMode::Num,
+                ["TRY", chars, ..] => dictionary.try_chars = chars.chars().collect(),
+                ["NEEDAFFIX", flag, ..] => dictionary.need_affix = Some(flag.to_string()),
+                ["FORBIDDENWORD", flag, ..] => dictionary.forbidden = Some(flag.to_string()),
+                ["AF", flags, ..] if flags.parse::<usize>().is_err() => {
+                    aliases.push(parse_flags(flags, flag_mode));
+                }
+                ["REP", from, to, ..] => dictionary
+                    .replacements
+                    .push((from.replace('_', " "), to.replace('_', " "))),
+                // La cabecera de cada grupo es `PFX A Y 3`: cuatro campos y la cantidad
+                // de reglas. Una regla como `SFX A N nes N` quita una `N` literal.
+                [kind @ ("PFX" | "SFX"), flag, "Y", count] if count.parse::<usize>().is_ok() => {
+                    cross_products.insert((kind.to_string(), flag.to_string()));
+                }
+                ["PFX" | "SFX", _, "N", count] if count.parse::<usize>().is_ok() => {}
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let cross_product =
+                        cross_products.contains(&(kind.to_string(), flag.to_string()));
+                    // Las marcas de continuación (`add/marcas`) no se usan
+                    let add = add.split('/').next().unwrap_or("");
+                    let affix = Affix {
+                        flag: flag.to_string(),
+                        cross_product,
+                        strip: empty_if_zero(strip),
+                        condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                    };
+                    let rules = if *kind == "PFX" {
+                        &mut dictionary.prefixes
+                    } else {
+                        &mut dictionary.suffixes
+                    };
+                    rules.entry(empty_if_zero(add)).or_default().push(affix);
+                }
+                _ => {}
+            }
+        }
+
+        for (i, line) in dic.lines().enumerate() {
+            // La primera línea es la cantidad aproximada de palabras
+            if i == 0 && line.trim().parse::<usize>().is_ok() {
+                continue;
+            }
+
+            let Some(entry) = line.split(['\t', ' ']).next().filter(|e| !e.is_empty()) else {
+                continue;
+            };
+
+            let (word, flags) = split_entry(entry);
+            let flags = match flags {
+                Some(flags) if !aliases.is_empty() => flags
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| aliases.get(n.wrapping_sub(1)))
+                    .cloned()
+                    .unwrap_or_default(),
+                Some(flags) => parse_flags(flags, flag_mode),
+                None => HashSet::new(),
+            };
+
+            dictionary.words.entry(word).or_default().extend(flags);
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Cantidad de raíces del diccionario
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Indica si una palabra está bien escrita
+    ///
+    /// Las palabras en mayúscula inicial o completa también se aceptan si lo
+    /// está su forma en minúsculas.
+    pub fn check(&self, word: &str) -> bool {
+        if self.check_exact(word) {
+            return true;
+        }
+
+        let lower = word.to_lowercase();
+        if lower != word && self.check_exact(&lower) {
+            return true;
+        }
+
+        // Nombres propios escritos en mayúsculas, como `MADRID`
+        let all_upper = word.chars().all(|c| !c.is_lowercase());
+        all_upper && self.check_exact(&capitalize(&lower))
+    }
+
+    /// Sugerencias para una palabra mal escrita, de la más a la menos probable
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let lower = word.to_lowercase();
+        // Nombres propios escritos en minúsculas
+        let mut candidates: Vec<String> = vec![capitalize(&lower)];
+
+        // Errores frecuentes declarados en el diccionario
+        for (from, to) in &self.replacements {
+            for (i, _) in lower.match_indices(from.as_str()) {
+                candidates.push(format!("{}{}{}", &lower[..i], to, &lower[i + from.len()..]));
+            }
+        }
+
+        let chars: Vec<char> = lower.chars().collect();
+        let try_chars: Vec<char> = if self.try_chars.is_empty() {
+            ('a'..='z').collect()
+        } else {
+            self.try_chars.clone()
+        };
+
+        // Un carácter cambiado (como una tilde que falta), luego intercambiado,
+        // sobrante o ausente
+        for i in 0..chars.len() {
+            for c in &try_chars {
+                if *c != chars[i] {
+                    let mut edited = chars.clone();
+                    edited[i] = *c;
+                    candidates.push(edited.iter().collect());
+                }
+            }
+        }
+        for i in 0..chars.len().saturating_sub(1) {
+            let mut edited = chars.clone();
+            edited.swap(i, i + 1);
+            candidates.push(edited.iter().collect());
+        }
+        for i in 0..chars.len() {
+            let mut edited = chars.clone();
+            edited.remove(i);
+            candidates.push(edited.iter().collect());
+        }
+        for i in 0..=chars.len() {
+            for c in &try_chars {
+                let mut edited = chars.clone();
+                edited.insert(i, *c);
+                candidates.push(edited.iter().collect());
+            }
+        }
+        // Dos palabras pegadas
+        for i in 1..chars.len() {
+            let (first, second): (String, String) =
+                (chars[..i].iter().collect(), chars[i..].iter().collect());
+            if self.check(&first) && self.check(&second) {
+                candidates.push(format!("{} {}", first, second));
+            }
+        }
+
+        let first_upper = word.chars().next().is_some_and(char::is_uppercase);
+        let mut suggestions: Vec<String> = Vec::new();
+        for candidate in candidates {
+            if candidate.is_empty() || candidate == word || candidate == lower {
+                continue;
+            }
+            let valid = candidate.split(' ').all(|w| self.check(w));
+            let candidate = if first_upper && !candidate.starts_with(char::is_uppercase) {
+                capitalize(&candidate)
+            } else {
+                candidate
+            };
+            if valid && !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+                if suggestions.len() == MAX_SUGGESTIONS {
+                    break;
+                }
+            }
+        }
+
+        suggestions
+    }
+
+    fn check_exact(&self, word: &str) -> bool {
+        if let Some(flags) = self.words.get(word) {
+            let needs_affix = self.need_affix.as_ref().is_some_and(|f| flags.contains(f));
+            if !needs_affix && !self.is_forbidden(flags) {
+                return true;
+            }
+        }
+
+        self.check_suffixed(word, None) || self.check_prefixed(word)
+    }
+
+    fn is_forbidden(&self, flags: &HashSet<String>) -> bool {
+        self.forbidden.as_ref().is_some_and(|f| flags.contains(f))
+    }
+
+    /// Busca una raíz que con un sufijo forme la palabra; con `prefix`, la raíz
+    /// también debe admitir ese prefijo
+    fn check_suffixed(&self, word: &str, prefix: Option<&Affix>) -> bool {
+        for (i, _) in word.char_indices().chain([(word.len(), ' ')]) {
+            let Some(rules) = self.suffixes.get(&word[i..]) else {
+                continue;
+            };
+            for rule in rules {
+                if prefix.is_some() && !rule.cross_product {
+                    continue;
+                }
+                let stem = format!("{}{}", &word[..i], rule.strip);
+                if stem.is_empty() || !condition_matches(&rule.condition, &stem, false) {
+                    continue;
+                }
+                if let Some(flags) = self.words.get(&stem) {
+                    let has_prefix = prefix.is_none_or(|p| flags.contains(&p.flag));
+                    if flags.contains(&rule.flag) && has_prefix && !self.is_forbidden(flags) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn check_prefixed(&self, word: &str) -> bool {
+        for (i, _) in word.char_indices().skip(1).chain([(word.len(), ' ')]) {
+            let Some(rules) = self.prefixes.get(&word[..i]) else {
+                continue;
+            };
+            for rule in rules {
+                let stem = format!("{}{}", rule.strip, &word[i..]);
+                if stem.is_empty() || !condition_matches(&rule.condition, &stem, true) {
+                    continue;
+                }
+                if let Some(flags) = self.words.get(&stem) {
+                    if flags.contains(&rule.flag) && !self.is_forbidden(flags) {
+                        return true;
+                    }
+                }
+                if rule.cross_product && self.check_suffixed(&stem, Some(rule)) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Palabras de un texto que no están en el diccionario, con su posición en bytes
+///
+/// Se ignoran las siglas en mayúsculas y las palabras con números, como `HTTP` o `v2`.
+pub fn misspelled_words(dictionary: &Dictionary, text: &str) -> Vec<(usize, String)> {
+    words_of(text)
+        .into_iter()
+        .filter(|(_, word)| {
+            let is_acronym = word.chars().count() > 1 && word.chars().all(char::is_uppercase);
+            !is_acronym && !dictionary.check(word)
+        })
+        .map(|(start, word)| (start, word.to_string()))
+        .collect()
+}
+
+/// Palabras de un texto con su posición en bytes, sin las que contienen números
+pub fn words_of(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let is_word_char = c.is_alphanumeric() || c == '\'' || c == '’';
+        match (start, is_word_char) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                let word = text[s..i].trim_matches(|c| c == '\'' || c == '’');
+                let offset = s + text[s..i].find(word).unwrap_or(0);
+                if !word.is_empty() && !word.chars().any(|c| c.is_numeric()) {
+                    words.push((offset, word));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+/// Lee un archivo del diccionario con la codificación de `SET`
+///
+/// Las codificaciones desconocidas se leen como UTF-8.
+fn read_text(path: &Path, encoding: Option<&str>) -> io::Result<String> {
+    let bytes = fs::read(path)?;
+
+    let encoding = encoding
+        .and_then(|name| Encoding::for_label(encoding_label(name).as_bytes()))
+        .unwrap_or(UTF_8);
+    let (text, _, _) = encoding.decode(&bytes);
+
+    Ok(text.into_owned())
+}
+
+/// Nombre de la codificación de `SET` tal como lo reconoce `encoding_rs`
+///
+/// Hunspell usa algunos nombres propios, como `microsoft-cp1251` o `TIS620-2533`.
+fn encoding_label(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.as_str() {
+        "microsoft-cp1251" => String::from("windows-1251"),
+        "tis620-2533" => String::from("tis-620"),
+        _ => name,
+    }
+}
+
+fn parse_flags(flags: &str, mode: FlagMode) -> HashSet<String> {
+    match mode {
+        FlagMode::Char => flags.chars().map(String::from).collect(),
+        FlagMode::Long => flags
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|pair| pair.iter().collect())
+            .collect(),
+        FlagMode::Num => flags.split(',').map(|f| f.trim().to_string()).collect(),
+    }
+}
+
+/// Separa `palabra/marcas`, teniendo en cuenta las barras escapadas como `\/`
+fn split_entry(entry: &str) -> (String, Option<&str>) {
+    let bytes = entry.as_bytes();
+    for i in 1..bytes.len() {
+        if bytes[i] == b'/' && bytes[i - 1] != b'\\' {
+            return (entry[..i].replace("\\/", "/"), Some(&entry[i + 1..]));
+        }
+    }
+    (entry.replace("\\/", "/"), None)
+}
+
+fn empty_if_zero(text: &str) -> String {
+    if text == "0" {
+        String::new()
+    } else {
+        text.to_string()
+    }
+}
+
+fn parse_condition(condition: &str) -> Vec<ConditionPart> {
+    let mut parts = Vec::new();
+    let mut chars = condition.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => parts.push(ConditionPart::Any),
+            '[' => {
+                let mut set: Vec<char> = chars.by_ref().take_while(|c| *c != ']').collect();
+                let negated = set.first() == Some(&'^');
+                if negated {
+                    set.remove(0);
+                }
+                parts.push(ConditionPart::Set {
+                    chars: set,
+                    negated,
+                });
+            }
+            c => parts.push(ConditionPart::Char(c)),
+        }
+    }
+
+    // Una condición `.` sola equivale a no tener condición
+    if matches!(parts.as_slice(), [ConditionPart::Any]) {
+        parts.clear();
+    }
+
+    parts
+}
+
+/// Comprueba la condición al principio (prefijos) o al final (sufijos) de la raíz
+fn condition_matches(condition: &[ConditionPart], stem: &str, at_start: bool) -> bool {
+    let chars: Vec<char> = stem.chars().collect();
+    if condition.len() > chars.len() {
+        return false;
+    }
+
+    let offset = if at_start {
+        0
+    } else {
+        chars.len() - condition.len()
+    };
+
+    condition.iter().enumerate().all(|(i, part)| {
+        let c = chars[offset + i];
+        match part {
+            ConditionPart::Any => true,
+            ConditionPart::Char(expected) => c == *expected,
+            ConditionPart::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    })
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/hunspell");
+
+    fn spanish() -> Dictionary {
+        Dictionary::find("es_TEST", Some(FIXTURES)).unwrap()
+    }
+
+    #[test]
+    fn checks_stems_and_suffixes_with_conditions() {
+        let dictionary = spanish();
+
+        assert!(dictionary.check("casa"));
+        assert!(dictionary.check("casas"));
+        assert!(dictionary.check("camiones"));
+        assert!(!dictionary.check("camiónes"));
+        assert!(dictionary.check("luces"));
+        assert!(!dictionary.check("luzes"));
+        assert!(!dictionary.check("casaes"));
+    }
+
+    #[test]
+    fn rules_that_strip_a_literal_y_or_n_are_not_headers() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("xx_TEST.aff"),
+            "SET UTF-8\n\
+             SFX A Y 2\n\
+             SFX A N NES N\n\
+             SFX A Y IES Y\n\
+             PFX B N 1\n\
+             PFX B 0 RE .\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("xx_TEST.dic"),
+            "3\nCAMION/A\nTOY/A\nCAMION/B\n",
+        )
+        .unwrap();
+
+        let dictionary = Dictionary::find("xx_TEST", dir.path().to_str()).unwrap();
+
+        assert!(dictionary.check("CAMIONES"));
+        assert!(dictionary.check("TOIES"));
+        assert!(dictionary.check("RECAMION"));
+        // El grupo B no admite combinarse con sufijos
+        assert!(!dictionary.check("RECAMIONES"));
+        assert!(!dictionary.check("CAMIONNES"));
+    }
+
+    #[test]
+    fn checks_prefixes_and_cross_products() {
+        let dictionary = spanish();
+
+        assert!(dictionary.check("recarga"));
+        assert!(dictionary.check("recargas"));
+        assert!(!dictionary.check("recasa"));
+    }
+
+    #[test]
+    fn applies_need_affix_and_forbidden_word() {
+        let dictionary = spanish();
+
+        assert!(!dictionary.check("trabaj"));
+        assert!(dictionary.check("trabajo"));
+        assert!(dictionary.check("trabajar"));
+        // Los sufijos sin combinación no admiten prefijos
+        assert!(!dictionary.check("retrabajo"));
+        assert!(!dictionary.check("mounstruo"));
+        assert!(dictionary.check("monstruo"));
+    }
+
+    #[test]
+    fn accepts_capitalized_and_upper_case_forms() {
+        let dictionary = spanish();
+
+        assert!(dictionary.check("Casa"));
+        assert!(dictionary.check("CASAS"));
+        assert!(!dictionary.check("CASAES"));
+    }
+
+    #[test]
+    fn suggests_accents_replacements_and_split_words() {
+        let dictionary = spanish();
+
+        assert_eq!(
+            dictionary.suggest("camion").first().map(String::as_str),
+            Some("camión")
+        );
+        assert_eq!(
+            dictionary.suggest("Camion").first().map(String::as_str),
+            Some("Camión")
+        );
+        assert_eq!(
+            dictionary.suggest("vurro").first().map(String::as_str),
+            Some("burro")
+        );
+        assert!(dictionary.suggest("cassa").contains(&String::from("casa")));
+        assert!(dictionary
+            .suggest("lacasa")
+            .contains(&String::from("la casa")));
+        assert!(!dictionary
+            .suggest("monstruo")
+            .contains(&String::from("mounstruo")));
+        assert!(dictionary.suggest("xyzzy").is_empty());
+    }
+
+    #[test]
+    fn reads_iso8859_15_long_flags_and_aliases() {
+        let dictionary = Dictionary::find("fr_TEST", Some(FIXTURES)).unwrap();
+
+        assert_eq!(dictionary.len(), 3);
+        // `œ` solo existe en ISO8859-15; en Latin-1 el mismo byte es `½`
+        assert!(dictionary.check("cœur"));
+        assert!(dictionary.check("cœurs"));
+        assert!(dictionary.check("œuvres"));
+        assert!(dictionary.check("amie"));
+        assert!(dictionary.check("amis"));
+        assert!(!dictionary.check("cœure"));
+    }
+
+    #[test]
+    fn reports_missing_dictionaries() {
+        let error = Dictionary::find("xx_XX", Some(FIXTURES)).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().contains(FIXTURES));
+        assert!(error.to_string().contains("apt install hunspell-es"));
+    }
+
+    #[test]
+    fn finds_misspelled_words_with_offsets() {
+        let dictionary = spanish();
+
+        let misspelled = misspelled_words(&dictionary, "La casa del camion HTTP v2");
+
+        assert_eq!(
+            misspelled,
+            vec![(8, String::from("del")), (12, String::from("camion"))]
+        );
+    }
+}
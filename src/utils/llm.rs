@@ -0,0 +1,347 @@
+use reqwest::blocking::{Client, Response};
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+use crate::config::{Config, LlmConfig};
+
+/// Espera máxima entre reintentos, en segundos
+const MAX_BACKOFF_SECS: u64 = 30;
+
+/// Proveedor de chat compatible con la API de OpenAI (`POST {base_url}/chat/completions`)
+///
+/// Reutiliza un único cliente HTTP con tiempo máximo de espera para todas las peticiones.
+#[derive(Debug, Clone)]
+pub struct LlmProvider {
+    pub base_url: String,
+    pub model: String,
+    /// Variable de entorno de la clave, o vacía si el servidor no la necesita
+    pub api_key_env: String,
+    pub prompt: String,
+    pub generation_prompt: String,
+    pub max_retries: u32,
+    pub batch_size: usize,
+    pub cache: bool,
+    client: Client,
+}
+
+impl LlmProvider {
+    /// Crea el proveedor a partir de la sección `[llm]`
+    pub fn from_config(config: &LlmConfig) -> io::Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .map_err(io::Error::other)?;
+
+        Ok(LlmProvider {
+            base_url: config.base_url.trim().trim_end_matches('/').to_string(),
+            model: config.model.clone(),
+            api_key_env: config.api_key_env.trim().to_string(),
+            prompt: config.prompt.clone(),
+            generation_prompt: config.generation_prompt.clone(),
+            max_retries: config.max_retries,
+            batch_size: config.batch_size.max(1),
+            cache: config.cache,
+            client,
+        })
+    }
+
+    /// Carga el proveedor de `test_case_manager.toml`, o el de Groq por defecto
+    pub fn load() -> io::Result<Self> {
+        LlmProvider::from_config(&Config::load()?.llm)
+    }
+
+    /// Clave de la API, si el proveedor la usa y está definida
+    fn api_key(&self) -> Option<String> {
+        if self.api_key_env.is_empty() {
+            return None;
+        }
+        std::env::var(&self.api_key_env).ok()
+    }
+
+    /// Indica si el proveedor necesita una clave que no está definida
+    pub fn missing_api_key(&self) -> bool {
+        !self.api_key_env.is_empty() && self.api_key().is_none()
+    }
+
+    /// Descripción del proveedor para los mensajes, como `llama3 en http://localhost:11434/v1`
+    pub fn describe(&self) -> String {
+        format!("{} en {}", self.model, self.base_url)
+    }
+
+    /// Envía un mensaje con las instrucciones de sistema indicadas y devuelve la respuesta
+    pub fn chat(&self, system: &str, user: &str) -> io::Result<String> {
+        self.complete(system, user, false)
+    }
+
+    /// Como [`LlmProvider::chat`], pidiendo que la respuesta sea un objeto JSON
+    pub fn chat_json(&self, system: &str, user: &str) -> io::Result<String> {
+        self.complete(system, user, true)
+    }
+
+    fn complete(&self, system: &str, user: &str, json_response: bool) -> io::Result<String> {
+        if self.missing_api_key() {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "No se encontró la clave API en la variable de entorno {}",
+                    self.api_key_env
+                ),
+            ));
+        }
+
+        let mut request_body = serde_json::json!({
+            "messages": [
+                { "role": "system", "content": system },
+                { "role": "user", "content": user }
+            ],
+            "model": self.model
+        });
+        if json_response {
+            request_body["response_format"] = serde_json::json!({ "type": "json_object" });
+        }
+
+        let response = self.send_with_retries(&request_body)?;
+
+        let status = response.status();
+        let json: Value = response.json().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Error al parsear la respuesta JSON: {}", e),
+            )
+        })?;
+
+        if !status.is_success() {
+            let message = json
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| status.to_string());
+            return Err(io::Error::other(format!(
+                "Error del proveedor LLM: {}",
+                message
+            )));
+        }
+
+        json.pointer("/choices/0/message/content")
+            .and_then(|c| c.as_str())
+            .map(|c| c.trim().to_string())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "La respuesta del proveedor LLM no incluye ningún mensaje",
+                )
+            })
+    }
+
+    /// Envía la petición y la repite ante límites de uso (429), errores del
+    /// servidor (5xx) o tiempos de espera agotados
+    ///
+    /// La espera es la indicada en `Retry-After` o, si no la hay, 1, 2, 4... segundos.
+    fn send_with_retries(&self, body: &Value) -> io::Result<Response> {
+        let url = format!("{}/chat/completions", self.base_url);
+        let mut attempt = 0;
+
+        loop {
+            let mut request = self.client.post(&url).json(body);
+            if let Some(api_key) = self.api_key() {
+                request = request.bearer_auth(api_key);
+            }
+
+            let retry_after = match request.send() {
+                Ok(response) if is_retryable(response.status()) => response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok()),
+                Ok(response) => return Ok(response),
+                Err(e) if e.is_timeout() && attempt < self.max_retries => None,
+                Err(e) => {
+                    return Err(io::Error::other(format!(
+                        "Error al conectar con el proveedor LLM ({}): {}",
+                        self.base_url, e
+                    )))
+                }
+            };
+
+            if attempt >= self.max_retries {
+                return Err(io::Error::other(format!(
+                    "El proveedor LLM sigue sin responder después de {} reintentos",
+                    self.max_retries
+                )));
+            }
+
+            let wait = retry_after
+                .unwrap_or_else(|| backoff_secs(attempt))
+                .min(MAX_BACKOFF_SECS);
+            thread::sleep(Duration::from_secs(wait));
+            attempt += 1;
+        }
+    }
+}
+
+/// Quita el bloque de código (```` ```json ````) con el que algunos modelos envuelven la respuesta
+pub fn strip_code_fence(content: &str) -> &str {
+    let content = content.trim();
+    content
+        .strip_prefix("```json")
+        .or_else(|| content.strip_prefix("```"))
+        .and_then(|c| c.strip_suffix("```"))
+        .map(str::trim)
+        .unwrap_or(content)
+}
+
+/// Espera antes del reintento indicado: 1, 2, 4... segundos, hasta [`MAX_BACKOFF_SECS`]
+///
+/// `max_retries` no tiene límite en la configuración, así que el desplazamiento
+/// no debe desbordarse.
+fn backoff_secs(attempt: u32) -> u64 {
+    1u64.checked_shl(attempt)
+        .unwrap_or(MAX_BACKOFF_SECS)
+        .min(MAX_BACKOFF_SECS)
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockResponse, MockServer};
+
+    fn provider_for(server: &MockServer, api_key_env: &str, max_retries: u32) -> LlmProvider {
+        LlmProvider::from_config(&LlmConfig {
+            base_url: format!("{}/v1/", server.url),
+            model: "llama3".to_string(),
+            api_key_env: api_key_env.to_string(),
+            max_retries,
+            timeout_secs: 5,
+            ..LlmConfig::default()
+        })
+        .unwrap()
+    }
+
+    fn reply(content: &str) -> MockResponse {
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "choices": [{ "message": { "role": "assistant", "content": content } }]
+            })
+            .to_string(),
+        )
+    }
+
+    #[test]
+    fn chat_sends_the_messages_and_returns_the_trimmed_reply() {
+        std::env::set_var("TCM_TEST_LLM_KEY", "clave-123");
+        let server = MockServer::start(vec![reply("  Texto corregido\n")]);
+        let provider = provider_for(&server, "TCM_TEST_LLM_KEY", 0);
+
+        let content = provider.chat("Corrige", "Texto corejido").unwrap();
+
+        assert_eq!(content, "Texto corregido");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/v1/chat/completions");
+        assert_eq!(
+            requests[0].header("Authorization"),
+            Some("Bearer clave-123")
+        );
+
+        let body = requests[0].json();
+        assert_eq!(body["model"], "llama3");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][0]["content"], "Corrige");
+        assert_eq!(body["messages"][1]["role"], "user");
+        assert_eq!(body["messages"][1]["content"], "Texto corejido");
+        assert!(body.get("response_format").is_none());
+    }
+
+    #[test]
+    fn chat_json_asks_for_a_json_object_without_api_key() {
+        let server = MockServer::start(vec![reply("```json\n{\"casos\": []}\n```")]);
+        let provider = provider_for(&server, "", 0);
+
+        let content = provider.chat_json("Genera", "Requisito").unwrap();
+
+        assert_eq!(strip_code_fence(&content), "{\"casos\": []}");
+        let requests = server.requests();
+        assert_eq!(requests[0].header("Authorization"), None);
+        assert_eq!(requests[0].json()["response_format"]["type"], "json_object");
+    }
+
+    #[test]
+    fn retries_rate_limits_and_server_errors() {
+        let server = MockServer::start(vec![
+            MockResponse::json(429, r#"{"error":{"message":"Rate limit"}}"#)
+                .with_header("Retry-After", "0"),
+            MockResponse::json(503, r#"{"error":{"message":"Ocupado"}}"#)
+                .with_header("Retry-After", "0"),
+            reply("Listo"),
+        ]);
+        let provider = provider_for(&server, "", 2);
+
+        assert_eq!(provider.chat("Corrige", "Texto").unwrap(), "Listo");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|r| r.body == requests[0].body));
+    }
+
+    #[test]
+    fn gives_up_after_the_configured_retries() {
+        let server = MockServer::start(vec![
+            MockResponse::json(500, "{}").with_header("Retry-After", "0"),
+            MockResponse::json(502, "{}").with_header("Retry-After", "0"),
+        ]);
+        let provider = provider_for(&server, "", 1);
+
+        let error = provider.chat("Corrige", "Texto").unwrap_err();
+
+        assert!(error.to_string().contains("después de 1 reintentos"));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum_without_overflowing() {
+        assert_eq!(backoff_secs(0), 1);
+        assert_eq!(backoff_secs(1), 2);
+        assert_eq!(backoff_secs(4), 16);
+        assert_eq!(backoff_secs(5), MAX_BACKOFF_SECS);
+        assert_eq!(backoff_secs(63), MAX_BACKOFF_SECS);
+        assert_eq!(backoff_secs(64), MAX_BACKOFF_SECS);
+        assert_eq!(backoff_secs(u32::MAX), MAX_BACKOFF_SECS);
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let server = MockServer::start(vec![MockResponse::json(
+            400,
+            r#"{"error":{"message":"Modelo desconocido"}}"#,
+        )]);
+        let provider = provider_for(&server, "", 3);
+
+        let error = provider.chat("Corrige", "Texto").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Error del proveedor LLM: Modelo desconocido"
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn missing_api_key_fails_before_sending() {
+        let server = MockServer::start(Vec::new());
+        let provider = provider_for(&server, "TCM_TEST_LLM_UNSET_KEY", 0);
+
+        let error = provider.chat("Corrige", "Texto").unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(server.requests().is_empty());
+    }
+}
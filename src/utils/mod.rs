@@ -1,5 +1,11 @@
-pub mod file_operations;
+pub mod diff;
+pub mod generation;
+pub mod hunspell;
+pub mod llm;
 pub mod spelling;
 
-pub use file_operations::*;
+pub use diff::*;
+pub use generation::*;
+pub use hunspell::*;
+pub use llm::*;
 pub use spelling::*;
@@ -1,113 +1,655 @@
 use colored::*;
-use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
 
-/// Corrige la ortografía de un texto utilizando la API de Groq
+use crate::utils::{strip_code_fence, LlmProvider};
+
+/// Caché en disco de las correcciones del proveedor LLM
+pub const SPELLING_CACHE_FILE: &str = ".cache/spelling.json";
+
+/// Corrige la ortografía de un texto con el proveedor LLM de `test_case_manager.toml`
 pub fn correct_spelling(text: &str) -> String {
-    // Si el texto está vacío, devolverlo tal cual
-    if text.trim().is_empty() {
-        return text.to_string();
+    match LlmProvider::load() {
+        Ok(provider) => correct_spelling_with(&provider, text),
+        Err(e) => {
+            println!("{}", format!("{}. Usando texto original.", e).yellow());
+            text.to_string()
+        }
     }
+}
+
+/// Corrige la ortografía de un texto con un proveedor LLM
+///
+/// Si la corrección falla o no pasa [`check_correction`], informa el motivo y
+/// devuelve el texto original.
+pub fn correct_spelling_with(provider: &LlmProvider, text: &str) -> String {
+    correct_spelling_batch(provider, &[text.to_string()])
+        .pop()
+        .unwrap_or_else(|| text.to_string())
+}
+
+/// Corrige la ortografía de varios textos, en el mismo orden
+///
+/// Los textos se envían en lotes de `batch_size` pidiendo una respuesta JSON;
+/// si un lote no se puede interpretar, sus textos se corrigen de a uno.
+///
+/// Se leen de [`SPELLING_CACHE_FILE`] las decisiones ya tomadas, pero solo se
+/// guardan los textos que no necesitan cambios: las correcciones propuestas se
+/// guardan con [`remember_corrections`] cuando el usuario las revisa.
+pub fn correct_spelling_batch(provider: &LlmProvider, texts: &[String]) -> Vec<String> {
+    let mut cache = if provider.cache {
+        SpellingCache::load()
+    } else {
+        SpellingCache::default()
+    };
 
-    // Obtener la clave API de Groq desde una variable de entorno
-    let api_key = match std::env::var("GROQ_API_KEY") {
-        Ok(key) => key,
-        Err(_) => {
+    let mut corrected: Vec<Option<String>> = texts
+        .iter()
+        .map(|text| {
+            if text.trim().is_empty() {
+                Some(text.clone())
+            } else {
+                cache.get(provider, text).map(str::to_string)
+            }
+        })
+        .collect();
+
+    let pending: Vec<usize> = (0..texts.len())
+        .filter(|i| corrected[*i].is_none())
+        .collect();
+    let batches: Vec<&[usize]> = pending.chunks(provider.batch_size).collect();
+
+    for (n, batch) in batches.iter().enumerate() {
+        if batches.len() > 1 {
             println!(
                 "{}",
-                "No se encontró la clave API de Groq. Usando texto original.".yellow()
+                format!(
+                    "Lote {}/{} ({} textos)...",
+                    n + 1,
+                    batches.len(),
+                    batch.len()
+                )
+                .blue()
             );
-            return text.to_string();
         }
-    };
 
-    // Crear cliente HTTP
-    let client = Client::new();
-
-    // Crear el cuerpo de la solicitud para la API de Groq
-    let request_body = serde_json::json!({
-        "messages": [
-            {
-                "role": "system",
-                "content": "Eres un asistente especializado en corrección ortográfica y gramatical en español. Tu tarea es corregir errores ortográficos y gramaticales en el texto proporcionado, manteniendo el significado original. Solo debes devolver el texto corregido, sin explicaciones ni comentarios adicionales."
-            },
-            {
-                "role": "user",
-                "content": format!("Corrige la ortografía y gramática del siguiente texto, manteniendo su significado original: \"{}\"", text)
+        let batch_texts: Vec<&str> = batch.iter().map(|i| texts[*i].as_str()).collect();
+        let responses = match request_batch(provider, &batch_texts) {
+            Ok(responses) => responses.into_iter().map(Ok).collect(),
+            Err(e) if batch.len() > 1 => {
+                println!(
+                    "{}",
+                    format!("{}. Se corrigen los textos de a uno.", e).yellow()
+                );
+                batch_texts
+                    .iter()
+                    .map(|text| request_single(provider, text))
+                    .collect()
             }
-        ],
-        "model": "llama3-8b-8192"
-    });
-
-    // Realizar la solicitud a la API de Groq
-    match client
-        .post("https://api.groq.com/openai/v1/chat/completions")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("Content-Type", "application/json")
-        .json(&request_body)
-        .send()
-    {
-        Ok(response) => {
-            let status = response.status();
-            if status.is_success() {
-                match response.json::<Value>() {
-                    Ok(json) => {
-                        if let Some(choices) = json.get("choices").and_then(|c| c.as_array()) {
-                            if let Some(first_choice) = choices.first() {
-                                if let Some(message) = first_choice.get("message") {
-                                    if let Some(content) = message.get("content").and_then(|c| c.as_str()) {
-                                        println!(
-                                            "{}",
-                                            "Corrección ortográfica completada.".green()
-                                        );
-                                        return content.to_string();
-                                    }
-                                }
-                            }
+            Err(e) => vec![Err(e)],
+        };
+
+        for (i, response) in batch.iter().zip(responses) {
+            let text = &texts[*i];
+            corrected[*i] = Some(match response {
+                Ok(response) => match check_correction(text, &response) {
+                    Ok(checked) => {
+                        if checked == *text {
+                            cache.insert(provider, text, text);
                         }
-                        println!(
-                            "{}",
-                            "No se pudo obtener la respuesta de la API de Groq. Usando texto original.".yellow()
-                        );
+                        checked
                     }
-                    Err(e) => {
+                    Err(reason) => {
                         println!(
                             "{}",
-                            format!("Error al procesar la respuesta de la API de Groq: {}. Usando texto original.", e).red()
+                            format!(
+                                "Se descarta la corrección de '{}': {}. Usando texto original.",
+                                text, reason
+                            )
+                            .yellow()
                         );
+                        text.clone()
                     }
+                },
+                Err(e) => {
+                    println!("{}", e.to_string().red());
+                    println!(
+                        "{}",
+                        format!("No se pudo corregir '{}'. Usando texto original.", text).yellow()
+                    );
+                    text.clone()
                 }
-            } else {
-                match response.json::<Value>() {
-                    Ok(error_json) => {
-                        if let Some(error) = error_json.get("error").and_then(|e| e.as_object()) {
-                            let message = error
-                                .get("message")
-                                .and_then(|m| m.as_str())
-                                .unwrap_or("Error desconocido");
-                            println!("{}", format!("Error de la API de Groq: {}", message).red());
-                        } else {
-                            println!("{}", format!("Error de la API de Groq: {}", status).red());
-                        }
-                    }
-                    Err(_) => {
-                        println!("{}", format!("Error de la API de Groq: {}", status).red());
-                    }
-                }
-            }
+            });
         }
-        Err(e) => {
-            println!(
-                "{}",
-                format!("Error al conectar con la API de Groq: {}", e).red()
+    }
+
+    if !pending.is_empty() {
+        save_cache(provider, &cache);
+    }
+
+    corrected
+        .into_iter()
+        .zip(texts)
+        .map(|(c, text)| c.unwrap_or_else(|| text.clone()))
+        .collect()
+}
+
+/// Guarda en la caché la decisión del usuario sobre cada corrección propuesta
+///
+/// Recibe pares de texto original y texto final: la corrección aceptada o
+/// editada, o el original si se rechazó. Así una corrección rechazada no se
+/// vuelve a proponer.
+pub fn remember_corrections(provider: &LlmProvider, decisions: &[(String, String)]) {
+    if !provider.cache || decisions.is_empty() {
+        return;
+    }
+
+    let mut cache = SpellingCache::load();
+    for (original, decided) in decisions {
+        cache.insert(provider, original, decided);
+    }
+    save_cache(provider, &cache);
+}
+
+fn save_cache(provider: &LlmProvider, cache: &SpellingCache) {
+    if !provider.cache {
+        return;
+    }
+
+    if let Err(e) = cache.save() {
+        println!(
+            "{}",
+            format!("No se pudo guardar la caché de correcciones: {}", e).yellow()
+        );
+    }
+}
+
+/// Corrige un lote de textos en una sola petición con respuesta JSON
+fn request_batch(provider: &LlmProvider, texts: &[&str]) -> io::Result<Vec<String>> {
+    let system = format!(
+        "{}\n\nRecibirás un arreglo JSON de textos. Corrige cada uno por separado y responde solo con un objeto JSON de la forma {{\"corrections\": [\"texto 1 corregido\", \"texto 2 corregido\"]}}, con la misma cantidad de elementos y en el mismo orden.",
+        provider.prompt
+    );
+    let user = serde_json::to_string(texts).map_err(io::Error::other)?;
+
+    let content = provider.chat_json(&system, &user)?;
+    let corrections = parse_corrections(&content).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "El proveedor LLM no devolvió una lista de correcciones válida",
+        )
+    })?;
+
+    if corrections.len() != texts.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "El proveedor LLM devolvió {} correcciones para {} textos",
+                corrections.len(),
+                texts.len()
+            ),
+        ));
+    }
+
+    Ok(corrections)
+}
+
+/// Corrige un único texto con una respuesta de texto libre
+fn request_single(provider: &LlmProvider, text: &str) -> io::Result<String> {
+    let user_message = format!(
+        "Corrige los errores ortográficos y gramaticales en el siguiente texto, manteniendo su significado original: {}",
+        text
+    );
+    provider.chat(&provider.prompt, &user_message)
+}
+
+/// Lee `{"corrections": [...]}` o un arreglo, aunque venga en un bloque de código
+fn parse_corrections(content: &str) -> Option<Vec<String>> {
+    let json: Value = serde_json::from_str(strip_code_fence(content)).ok()?;
+    let list = match &json {
+        Value::Array(_) => &json,
+        _ => json.get("corrections")?,
+    };
+
+    list.as_array()?
+        .iter()
+        .map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+/// Correcciones guardadas en disco, indexadas por el hash del servidor, el
+/// modelo, las instrucciones y el texto
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SpellingCache {
+    entries: BTreeMap<String, String>,
+}
+
+impl SpellingCache {
+    /// Carga la caché; si no existe o está dañada, empieza vacía
+    fn load() -> Self {
+        fs::read_to_string(SPELLING_CACHE_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> io::Result<()> {
+        if let Some(dir) = Path::new(SPELLING_CACHE_FILE).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(SPELLING_CACHE_FILE, content)
+    }
+
+    fn get(&self, provider: &LlmProvider, text: &str) -> Option<&str> {
+        self.entries
+            .get(&cache_key(provider, text))
+            .map(String::as_str)
+    }
+
+    fn insert(&mut self, provider: &LlmProvider, text: &str, corrected: &str) {
+        self.entries
+            .insert(cache_key(provider, text), corrected.to_string());
+    }
+}
+
+/// Hash FNV-1a de 64 bits, estable entre versiones a diferencia de `DefaultHasher`
+///
+/// Incluye el servidor además del modelo: el mismo nombre de modelo puede
+/// corresponder a modelos distintos en Groq y en un servidor local.
+fn cache_key(provider: &LlmProvider, text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for part in [
+        provider.base_url.as_str(),
+        provider.model.as_str(),
+        provider.prompt.as_str(),
+        text,
+    ] {
+        for byte in part.bytes().chain([0]) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Pares de comillas con los que los modelos suelen envolver la respuesta
+const QUOTE_PAIRS: [(char, char); 5] =
+    [('"', '"'), ('“', '”'), ('\'', '\''), ('«', '»'), ('`', '`')];
+
+/// Diferencia de longitud que se acepta siempre, en caracteres
+const MIN_LENGTH_CHANGE: usize = 10;
+
+/// Proporción máxima de cambio de longitud respecto al texto original
+const MAX_LENGTH_CHANGE_RATIO: f64 = 0.3;
+
+/// Proporción mínima de palabras de cada texto que deben tener una parecida en el otro
+const MIN_WORD_OVERLAP: f64 = 0.5;
+
+/// Valida la respuesta de un corrector antes de ofrecerla
+///
+/// Quita las comillas que envuelven la respuesta si el original no las tenía y
+/// rechaza las respuestas vacías, las que cambian demasiado la longitud y las
+/// que comparten pocas palabras con el original: suelen ser reescrituras o
+/// explicaciones en lugar de correcciones. La comparación de palabras cubre
+/// los textos cortos, en los que la diferencia de longitud siempre se acepta.
+pub fn check_correction(original: &str, corrected: &str) -> Result<String, String> {
+    let original = original.trim();
+    let mut corrected = corrected.trim();
+
+    while let Some(inner) = strip_quotes(corrected) {
+        if strip_quotes(original).is_some() {
+            break;
+        }
+        corrected = inner.trim();
+    }
+
+    if corrected.is_empty() {
+        return Err(String::from("la respuesta está vacía"));
+    }
+
+    let before = original.chars().count();
+    let after = corrected.chars().count();
+    let allowed = MIN_LENGTH_CHANGE.max((before as f64 * MAX_LENGTH_CHANGE_RATIO) as usize);
+    if before.abs_diff(after) > allowed {
+        return Err(format!(
+            "la longitud cambia de {} a {} caracteres",
+            before, after
+        ));
+    }
+
+    let original_words = normalized_words(original);
+    let corrected_words = normalized_words(corrected);
+    if word_overlap(&original_words, &corrected_words) < MIN_WORD_OVERLAP
+        || word_overlap(&corrected_words, &original_words) < MIN_WORD_OVERLAP
+    {
+        return Err(String::from(
+            "la respuesta no conserva las palabras del original",
+        ));
+    }
+
+    Ok(corrected.to_string())
+}
+
+/// Palabras en minúsculas y sin la puntuación que las rodea
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Proporción de `words` que tienen una palabra parecida en `other`
+///
+/// Dos palabras se parecen si difieren en un carácter, o en un tercio de sus
+/// caracteres en las palabras largas: tildes, letras cambiadas o que faltan.
+fn word_overlap(words: &[String], other: &[String]) -> f64 {
+    if words.is_empty() {
+        return 1.0;
+    }
+
+    let similar = words
+        .iter()
+        .filter(|word| {
+            other.iter().any(|candidate| {
+                let longest = word.chars().count().max(candidate.chars().count());
+                edit_distance(word, candidate) <= (longest / 3).max(1)
+            })
+        })
+        .count();
+
+    similar as f64 / words.len() as f64
+}
+
+/// Distancia de Levenshtein entre dos palabras, en caracteres
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Texto sin el par de comillas que lo envuelve, si lo tiene
+fn strip_quotes(text: &str) -> Option<&str> {
+    QUOTE_PAIRS.iter().find_map(|(open, close)| {
+        text.strip_prefix(*open)
+            .and_then(|rest| rest.strip_suffix(*close))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LlmConfig;
+    use crate::test_support::{MockResponse, MockServer, TempWorkingDir};
+
+    fn stub_provider(server: &MockServer, batch_size: usize, cache: bool) -> LlmProvider {
+        LlmProvider::from_config(&LlmConfig {
+            base_url: server.url.clone(),
+            model: "llama3".to_string(),
+            api_key_env: String::new(),
+            max_retries: 0,
+            batch_size,
+            cache,
+            ..LlmConfig::default()
+        })
+        .unwrap()
+    }
+
+    fn reply(content: &str) -> MockResponse {
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "choices": [{ "message": { "content": content } }] }).to_string(),
+        )
+    }
+
+    fn texts(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn sends_texts_in_batches_of_batch_size() {
+        let server = MockServer::start(vec![
+            reply(r#"{"corrections": ["Abrir el menú", "Pulsar el botón"]}"#),
+            reply(r#"["Guardar la configuración"]"#),
+        ]);
+        let provider = stub_provider(&server, 2, false);
+
+        let corrected = correct_spelling_batch(
+            &provider,
+            &texts(&[
+                "Abrir el menu",
+                "  ",
+                "Pulsar el boton",
+                "Guardar la configuracion",
+            ]),
+        );
+
+        assert_eq!(
+            corrected,
+            texts(&[
+                "Abrir el menú",
+                "  ",
+                "Pulsar el botón",
+                "Guardar la configuración"
+            ])
+        );
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let sent: Vec<Value> = requests
+            .iter()
+            .map(|r| {
+                serde_json::from_str(r.json()["messages"][1]["content"].as_str().unwrap()).unwrap()
+            })
+            .collect();
+        assert_eq!(
+            sent[0],
+            serde_json::json!(["Abrir el menu", "Pulsar el boton"])
+        );
+        assert_eq!(sent[1], serde_json::json!(["Guardar la configuracion"]));
+        assert!(requests
+            .iter()
+            .all(|r| r.json()["response_format"]["type"] == "json_object"));
+    }
+
+    #[test]
+    fn corrects_texts_one_by_one_when_a_batch_fails() {
+        let server = MockServer::start(vec![
+            // Una corrección para dos textos: el lote no se puede usar
+            reply(r#"{"corrections": ["Abrir el menú"]}"#),
+            reply("Abrir el menú"),
+            reply("Una explicación mucho más larga que el texto original que no sirve"),
+        ]);
+        let provider = stub_provider(&server, 5, false);
+
+        let corrected =
+            correct_spelling_batch(&provider, &texts(&["Abrir el menu", "Pulsar el boton"]));
+
+        // La segunda respuesta no pasa la validación y se conserva el original
+        assert_eq!(corrected, texts(&["Abrir el menú", "Pulsar el boton"]));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[1].json().get("response_format").is_none());
+        assert!(requests[2].json()["messages"][1]["content"]
+            .as_str()
+            .unwrap()
+            .ends_with("Pulsar el boton"));
+    }
+
+    #[test]
+    fn strips_quotes_added_by_the_model() {
+        assert_eq!(
+            check_correction("Abrir el menu", "  \"Abrir el menú\"\n"),
+            Ok("Abrir el menú".to_string())
+        );
+        assert_eq!(
+            check_correction("Abrir el menu", "«“Abrir el menú”»"),
+            Ok("Abrir el menú".to_string())
+        );
+        // Si el original ya tenía comillas, se conservan
+        assert_eq!(
+            check_correction("'Abrir el menu'", "'Abrir el menú'"),
+            Ok("'Abrir el menú'".to_string())
+        );
+        assert!(check_correction("Abrir el menu", "\"\"").is_err());
+        assert!(check_correction("Abrir el menu", "   ").is_err());
+    }
+
+    #[test]
+    fn rejects_rewrites_instead_of_corrections() {
+        // Correcciones de tildes, letras y puntuación
+        for (original, corrected) in [
+            ("Login OK", "Login OK."),
+            (
+                "Verificar que el boton funcione",
+                "Verificar que el botón funcione",
+            ),
+            ("Se muestra la region", "Se muestra la región"),
+            (
+                "Ingresr usario y contraseña",
+                "Ingresar usuario y contraseña",
+            ),
+            ("El ícono es un carito", "El ícono es un carrito"),
+        ] {
+            assert!(
+                check_correction(original, corrected).is_ok(),
+                "{} -> {}",
+                original,
+                corrected
             );
         }
+
+        // Un texto corto reescrito con una longitud parecida
+        assert!(check_correction("Login OK", "Todo va perfecto").is_err());
+        // Una explicación en lugar de la corrección
+        assert!(check_correction(
+            "Verificar el login",
+            "Aquí tienes el texto corregido sin errores"
+        )
+        .is_err());
+        // Cambios de longitud por encima de la proporción permitida
+        let original = "Verificar que el header tenga fondo blanco y el campo de búsqueda";
+        let longer = format!("{} y que además se vean todos los íconos", original);
+        assert!(check_correction(original, &longer).is_err());
+        assert!(check_correction(original, "Verificar el header").is_err());
     }
 
-    // En caso de error, devolver el texto original
-    println!(
-        "{}",
-        "No se pudo corregir el texto. Usando texto original.".yellow()
-    );
-    text.to_string()
+    #[test]
+    fn measures_word_edit_distance() {
+        assert_eq!(edit_distance("boton", "botón"), 1);
+        assert_eq!(edit_distance("usario", "usuario"), 1);
+        assert_eq!(edit_distance("casa", "casa"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("login", "logout"), 3);
+    }
+
+    #[test]
+    fn parses_correction_lists() {
+        let expected = Some(texts(&["uno", "dos"]));
+        assert_eq!(
+            parse_corrections(r#"{"corrections": ["uno", "dos"]}"#),
+            expected
+        );
+        assert_eq!(parse_corrections(r#"["uno", "dos"]"#), expected);
+        assert_eq!(
+            parse_corrections("```json\n{\"corrections\": [\"uno\", \"dos\"]}\n```"),
+            expected
+        );
+        assert_eq!(
+            parse_corrections(r#"{"corrections": []}"#),
+            Some(Vec::new())
+        );
+
+        assert_eq!(parse_corrections(r#"{"corrections": ["uno", 2]}"#), None);
+        assert_eq!(parse_corrections(r#"{"correcciones": ["uno"]}"#), None);
+        assert_eq!(parse_corrections(r#"{"corrections": "uno"}"#), None);
+        assert_eq!(parse_corrections("uno, dos"), None);
+    }
+
+    #[test]
+    fn caches_only_reviewed_decisions_and_unchanged_texts() {
+        let _dir = TempWorkingDir::new();
+        let server = MockServer::start(vec![reply(
+            r#"{"corrections": ["Abrir el menú", "Pulsar el botón", "Sin cambios"]}"#,
+        )]);
+        let provider = stub_provider(&server, 5, true);
+        let originals = texts(&["Abrir el menu", "Pulsar el boton", "Sin cambios"]);
+
+        let corrected = correct_spelling_batch(&provider, &originals);
+        assert_eq!(corrected[0], "Abrir el menú");
+        assert_eq!(server.requests().len(), 1);
+
+        let cache = SpellingCache::load();
+        assert_eq!(cache.get(&provider, "Sin cambios"), Some("Sin cambios"));
+        assert_eq!(cache.get(&provider, "Abrir el menu"), None);
+
+        // El usuario acepta la primera corrección y rechaza la segunda
+        remember_corrections(
+            &provider,
+            &[
+                (originals[0].clone(), corrected[0].clone()),
+                (originals[1].clone(), originals[1].clone()),
+            ],
+        );
+
+        // La siguiente corrección de los mismos textos no hace peticiones y no
+        // vuelve a proponer la corrección rechazada
+        let offline = MockServer::start(Vec::new());
+        let mut cached_provider = stub_provider(&offline, 5, true);
+        cached_provider.base_url = provider.base_url.clone();
+        assert_eq!(
+            correct_spelling_batch(&cached_provider, &originals),
+            texts(&["Abrir el menú", "Pulsar el boton", "Sin cambios"])
+        );
+        assert!(offline.requests().is_empty());
+    }
+
+    fn provider(base_url: &str, model: &str) -> LlmProvider {
+        LlmProvider::from_config(&LlmConfig {
+            base_url: base_url.to_string(),
+            model: model.to_string(),
+            ..LlmConfig::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn cache_key_depends_on_server_model_prompt_and_text() {
+        let groq = provider("https://api.groq.com/openai/v1", "llama3");
+        let key = cache_key(&groq, "Texto");
+
+        assert_eq!(key, cache_key(&groq, "Texto"));
+        assert_ne!(key, cache_key(&groq, "Texto2"));
+        assert_ne!(
+            key,
+            cache_key(&provider("http://localhost:11434/v1", "llama3"), "Texto")
+        );
+        assert_ne!(
+            key,
+            cache_key(
+                &provider("https://api.groq.com/openai/v1", "llama3.1"),
+                "Texto"
+            )
+        );
+
+        let mut other_prompt = groq.clone();
+        other_prompt.prompt.push_str(" Responde en inglés.");
+        assert_ne!(key, cache_key(&other_prompt, "Texto"));
+    }
 }